@@ -1,16 +1,38 @@
-use lalrpop_intern::InternedString;
+use lalrpop_intern::{intern, InternedString};
 use std::fmt;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Span {
+    /// Which of the sources given to `parse_programs` the span is in;
+    /// always 0 for a program parsed from a single source.
+    pub source: usize,
     pub lo: usize,
     pub hi: usize,
 }
 
 impl Span {
-    pub fn new(lo: usize, hi: usize) -> Self {
-        Span { lo: lo, hi: hi }
+    pub fn new(source: usize, lo: usize, hi: usize) -> Self {
+        Span { source: source, lo: lo, hi: hi }
     }
+
+    /// The line and column at which the span starts in `text`, the
+    /// text of the source it was parsed from, both counting from 1.
+    pub fn line_column(&self, text: &str) -> (usize, usize) {
+        let before = &text[..self.lo];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line = before.matches('\n').count() + 1;
+        let column = before[line_start..].chars().count() + 1;
+        (line, column)
+    }
+}
+
+/// A value of the AST along with where it was written, for the parts
+/// of a program that have no name of their own to point at, like the
+/// where clauses of an item or the leaves of a goal.
+#[derive(Clone, Debug)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub span: Span,
 }
 
 pub struct Program {
@@ -19,28 +41,221 @@ pub struct Program {
 
 pub enum Item {
     StructDefn(StructDefn),
+    EnumDefn(EnumDefn),
     TraitDefn(TraitDefn),
     Impl(Impl),
     KrateDefn(KrateDefn),
+    ClosureDefn(ClosureDefn),
+    FnDefn(FnDefn),
+    GeneratorDefn(GeneratorDefn),
+    ExternTyDefn(ExternTyDefn),
+    OpaqueTyDefn(OpaqueTyDefn),
+    TypeAliasDefn(TypeAliasDefn),
+}
+
+impl Item {
+    /// The attributes of the item, for the kinds of item that may have
+    /// any.
+    pub fn attributes(&self) -> &[Attribute] {
+        match *self {
+            Item::StructDefn(ref d) => &d.attributes,
+            Item::TraitDefn(ref d) => &d.attributes,
+            Item::Impl(ref d) => &d.attributes,
+            Item::EnumDefn(_) |
+            Item::KrateDefn(_) |
+            Item::ClosureDefn(_) |
+            Item::FnDefn(_) |
+            Item::GeneratorDefn(_) |
+            Item::ExternTyDefn(_) |
+            Item::OpaqueTyDefn(_) |
+            Item::TypeAliasDefn(_) => &[],
+        }
+    }
 }
 
 pub struct StructDefn {
     pub name: Identifier,
     pub parameter_kinds: Vec<ParameterKind>,
-    pub where_clauses: Vec<WhereClause>,
+
+    /// The default for each of `parameter_kinds`, like the `i32` in
+    /// `struct Foo<T = i32>`.
+    pub parameter_defaults: Vec<Option<Ty>>,
+    pub where_clauses: Vec<Spanned<WhereClause>>,
+    pub fields: Vec<Field>,
+    pub flags: StructFlags,
+    pub attributes: Vec<Attribute>,
+    pub span: Span,
+}
+
+pub struct EnumDefn {
+    pub name: Identifier,
+    pub parameter_kinds: Vec<ParameterKind>,
+    pub parameter_defaults: Vec<Option<Ty>>,
+    pub where_clauses: Vec<Spanned<WhereClause>>,
+    pub variants: Vec<Variant>,
+    pub span: Span,
+}
+
+pub struct Variant {
+    pub name: Identifier,
     pub fields: Vec<Field>,
 }
 
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct StructFlags {
+    /// A `#[fundamental] struct`, like `Box`: for coherence purposes,
+    /// `Box<T>` is local to any crate that `T` is local to.
+    pub fundamental: bool,
+}
+
+/// A closure type, like `closure Foo<T>: FnMut(T) -> u32;`. The
+/// kind (`Fn`, `FnMut` or `FnOnce`) is checked during lowering.
+pub struct ClosureDefn {
+    pub name: Identifier,
+    pub parameter_kinds: Vec<ParameterKind>,
+    pub kind: Identifier,
+    pub arg_tys: Vec<Ty>,
+    pub ret_ty: Ty,
+    pub span: Span,
+}
+
+/// A function item, like `fn foo<T>(T) -> u32 where T: Clone;`.
+/// Each one has its own zero-sized type, which implements the call
+/// traits with the declared signature.
+pub struct FnDefn {
+    pub name: Identifier,
+    pub parameter_kinds: Vec<ParameterKind>,
+    pub arg_tys: Vec<Ty>,
+    pub ret_ty: Ty,
+    pub where_clauses: Vec<Spanned<WhereClause>>,
+    pub span: Span,
+}
+
+/// A generator type, like `generator Foo<T>(u32) yield T -> bool;`,
+/// which resumes with a `u32`, yields `T` and returns `bool`. Like a
+/// closure, its body is not modeled; all that is known is its
+/// signature.
+pub struct GeneratorDefn {
+    pub name: Identifier,
+    pub parameter_kinds: Vec<ParameterKind>,
+    pub resume_ty: Ty,
+    pub yield_ty: Ty,
+    pub return_ty: Ty,
+    pub span: Span,
+}
+
+/// A foreign type, like `extern type Foo;`. Nothing is known about it
+/// but its name: it is neither `Sized` nor implements any auto trait.
+pub struct ExternTyDefn {
+    pub name: Identifier,
+    pub span: Span,
+}
+
+/// An opaque type, like `opaque type Foo<T>: Iterator + Clone;`.
+/// Outside of its defining scope, all that is known about the type
+/// is that it implements its bounds.
+pub struct OpaqueTyDefn {
+    pub name: Identifier,
+    pub parameter_kinds: Vec<ParameterKind>,
+    pub bounds: Vec<TraitBound>,
+    pub span: Span,
+}
+
+/// A type alias, like `type Map<V> = HashMap<String, V>;`. Lowering
+/// expands uses of the alias in place.
+pub struct TypeAliasDefn {
+    pub name: Identifier,
+    pub parameter_kinds: Vec<ParameterKind>,
+    pub ty: Ty,
+    pub span: Span,
+}
+
+/// A trait bound whose self type is implied, like the `Iterator` in
+/// `opaque type Foo: Iterator;`.
+pub struct TraitBound {
+    pub trait_name: Identifier,
+    pub args: Vec<Parameter>,
+}
+
 pub struct TraitDefn {
     pub name: Identifier,
+
+    /// The `sized` in `#[lang(sized)] trait Sized { }`, marking the
+    /// trait as one the solver has built-in rules for. The names are
+    /// checked during lowering.
+    pub lang: Option<Identifier>,
+
     pub parameter_kinds: Vec<ParameterKind>,
-    pub where_clauses: Vec<WhereClause>,
+
+    /// The default for each of `parameter_kinds`, like the `Self` in
+    /// `trait Add<Rhs = Self>`.
+    pub parameter_defaults: Vec<Option<Ty>>,
+
+    /// The `Eq` in `trait Ord: Eq`; sugar for `where Self: Eq`.
+    pub supertraits: Vec<TraitBound>,
+    pub where_clauses: Vec<Spanned<WhereClause>>,
     pub assoc_ty_defns: Vec<AssocTyDefn>,
+    pub assoc_const_defns: Vec<AssocConstDefn>,
+    pub flags: TraitFlags,
+    pub attributes: Vec<Attribute>,
+    pub span: Span,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TraitFlags {
+    /// An `auto trait`, like `Send`: implemented structurally for any
+    /// struct whose field types all implement it.
+    pub auto: bool,
+
+    /// A trait alias, like `trait Alias = A + B;`: implemented by
+    /// exactly the types that implement all of its bounds (which are
+    /// stored as supertraits).
+    pub alias: bool,
+
+    /// A `#[marker] trait`: it has no items, so its impls are allowed
+    /// to overlap.
+    pub marker: bool,
+
+    /// A `#[coinductive] trait`: a cycle of goals implementing it
+    /// holds, as for auto traits.
+    pub coinductive: bool,
+
+    /// An `unsafe trait`, like `Send`: its impls must be declared
+    /// `unsafe impl`, promising to uphold invariants the compiler
+    /// cannot check.
+    pub is_unsafe: bool,
 }
 
 pub struct AssocTyDefn {
     pub name: Identifier,
     pub parameter_kinds: Vec<ParameterKind>,
+    pub where_clauses: Vec<Spanned<WhereClause>>,
+
+    /// The default value, like the `Bar` in `type Assoc = Bar;`, used
+    /// by impls that do not specify the associated type themselves.
+    pub default: Option<Ty>,
+}
+
+/// An associated const, like `const N;`.
+pub struct AssocConstDefn {
+    pub name: Identifier,
+}
+
+/// An attribute of an item, like the `marker` of `#[marker] trait Foo
+/// { }`, or the `lang(sized)` of `#[lang(sized)] trait Sized { }`. The
+/// attributes chalk knows of are taken into the flags of the item as
+/// it is parsed; lowering rejects any others.
+#[derive(Clone, Debug)]
+pub struct Attribute {
+    pub name: Identifier,
+    pub args: Vec<Identifier>,
+}
+
+impl Attribute {
+    /// The attribute called `name` among `attributes`, if any.
+    pub fn find<'a>(attributes: &'a [Attribute], name: &str) -> Option<&'a Attribute> {
+        attributes.iter().find(|attribute| attribute.name.str == intern(name))
+    }
 }
 
 pub struct KrateDefn {
@@ -48,16 +263,19 @@ pub struct KrateDefn {
     pub items: Vec<Item>
 }
 
+#[derive(Copy, Clone, Debug)]
 pub enum ParameterKind {
     Ty(Identifier),
     Lifetime(Identifier),
     Krate(Identifier),
+    Const(Identifier),
 }
 
 pub enum Parameter {
     Ty(Ty),
     Lifetime(Lifetime),
     Krate(Krate),
+    Const(Const),
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -65,6 +283,7 @@ pub enum Kind {
     Ty,
     Lifetime,
     Krate,
+    Const,
 }
 
 impl fmt::Display for Kind {
@@ -74,6 +293,7 @@ impl fmt::Display for Kind {
                 Kind::Ty => "type",
                 Kind::Lifetime => "lifetime",
                 Kind::Krate => "crate",
+                Kind::Const => "const",
             }
         )
     }
@@ -89,6 +309,18 @@ impl Kinded for ParameterKind {
             ParameterKind::Ty(_) => Kind::Ty,
             ParameterKind::Lifetime(_) => Kind::Lifetime,
             ParameterKind::Krate(_) => Kind::Krate,
+            ParameterKind::Const(_) => Kind::Const,
+        }
+    }
+}
+
+impl Parameter {
+    pub fn span(&self) -> Span {
+        match *self {
+            Parameter::Ty(ref t) => t.span(),
+            Parameter::Lifetime(ref l) => l.span(),
+            Parameter::Krate(ref c) => c.span(),
+            Parameter::Const(ref n) => n.span(),
         }
     }
 }
@@ -99,6 +331,7 @@ impl Kinded for Parameter {
             Parameter::Ty(_) => Kind::Ty,
             Parameter::Lifetime(_) => Kind::Lifetime,
             Parameter::Krate(_) => Kind::Krate,
+            Parameter::Const(_) => Kind::Const,
         }
     }
 }
@@ -106,37 +339,166 @@ impl Kinded for Parameter {
 pub struct Impl {
     pub parameter_kinds: Vec<ParameterKind>,
     pub trait_ref: TraitRef,
-    pub where_clauses: Vec<WhereClause>,
+    pub polarity: Polarity,
+    pub where_clauses: Vec<Spanned<WhereClause>>,
     pub assoc_ty_values: Vec<AssocTyValue>,
+    pub assoc_const_values: Vec<AssocConstValue>,
+
+    /// A `default impl`, all of whose items may be overridden by
+    /// more specialized impls.
+    pub default: bool,
+
+    /// An `unsafe impl`, as required for an `unsafe trait`.
+    pub is_unsafe: bool,
+
+    pub attributes: Vec<Attribute>,
+    pub span: Span,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Polarity {
+    /// `impl Foo for Bar`
+    Positive,
+
+    /// `impl !Foo for Bar`
+    Negative,
+}
+
+/// The value of an associated const in an impl, like `const N = 3;`.
+pub struct AssocConstValue {
+    pub name: Identifier,
+    pub value: Const,
+    pub span: Span,
 }
 
 pub struct AssocTyValue {
     pub name: Identifier,
     pub parameter_kinds: Vec<ParameterKind>,
-    pub where_clauses: Vec<WhereClause>,
+    pub where_clauses: Vec<Spanned<WhereClause>>,
     pub value: Ty,
+
+    /// A `default type`, which may be overridden by more specialized
+    /// impls.
+    pub default: bool,
+    pub span: Span,
 }
 
 pub enum Ty {
     Id {
         name: Identifier,
+        span: Span,
     },
     Apply {
         name: Identifier,
-        args: Vec<Parameter>
+        args: Vec<Parameter>,
+        span: Span,
     },
     Projection {
         proj: ProjectionTy,
+        span: Span,
     },
     ForAll {
         lifetime_names: Vec<Identifier>,
-        ty: Box<Ty>
+        ty: Box<Ty>,
+        span: Span,
+    },
+    Tuple {
+        types: Vec<Ty>,
+        span: Span,
+    },
+    Ref {
+        mutability: Mutability,
+        lifetime: Lifetime,
+        ty: Box<Ty>,
+        span: Span,
+    },
+    Raw {
+        mutability: Mutability,
+        ty: Box<Ty>,
+        span: Span,
+    },
+    Slice {
+        ty: Box<Ty>,
+        span: Span,
+    },
+    Array {
+        ty: Box<Ty>,
+        len: Const,
+        span: Span,
+    },
+    Fn {
+        args: Vec<Ty>,
+        ret: Box<Ty>,
+        span: Span,
+    },
+    Dyn {
+        name: Identifier,
+        args: Vec<Parameter>,
+        bindings: Vec<AssocTyBinding>,
+        span: Span,
+    },
+    Never {
+        span: Span,
+    },
+}
+
+impl Ty {
+    /// Where the type was written. A type left implicit, like the `()`
+    /// that `fn(A)` returns, has the span of what implies it.
+    pub fn span(&self) -> Span {
+        match *self {
+            Ty::Id { span, .. } |
+            Ty::Apply { span, .. } |
+            Ty::Projection { span, .. } |
+            Ty::ForAll { span, .. } |
+            Ty::Tuple { span, .. } |
+            Ty::Ref { span, .. } |
+            Ty::Raw { span, .. } |
+            Ty::Slice { span, .. } |
+            Ty::Array { span, .. } |
+            Ty::Fn { span, .. } |
+            Ty::Dyn { span, .. } |
+            Ty::Never { span } => span,
+        }
     }
 }
 
+/// The value of an associated type in a `dyn Trait` type, like the
+/// `Item = u32` in `dyn Iterator<Item = u32>`.
+pub struct AssocTyBinding {
+    pub name: Identifier,
+    pub ty: Ty,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Mutability {
+    Not,
+    Mut,
+}
+
+/// The kind of type a numeric literal may have: `{integer}` for `22`,
+/// `{float}` for `2.2`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NumericKind {
+    Integer,
+    Float,
+}
+
 pub enum Lifetime {
     Id {
         name: Identifier,
+    },
+    Static {
+        span: Span,
+    },
+}
+
+impl Lifetime {
+    pub fn span(&self) -> Span {
+        match *self {
+            Lifetime::Id { name } => name.span,
+            Lifetime::Static { span } => span,
+        }
     }
 }
 
@@ -146,15 +508,49 @@ pub enum Krate {
     }
 }
 
+impl Krate {
+    pub fn span(&self) -> Span {
+        match *self {
+            Krate::Id { name } => name.span,
+        }
+    }
+}
+
+pub enum Const {
+    Id {
+        name: Identifier,
+    },
+    Value {
+        value: u64,
+        span: Span,
+    },
+}
+
+impl Const {
+    pub fn span(&self) -> Span {
+        match *self {
+            Const::Id { name } => name.span,
+            Const::Value { span, .. } => span,
+        }
+    }
+}
+
 pub struct ProjectionTy {
     pub trait_ref: TraitRef,
     pub name: Identifier,
     pub args: Vec<Parameter>,
 }
 
+/// An associated const of a trait ref, like `<T as Foo>::N`.
+pub struct ConstProjection {
+    pub trait_ref: TraitRef,
+    pub name: Identifier,
+}
+
 pub struct TraitRef {
     pub trait_name: Identifier,
     pub args: Vec<Parameter>,
+    pub span: Span,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -166,13 +562,25 @@ pub struct Identifier {
 pub enum WhereClause {
     Implemented { trait_ref: TraitRef },
     NotImplemented { trait_ref: TraitRef },
+    /// `T: ?Sized`, opting out of the implicit `T: Sized` bound.
+    MaybeImplemented { trait_ref: TraitRef },
     ProjectionEq { projection: ProjectionTy, ty: Ty, eq: bool},
+    ConstProjectionEq { projection: ConstProjection, value: Const },
+    TypeOutlives { ty: Ty, lifetime: Lifetime },
+    LifetimeOutlives { a: Lifetime, b: Lifetime },
     TyWellFormed { ty: Ty },
     TraitRefWellFormed { trait_ref: TraitRef },
     LocalTo { ty: Ty, krate: Krate },
+    Unsize { source: Ty, target: Ty },
+    Subtype { a: Ty, b: Ty },
     UnifyTys { a: Ty, b: Ty, eq: bool },
     UnifyKrates { a: Krate, b: Krate },
     UnifyLifetimes { a: Lifetime, b: Lifetime },
+    /// `T: {integer}` or `T: {float}`: `T` is the type of a numeric
+    /// literal, and so some integer (or float) type.
+    Numeric { ty: Ty, kind: NumericKind },
+    /// `forall<'a> T: Foo<'a>`, a higher-ranked where-clause.
+    ForAll { parameter_kinds: Vec<ParameterKind>, where_clause: Box<WhereClause> },
 }
 
 pub struct Field {
@@ -185,8 +593,9 @@ pub enum Goal {
     Exists(Vec<ParameterKind>, Box<Goal>),
     Implies(Vec<WhereClause>, Box<Goal>),
     And(Box<Goal>, Box<Goal>),
+    Not(Box<Goal>),
     Krate(Krate, Box<Goal>),
 
     // Additional kinds of goals:
-    Leaf(WhereClause),
+    Leaf(Spanned<WhereClause>),
 }
@@ -0,0 +1,564 @@
+//! Printing the AST back out as `.chalk` source, which parses to the
+//! same AST again (spans aside). The output is canonical: one item to
+//! a line, with the attributes of an item first, and single spaces
+//! between tokens, however the source was laid out.
+
+use lalrpop_intern::intern;
+use std::fmt::{Display, Formatter, Error};
+
+use ast::*;
+
+impl Display for Program {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        for item in &self.items {
+            writeln!(fmt, "{}", item)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Item {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            Item::StructDefn(ref defn) => write!(fmt, "{}", defn),
+            Item::EnumDefn(ref defn) => write!(fmt, "{}", defn),
+            Item::TraitDefn(ref defn) => write!(fmt, "{}", defn),
+            Item::Impl(ref impl_) => write!(fmt, "{}", impl_),
+            Item::KrateDefn(ref defn) => write!(fmt, "{}", defn),
+            Item::ClosureDefn(ref defn) => write!(fmt, "{}", defn),
+            Item::FnDefn(ref defn) => write!(fmt, "{}", defn),
+            Item::GeneratorDefn(ref defn) => write!(fmt, "{}", defn),
+            Item::ExternTyDefn(ref defn) => write!(fmt, "{}", defn),
+            Item::OpaqueTyDefn(ref defn) => write!(fmt, "{}", defn),
+            Item::TypeAliasDefn(ref defn) => write!(fmt, "{}", defn),
+        }
+    }
+}
+
+impl Display for StructDefn {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt,
+               "{}struct {}{}{} {{{}}}",
+               Attributes(&self.attributes),
+               self.name,
+               Defaulted(&self.parameter_kinds, &self.parameter_defaults),
+               Where(&self.where_clauses),
+               Braced(&self.fields))
+    }
+}
+
+impl Display for EnumDefn {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt,
+               "enum {}{}{} {{{}}}",
+               self.name,
+               Defaulted(&self.parameter_kinds, &self.parameter_defaults),
+               Where(&self.where_clauses),
+               Braced(&self.variants))
+    }
+}
+
+impl Display for Variant {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        if self.fields.is_empty() {
+            write!(fmt, "{}", self.name)
+        } else {
+            write!(fmt, "{} {{{}}}", self.name, Braced(&self.fields))
+        }
+    }
+}
+
+impl Display for Field {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt, "{}: {}", self.name, self.ty)
+    }
+}
+
+impl Display for ClosureDefn {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt,
+               "closure {}{}: {}({}) -> {};",
+               self.name,
+               Angle(&self.parameter_kinds),
+               self.kind,
+               Comma(&self.arg_tys),
+               self.ret_ty)
+    }
+}
+
+impl Display for FnDefn {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt,
+               "fn {}{}({}) -> {}{};",
+               self.name,
+               Angle(&self.parameter_kinds),
+               Comma(&self.arg_tys),
+               self.ret_ty,
+               Where(&self.where_clauses))
+    }
+}
+
+impl Display for GeneratorDefn {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt,
+               "generator {}{}({}) yield {} -> {};",
+               self.name,
+               Angle(&self.parameter_kinds),
+               self.resume_ty,
+               self.yield_ty,
+               self.return_ty)
+    }
+}
+
+impl Display for ExternTyDefn {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt, "extern type {};", self.name)
+    }
+}
+
+impl Display for OpaqueTyDefn {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt,
+               "opaque type {}{}: {};",
+               self.name,
+               Angle(&self.parameter_kinds),
+               Plus(&self.bounds))
+    }
+}
+
+impl Display for TypeAliasDefn {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt, "type {}{} = {};", self.name, Angle(&self.parameter_kinds), self.ty)
+    }
+}
+
+impl Display for TraitBound {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt, "{}{}", self.trait_name, Angle(&self.args))
+    }
+}
+
+impl Display for TraitDefn {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        let parameters = Defaulted(&self.parameter_kinds, &self.parameter_defaults);
+        if self.flags.alias {
+            return write!(fmt, "trait {}{} = {};", self.name, parameters, Plus(&self.supertraits));
+        }
+
+        write!(fmt, "{}", Attributes(&self.attributes))?;
+        if self.flags.is_unsafe {
+            write!(fmt, "unsafe ")?;
+        }
+        // `#[auto]` is printed among the attributes.
+        if self.flags.auto && Attribute::find(&self.attributes, "auto").is_none() {
+            write!(fmt, "auto ")?;
+        }
+        write!(fmt, "trait {}{}", self.name, parameters)?;
+        if !self.supertraits.is_empty() {
+            write!(fmt, ": {}", Plus(&self.supertraits))?;
+        }
+        write!(fmt, "{} {{", Where(&self.where_clauses))?;
+        for defn in &self.assoc_ty_defns {
+            write!(fmt, " {}", defn)?;
+        }
+        for defn in &self.assoc_const_defns {
+            write!(fmt, " {}", defn)?;
+        }
+        write!(fmt, " }}")
+    }
+}
+
+impl Display for AssocTyDefn {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt, "type {}{}{}", self.name, Angle(&self.parameter_kinds), Where(&self.where_clauses))?;
+        if let Some(ref default) = self.default {
+            write!(fmt, " = {}", default)?;
+        }
+        write!(fmt, ";")
+    }
+}
+
+impl Display for AssocConstDefn {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt, "const {};", self.name)
+    }
+}
+
+impl Display for Attribute {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        // Attributes may be named by keywords, like `#[auto]` and
+        // `#[lang(fn)]`, without escaping them.
+        let args: Vec<_> = self.args.iter().map(|arg| arg.str).collect();
+        if args.is_empty() {
+            write!(fmt, "#[{}]", self.name.str)
+        } else {
+            write!(fmt, "#[{}({})]", self.name.str, Comma(&args))
+        }
+    }
+}
+
+impl Display for KrateDefn {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt, "crate {} {{", self.name)?;
+        for item in &self.items {
+            write!(fmt, " {}", item)?;
+        }
+        write!(fmt, " }}")
+    }
+}
+
+impl Display for ParameterKind {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            ParameterKind::Ty(ref n) => write!(fmt, "{}", n),
+            ParameterKind::Lifetime(ref n) => write!(fmt, "{}", n),
+            ParameterKind::Krate(ref n) => write!(fmt, "crate {}", n),
+            ParameterKind::Const(ref n) => write!(fmt, "const {}", n),
+        }
+    }
+}
+
+impl Display for Parameter {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            Parameter::Ty(ref ty) => write!(fmt, "{}", ty),
+            Parameter::Lifetime(ref lifetime) => write!(fmt, "{}", lifetime),
+            Parameter::Krate(ref krate) => write!(fmt, "{}", krate),
+            Parameter::Const(ref value) => write!(fmt, "{}", value),
+        }
+    }
+}
+
+impl Display for Impl {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt, "{}", Attributes(&self.attributes))?;
+        if self.default {
+            write!(fmt, "default ")?;
+        }
+        if self.is_unsafe {
+            write!(fmt, "unsafe ")?;
+        }
+        write!(fmt, "impl{} ", Angle(&self.parameter_kinds))?;
+        if self.polarity == Polarity::Negative {
+            write!(fmt, "!")?;
+        }
+        write!(fmt,
+               "{}{} for {}{} {{",
+               self.trait_ref.trait_name,
+               Angle(&self.trait_ref.args[1..]),
+               self.trait_ref.args[0],
+               Where(&self.where_clauses))?;
+        for value in &self.assoc_ty_values {
+            write!(fmt, " {}", value)?;
+        }
+        for value in &self.assoc_const_values {
+            write!(fmt, " {}", value)?;
+        }
+        write!(fmt, " }}")
+    }
+}
+
+impl Display for AssocConstValue {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt, "const {} = {};", self.name, self.value)
+    }
+}
+
+impl Display for AssocTyValue {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        if self.default {
+            write!(fmt, "default ")?;
+        }
+        write!(fmt,
+               "type {}{}{} = {};",
+               self.name,
+               Angle(&self.parameter_kinds),
+               Where(&self.where_clauses),
+               self.value)
+    }
+}
+
+impl Display for Ty {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            Ty::Id { ref name, .. } => write!(fmt, "{}", name),
+            Ty::Apply { ref name, ref args, .. } => write!(fmt, "{}<{}>", name, Comma(args)),
+            Ty::Projection { ref proj, .. } => write!(fmt, "{}", proj),
+            Ty::ForAll { ref lifetime_names, ref ty, .. } => write!(fmt, "for<{}> {}", Comma(lifetime_names), ty),
+            Ty::Tuple { ref types, .. } => {
+                // `(A)` is not a tuple, but `(A,)` is.
+                if types.len() == 1 {
+                    write!(fmt, "({},)", types[0])
+                } else {
+                    write!(fmt, "({})", Comma(types))
+                }
+            }
+            Ty::Ref { mutability, ref lifetime, ref ty, .. } => match mutability {
+                Mutability::Not => write!(fmt, "&{} {}", lifetime, ty),
+                Mutability::Mut => write!(fmt, "&{} mut {}", lifetime, ty),
+            },
+            Ty::Raw { mutability, ref ty, .. } => match mutability {
+                Mutability::Not => write!(fmt, "*const {}", ty),
+                Mutability::Mut => write!(fmt, "*mut {}", ty),
+            },
+            Ty::Slice { ref ty, .. } => write!(fmt, "[{}]", ty),
+            Ty::Array { ref ty, ref len, .. } => write!(fmt, "[{}; {}]", ty, len),
+            Ty::Fn { ref args, ref ret, .. } => write!(fmt, "fn({}) -> {}", Comma(args), ret),
+            Ty::Dyn { ref name, ref args, ref bindings, .. } => {
+                if bindings.is_empty() {
+                    write!(fmt, "dyn {}{}", name, Angle(args))
+                } else if args.is_empty() {
+                    write!(fmt, "dyn {}<{}>", name, Comma(bindings))
+                } else {
+                    write!(fmt, "dyn {}<{}, {}>", name, Comma(args), Comma(bindings))
+                }
+            }
+            Ty::Never { .. } => write!(fmt, "!"),
+        }
+    }
+}
+
+impl Display for AssocTyBinding {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt, "{} = {}", self.name, self.ty)
+    }
+}
+
+impl Display for Lifetime {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            Lifetime::Id { ref name } => write!(fmt, "{}", name),
+            Lifetime::Static { .. } => write!(fmt, "'static"),
+        }
+    }
+}
+
+impl Display for Krate {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            Krate::Id { ref name } => write!(fmt, "crate {}", name),
+        }
+    }
+}
+
+impl Display for Const {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            Const::Id { ref name } => write!(fmt, "{}", name),
+            Const::Value { value, .. } => write!(fmt, "{}", value),
+        }
+    }
+}
+
+impl Display for ProjectionTy {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt,
+               "<{} as {}{}>::{}{}",
+               self.trait_ref.args[0],
+               self.trait_ref.trait_name,
+               Angle(&self.trait_ref.args[1..]),
+               self.name,
+               Angle(&self.args))
+    }
+}
+
+impl Display for TraitRef {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt, "{}: {}{}", self.args[0], self.trait_name, Angle(&self.args[1..]))
+    }
+}
+
+/// The words that the grammar takes as keywords, and so that names
+/// must be written as raw identifiers, like `r#type`, to be.
+const KEYWORDS: &[&str] = &[
+    "LocalTo", "Subtype", "Unsize", "WellFormed", "as", "auto", "closure", "const", "crate",
+    "default", "dyn", "enum", "exists", "extern", "fn", "for", "forall", "generator", "if",
+    "impl", "mut", "not", "opaque", "struct", "trait", "type", "unsafe", "where", "yield",
+];
+
+impl Display for Identifier {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        if KEYWORDS.iter().any(|&keyword| self.str == intern(keyword)) {
+            write!(fmt, "r#{}", self.str)
+        } else {
+            write!(fmt, "{}", self.str)
+        }
+    }
+}
+
+impl<T: Display> Display for Spanned<T> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        Display::fmt(&self.value, fmt)
+    }
+}
+
+impl Display for WhereClause {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            WhereClause::Implemented { ref trait_ref } => write!(fmt, "{}", trait_ref),
+            WhereClause::NotImplemented { ref trait_ref } => {
+                write!(fmt, "{}: !{}{}", trait_ref.args[0], trait_ref.trait_name, Angle(&trait_ref.args[1..]))
+            }
+            WhereClause::MaybeImplemented { ref trait_ref } => {
+                write!(fmt, "{}: ?{}{}", trait_ref.args[0], trait_ref.trait_name, Angle(&trait_ref.args[1..]))
+            }
+            WhereClause::ProjectionEq { ref projection, ref ty, eq } => {
+                let trait_ref = &projection.trait_ref;
+                write!(fmt, "{}: {}<", trait_ref.args[0], trait_ref.trait_name)?;
+                for arg in &trait_ref.args[1..] {
+                    write!(fmt, "{}, ", arg)?;
+                }
+                write!(fmt,
+                       "{}{} {} {}>",
+                       projection.name,
+                       Angle(&projection.args),
+                       if eq { "=" } else { "!=" },
+                       ty)
+            }
+            WhereClause::ConstProjectionEq { ref projection, ref value } => {
+                let trait_ref = &projection.trait_ref;
+                write!(fmt, "{}: {}<", trait_ref.args[0], trait_ref.trait_name)?;
+                for arg in &trait_ref.args[1..] {
+                    write!(fmt, "{}, ", arg)?;
+                }
+                write!(fmt, "const {} = {}>", projection.name, value)
+            }
+            WhereClause::TypeOutlives { ref ty, ref lifetime } => write!(fmt, "{}: {}", ty, lifetime),
+            WhereClause::LifetimeOutlives { ref a, ref b } => write!(fmt, "{}: {}", a, b),
+            WhereClause::TyWellFormed { ref ty } => write!(fmt, "WellFormed({})", ty),
+            WhereClause::TraitRefWellFormed { ref trait_ref } => write!(fmt, "WellFormed({})", trait_ref),
+            WhereClause::LocalTo { ref ty, ref krate } => write!(fmt, "LocalTo({}, {})", ty, krate),
+            WhereClause::Unsize { ref source, ref target } => write!(fmt, "Unsize({}, {})", source, target),
+            WhereClause::Subtype { ref a, ref b } => write!(fmt, "Subtype({}, {})", a, b),
+            WhereClause::UnifyTys { ref a, ref b, eq } => {
+                write!(fmt, "{} {} {}", a, if eq { "=" } else { "!=" }, b)
+            }
+            WhereClause::UnifyKrates { ref a, ref b } => write!(fmt, "{} = {}", a, b),
+            WhereClause::UnifyLifetimes { ref a, ref b } => write!(fmt, "{} = {}", a, b),
+            WhereClause::Numeric { ref ty, kind } => match kind {
+                NumericKind::Integer => write!(fmt, "{}: {{integer}}", ty),
+                NumericKind::Float => write!(fmt, "{}: {{float}}", ty),
+            },
+            WhereClause::ForAll { ref parameter_kinds, ref where_clause } => {
+                write!(fmt, "forall<{}> {}", Comma(parameter_kinds), where_clause)
+            }
+        }
+    }
+}
+
+impl Display for Goal {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            Goal::ForAll(ref p, ref g) => write!(fmt, "forall<{}> {{ {} }}", Comma(p), g),
+            Goal::Exists(ref p, ref g) => write!(fmt, "exists<{}> {{ {} }}", Comma(p), g),
+            Goal::Implies(ref h, ref g) => write!(fmt, "if ({}) {{ {} }}", Comma(h), g),
+            Goal::And(ref g1, ref g2) => write!(fmt, "{}, {}", g1, g2),
+            Goal::Not(ref g) => write!(fmt, "not {{ {} }}", g),
+            Goal::Krate(ref k, ref g) => write!(fmt, "{} {{ {} }}", k, g),
+            Goal::Leaf(ref wc) => write!(fmt, "{}", wc),
+        }
+    }
+}
+
+struct Comma<'a, T: 'a>(&'a [T]);
+
+impl<'a, T: Display> Display for Comma<'a, T> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        for (index, elem) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(fmt, ", {}", elem)?;
+            } else {
+                write!(fmt, "{}", elem)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+struct Plus<'a, T: 'a>(&'a [T]);
+
+impl<'a, T: Display> Display for Plus<'a, T> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        for (index, elem) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(fmt, " + {}", elem)?;
+            } else {
+                write!(fmt, "{}", elem)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `<A, B>`, or nothing at all if there are no elements.
+struct Angle<'a, T: 'a>(&'a [T]);
+
+impl<'a, T: Display> Display for Angle<'a, T> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        if self.0.len() > 0 {
+            write!(fmt, "<{}>", Comma(self.0))?;
+        }
+        Ok(())
+    }
+}
+
+/// ` A, B ` between the braces of a struct or enum, or just ` ` if
+/// there is nothing in them.
+struct Braced<'a, T: 'a>(&'a [T]);
+
+impl<'a, T: Display> Display for Braced<'a, T> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        if self.0.len() > 0 {
+            write!(fmt, " {} ", Comma(self.0))
+        } else {
+            write!(fmt, " ")
+        }
+    }
+}
+
+/// ` where A, B`, or nothing at all if there are no where-clauses.
+struct Where<'a>(&'a [Spanned<WhereClause>]);
+
+impl<'a> Display for Where<'a> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        if self.0.len() > 0 {
+            write!(fmt, " where {}", Comma(self.0))?;
+        }
+        Ok(())
+    }
+}
+
+/// The parameters of an item along with their defaults, like `<T, U =
+/// i32>`.
+struct Defaulted<'a>(&'a [ParameterKind], &'a [Option<Ty>]);
+
+impl<'a> Display for Defaulted<'a> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        if self.0.len() > 0 {
+            write!(fmt, "<")?;
+            for (index, (kind, default)) in self.0.iter().zip(self.1).enumerate() {
+                if index > 0 {
+                    write!(fmt, ", ")?;
+                }
+                write!(fmt, "{}", kind)?;
+                if let Some(ref default) = *default {
+                    write!(fmt, " = {}", default)?;
+                }
+            }
+            write!(fmt, ">")?;
+        }
+        Ok(())
+    }
+}
+
+/// The attributes of an item, each followed by a space.
+struct Attributes<'a>(&'a [Attribute]);
+
+impl<'a> Display for Attributes<'a> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        for attribute in self.0 {
+            write!(fmt, "{} ", attribute)?;
+        }
+        Ok(())
+    }
+}
@@ -1,2 +1,8 @@
 error_chain! {
+    errors {
+        ParseErrors(messages: Vec<String>) {
+            description("parse errors")
+                display("{}", messages.join("\n"))
+        }
+    }
 }
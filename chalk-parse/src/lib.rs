@@ -7,29 +7,174 @@ extern crate lalrpop_intern;
 extern crate lalrpop_util;
 
 pub mod ast;
+mod display;
 pub mod errors;
 mod parser;
 
-use errors::Result;
+use errors::{ErrorKind, Result};
+use lalrpop_util::ParseError;
+use std::borrow::Cow;
 
+/// Parses a program. A syntax error does not stop the parse: we skip
+/// ahead to the next item and go on, so that the error reported lists
+/// every syntax error in the program, one per line, and not just the
+/// first.
 pub fn parse_program(text: &str) -> Result<ast::Program> {
-    match parser::parse_Program(text) {
-        Ok(v) => Ok(v),
-        Err(e) => bail!("parse error: {:?}", e),
+    match parse_items(0, &strip_comments(text)?) {
+        Ok(items) => Ok(ast::Program { items: items }),
+        Err(errors) => {
+            let messages = errors.into_iter().map(|e| format!("parse error at {}", e)).collect();
+            Err(ErrorKind::ParseErrors(messages).into())
+        }
+    }
+}
+
+/// Parses a program split across several sources, such as a prelude
+/// shared between tests and the items of one test, as if they were a
+/// single program with the items of each source in turn. The spans of
+/// each item are relative to the source it came from, whose index they
+/// record.
+pub fn parse_programs(texts: &[&str]) -> Result<ast::Program> {
+    let mut items = vec![];
+    let mut messages = vec![];
+    for (index, text) in texts.iter().enumerate() {
+        match parse_items(index, &strip_comments(text)?) {
+            Ok(more_items) => items.extend(more_items),
+            Err(errors) => {
+                messages.extend(errors.into_iter().map(|e| format!("parse error in source {} at {}", index, e)))
+            }
+        }
+    }
+    if !messages.is_empty() {
+        return Err(ErrorKind::ParseErrors(messages).into());
+    }
+    Ok(ast::Program { items: items })
+}
+
+/// Parses the items of a program, the source numbered `source`, or
+/// else describes each of its syntax errors, in order (see
+/// `describe_error`).
+fn parse_items(source: usize, text: &str) -> ::std::result::Result<Vec<ast::Item>, Vec<String>> {
+    let mut recovered = vec![];
+    let result = parser::parse_Program(source, &mut recovered, text);
+    let mut errors: Vec<_> = recovered.into_iter().map(|r| describe_error(r.error, text)).collect();
+    match result {
+        Ok(program) => {
+            if errors.is_empty() {
+                return Ok(program.items);
+            }
+        }
+        Err(e) => errors.push(describe_error(e, text)),
     }
+    Err(errors)
 }
 
 pub fn parse_ty(text: &str) -> Result<ast::Ty> {
-    match parser::parse_Ty(text) {
+    let stripped = strip_comments(text)?;
+    match parser::parse_Ty(0, &mut vec![], &stripped) {
         Ok(v) => Ok(v),
-        Err(e) => bail!("error parsing `{}`: {:?}", text, e),
+        Err(e) => bail!("error parsing `{}` at {}", text, describe_error(e, &stripped)),
     }
 }
 
 pub fn parse_goal(text: &str) -> Result<Box<ast::Goal>> {
-    match parser::parse_Goal(text) {
+    let stripped = strip_comments(text)?;
+    match parser::parse_Goal(0, &mut vec![], &stripped) {
+        Ok(v) => Ok(v),
+        Err(e) => bail!("parse error at {}", describe_error(e, &stripped)),
+    }
+}
+
+/// Parses a comma-separated list of where-clauses, as would appear in
+/// `if (...)`.
+pub fn parse_hypotheses(text: &str) -> Result<Vec<ast::WhereClause>> {
+    let stripped = strip_comments(text)?;
+    match parser::parse_Hypotheses(0, &mut vec![], &stripped) {
         Ok(v) => Ok(v),
-        Err(e) => bail!("parse error: {:?}", e),
+        Err(e) => bail!("parse error at {}", describe_error(e, &stripped)),
     }
 }
 
+/// Describes `error`, a syntax error in `text`, along with where it
+/// was found: "line 2, column 29: unexpected token `}`".
+fn describe_error(error: ParseError<usize, (usize, &str), (usize, &'static str)>, text: &str) -> String {
+    let (location, message) = match error {
+        ParseError::InvalidToken { location } => (location, "invalid token".to_string()),
+        ParseError::UnrecognizedToken { token: Some((location, (_, token), _)), expected } => {
+            (location, format!("unexpected token `{}`{}", token, describe_expected(&expected)))
+        }
+        ParseError::UnrecognizedToken { token: None, expected } => {
+            (text.len(), format!("unexpected end of input{}", describe_expected(&expected)))
+        }
+        ParseError::ExtraToken { token: (location, (_, token), _) } => {
+            (location, format!("extra token `{}`", token))
+        }
+        ParseError::User { error: (location, message) } => (location, message.to_string()),
+    };
+    let (line, column) = ast::Span::new(0, location, location).line_column(text);
+    format!("line {}, column {}: {}", line, column, message)
+}
+
+fn describe_expected(expected: &[String]) -> String {
+    if expected.is_empty() {
+        String::new()
+    } else {
+        format!(", expected one of {}", expected.join(", "))
+    }
+}
+
+/// Blanks out the `//` and `/* */` comments of `text`, which the
+/// grammar knows nothing of. Each byte of a comment but its newlines
+/// becomes a space, so that the spans of what is left are as they
+/// were. Block comments nest, as in Rust.
+fn strip_comments(text: &str) -> Result<Cow<str>> {
+    if !text.contains("//") && !text.contains("/*") {
+        return Ok(Cow::Borrowed(text));
+    }
+
+    let bytes = text.as_bytes();
+    let mut stripped = Vec::with_capacity(bytes.len());
+    let mut depth = 0;
+    let mut line_comment = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        let next = bytes.get(i + 1).cloned();
+        match bytes[i] {
+            b'\n' => {
+                line_comment = false;
+                stripped.push(b'\n');
+                i += 1;
+                continue;
+            }
+            _ if line_comment => (),
+            b'/' if next == Some(b'*') => {
+                depth += 1;
+                stripped.extend_from_slice(b"  ");
+                i += 2;
+                continue;
+            }
+            b'*' if depth > 0 && next == Some(b'/') => {
+                depth -= 1;
+                stripped.extend_from_slice(b"  ");
+                i += 2;
+                continue;
+            }
+            _ if depth > 0 => (),
+            b'/' if next == Some(b'/') => line_comment = true,
+            byte => {
+                stripped.push(byte);
+                i += 1;
+                continue;
+            }
+        }
+        stripped.push(b' ');
+        i += 1;
+    }
+
+    if depth > 0 {
+        bail!("parse error: unterminated block comment");
+    }
+    // Only whole comments were blanked out, and every byte of them, so
+    // what is left is still UTF-8.
+    Ok(Cow::Owned(String::from_utf8(stripped).unwrap()))
+}
@@ -0,0 +1,376 @@
+//! `chalki`, an interactive shell for chalk: load a program, from a
+//! file or typed in, then enter goals to be solved against it.
+//!
+//! ```text
+//! $ chalki traits.chalk
+//! ?- exists<T> { Vec<T>: Clone }
+//! ```
+//!
+//! Lines are read with rustyline, so the usual line editing works,
+//! and the history is kept across sessions in `~/.chalki_history`:
+//! the arrow keys recall earlier lines, and Ctrl-R searches them.
+//! Tab completes the names of commands and of the items of the
+//! current program.
+//!
+//! So that the library does not depend on rustyline, `chalki` is only
+//! built with the `repl` feature: `cargo run --features repl`.
+
+extern crate chalk;
+extern crate chalk_parse;
+extern crate rustyline;
+
+use chalk::errors::*;
+use chalk::ir;
+use chalk::lower::*;
+use chalk::solve::{Solution, SolveOptions, SolverChoice};
+use rustyline::Editor;
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Debug;
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::Arc;
+
+const HELP: &'static str = "\
+Enter a goal to solve it against the current program, or one of:
+
+  :help             print this message
+  :program          enter a program, ending it with a line `.`
+  :load <path>      load the program in the file at <path>
+  :load             load the file that was last loaded again
+  :lowered          print the current program as lowering left it
+  :answers <goal>   print each distinct answer to <goal>, up to ten
+  :clauses <trait>  print the program clauses that might prove that
+                    a type implements <trait>
+  :quit             leave chalki";
+
+/// The commands of `HELP`, to complete.
+const COMMANDS: &'static [&'static str] = &[
+    ":help",
+    ":program",
+    ":load",
+    ":lowered",
+    ":answers",
+    ":clauses",
+    ":quit",
+];
+
+/// A program that was loaded, lowered and ready to solve goals in.
+struct Program {
+    ir: Arc<ir::Program>,
+
+    /// The clauses of the program, as `env` looks them up.
+    environment: Arc<ir::ProgramEnvironment>,
+
+    env: Arc<ir::ChalkDatabase>,
+}
+
+impl Program {
+    fn new(text: &str) -> Result<Program> {
+        let ir = Arc::new(chalk_parse::parse_program(text)?.lower()?);
+        let environment = Arc::new(ir.environment());
+        let env: Arc<ir::ChalkDatabase> = environment.clone();
+        Ok(Program { ir: ir, environment: environment, env: env })
+    }
+}
+
+/// The state of the shell between commands.
+struct Shell {
+    program: Option<Program>,
+
+    /// The file of the last `:load`, which a bare `:load` reads again.
+    path: Option<String>,
+
+    solver: SolverChoice,
+
+    editor: Editor<NameCompleter>,
+
+    /// The names of the items of the current program, which the
+    /// completer of `editor` completes.
+    names: Rc<RefCell<Vec<String>>>,
+}
+
+/// What to do once a command is done.
+enum Next {
+    Continue,
+    Quit,
+}
+
+impl Shell {
+    fn new() -> Shell {
+        let names = Rc::new(RefCell::new(vec![]));
+        let mut editor = Editor::new();
+        editor.set_completer(Some(NameCompleter { names: names.clone() }));
+        Shell {
+            program: None,
+            path: None,
+            solver: SolverChoice::Recursive { overflow_depth: 10 },
+            editor: editor,
+            names: names,
+        }
+    }
+
+    fn process(&mut self, line: &str) -> Next {
+        let (command, argument) = match line.find(char::is_whitespace) {
+            Some(index) => (&line[..index], line[index..].trim()),
+            None => (line, ""),
+        };
+        match command {
+            "" => (),
+            ":help" => println!("{}", HELP),
+            ":quit" => return Next::Quit,
+            ":program" => {
+                let mut text = String::new();
+                loop {
+                    match self.read_line("| ") {
+                        Some(ref line) if line.trim() == "." => break,
+                        Some(line) => {
+                            text.push_str(&line);
+                            text.push('\n');
+                        }
+                        None => break,
+                    }
+                }
+                self.set_program(&text);
+            }
+            ":load" => {
+                if !argument.is_empty() {
+                    self.path = Some(argument.to_string());
+                }
+                match self.path.clone() {
+                    Some(path) => self.load(&path),
+                    None => println!("error: no file has been loaded yet; try `:load <path>`"),
+                }
+            }
+            ":lowered" => self.with_program(|program| print_lowered(&program.ir)),
+            ":answers" => self.answers(argument),
+            ":clauses" => self.with_program(|program| print_clauses(program, argument)),
+            _ if command.starts_with(':') => {
+                println!("error: unknown command `{}`; try `:help`", command);
+            }
+            _ => self.solve(line),
+        }
+        Next::Continue
+    }
+
+    fn load(&mut self, path: &str) {
+        let mut text = String::new();
+        match File::open(path).and_then(|mut file| file.read_to_string(&mut text)) {
+            Ok(_) => self.set_program(&text),
+            Err(e) => println!("error: cannot read `{}`: {}", path, e),
+        }
+    }
+
+    /// Replaces the current program with the one in `text`. If it is
+    /// not a valid program, the current one is kept.
+    fn set_program(&mut self, text: &str) {
+        match Program::new(text) {
+            Ok(program) => {
+                let mut names: Vec<_> = program.ir.type_kinds.values().map(|kind| kind.name.to_string()).collect();
+                names.sort();
+                names.dedup();
+                *self.names.borrow_mut() = names;
+                self.program = Some(program);
+            }
+            Err(e) => println!("{}", e.located(text)),
+        }
+    }
+
+    /// Runs `op` on the current program, with it set as the program
+    /// to print items of, or complains that there is none.
+    fn with_program<OP>(&self, op: OP)
+        where OP: FnOnce(&Program)
+    {
+        match self.program {
+            Some(ref program) => ir::set_current_program(&program.ir, || op(program)),
+            None => println!("error: no program; enter one with `:program` or `:load <path>`"),
+        }
+    }
+
+    fn solve(&self, text: &str) {
+        self.with_program(|program| {
+            match self.solve_goal(program, text) {
+                Ok(solution) => println!("{:#?}", solution),
+                Err(e) => println!("error: {}", e),
+            }
+        });
+    }
+
+    /// Prompts with `prompt` and reads a line, adding it to the
+    /// history unless it is blank, or returns `None` at the end of
+    /// input. Interrupting with Ctrl-C gives up on the line, which then
+    /// reads as empty.
+    fn read_line(&mut self, prompt: &str) -> Option<String> {
+        match self.editor.readline(prompt) {
+            Ok(line) => {
+                if !line.trim().is_empty() {
+                    self.editor.add_history_entry(&line);
+                }
+                Some(line.trim_right().to_string())
+            }
+            Err(ReadlineError::Interrupted) => Some(String::new()),
+            Err(ReadlineError::Eof) => None,
+            Err(e) => {
+                println!("error: {}", e);
+                None
+            }
+        }
+    }
+
+    fn answers(&self, text: &str) {
+        self.with_program(|program| {
+            match self.solve_goal_answers(program, text) {
+                Ok(ref answers) if answers.is_empty() => println!("no answers"),
+                Ok(answers) => {
+                    for answer in answers {
+                        println!("{:#?}", answer);
+                    }
+                }
+                Err(e) => println!("error: {}", e),
+            }
+        });
+    }
+
+    fn solve_goal(&self, program: &Program, text: &str) -> Result<Solution<Vec<ir::WhereClauseGoal>>> {
+        let goal = chalk_parse::parse_goal(text)?.lower(&*program.ir)?;
+        self.solver.solve_goal(&program.env, goal)
+    }
+
+    fn solve_goal_answers(&self, program: &Program, text: &str)
+                          -> Result<Vec<Solution<Vec<ir::WhereClauseGoal>>>> {
+        let goal = chalk_parse::parse_goal(text)?.lower(&*program.ir)?;
+        self.solver.solve_goal_answers(&program.env, goal, &SolveOptions::default())
+    }
+}
+
+/// Completes the word before the cursor: the name of a command at the
+/// start of the line, and otherwise the name of a struct, enum, trait
+/// or other item of the current program.
+struct NameCompleter {
+    names: Rc<RefCell<Vec<String>>>,
+}
+
+impl Completer for NameCompleter {
+    fn complete(&self, line: &str, pos: usize) -> rustyline::Result<(usize, Vec<String>)> {
+        let word_start = line[..pos].char_indices()
+                                    .rev()
+                                    .find(|&(_, c)| !(c.is_alphanumeric() || c == '_'))
+                                    .map(|(index, c)| index + c.len_utf8())
+                                    .unwrap_or(0);
+        let (start, candidates) = if &line[..word_start] == ":" {
+            (0, COMMANDS.iter().map(|command| command.to_string()).collect())
+        } else {
+            (word_start, self.names.borrow().clone())
+        };
+        let word = &line[start..pos];
+        let completions = candidates.into_iter().filter(|candidate| candidate.starts_with(word)).collect();
+        Ok((start, completions))
+    }
+}
+
+/// Prints what lowering made of `program`: the kind of each item,
+/// then the structs, enums, traits and impls, each with its where
+/// clauses, and the associated types of the traits.
+fn print_lowered(program: &ir::Program) {
+    print_items("type kinds", &program.type_kinds);
+    print_items("structs", &program.struct_data);
+    print_items("enums", &program.enum_data);
+    print_items("traits", &program.trait_data);
+    print_items("impls", &program.impl_data);
+    print_items("associated types", &program.associated_ty_data);
+}
+
+/// Prints `items` under `heading`, in the order they were declared.
+fn print_items<T: Debug>(heading: &str, items: &HashMap<ir::ItemId, T>) {
+    if items.is_empty() {
+        return;
+    }
+    let mut items: Vec<_> = items.iter().collect();
+    items.sort_by_key(|&(&id, _)| id);
+    println!("{}:", heading);
+    for (id, item) in items {
+        println!("{:?} = {:#?}", id, item);
+    }
+}
+
+/// Prints each clause of `program` that concludes that a type
+/// implements the trait called `name`: those of its impls, of the
+/// where clauses it implies, and of the rules built into lowering,
+/// like those for auto traits. Then the clauses of its negative impls.
+fn print_clauses(program: &Program, name: &str) {
+    let trait_id = program.ir.type_kinds.iter().find(|&(_, kind)| {
+        kind.sort == ir::TypeSort::Trait && kind.name.to_string() == name
+    });
+    let trait_id = match trait_id {
+        Some((&trait_id, _)) => trait_id,
+        None => {
+            println!("error: no trait is called `{}`", name);
+            return;
+        }
+    };
+    let concludes = |clause: &&ir::ProgramClause| {
+        match clause.implication.value.consequence {
+            ir::WhereClauseGoal::Implemented(ref trait_ref) => trait_ref.trait_id == trait_id,
+            _ => false,
+        }
+    };
+    for clause in program.environment.program_clauses.iter().filter(&concludes) {
+        print_clause(clause);
+    }
+    let negative: Vec<_> = program.environment.negative_impl_clauses.iter().filter(&concludes).collect();
+    if !negative.is_empty() {
+        println!("negative impls:");
+        for clause in negative {
+            print_clause(clause);
+        }
+    }
+}
+
+/// Prints `clause` as `consequence :- conditions`, or just its
+/// consequence if it has no conditions.
+fn print_clause(clause: &ir::ProgramClause) {
+    let implication = &clause.implication.value;
+    if implication.conditions.is_empty() {
+        println!("{:?}", implication.consequence);
+    } else {
+        let conditions: Vec<_> = implication.conditions.iter().map(|goal| format!("{:?}", goal)).collect();
+        println!("{:?} :- {}", implication.consequence, conditions.join(", "));
+    }
+}
+
+/// Where the history is kept, if there is a home directory to keep it
+/// in.
+fn history_path() -> Option<PathBuf> {
+    env::home_dir().map(|home| home.join(".chalki_history"))
+}
+
+fn main() {
+    let mut shell = Shell::new();
+    let history = history_path();
+    if let Some(ref history) = history {
+        // There is no history yet the first time chalki is run.
+        let _ = shell.editor.load_history(history);
+    }
+    if let Some(path) = env::args().nth(1) {
+        shell.process(&format!(":load {}", path));
+    }
+    loop {
+        let line = match shell.read_line("?- ") {
+            Some(line) => line,
+            None => break,
+        };
+        if let Next::Quit = shell.process(line.trim()) {
+            break;
+        }
+    }
+    if let Some(ref history) = history {
+        if let Err(e) = shell.editor.save_history(history) {
+            println!("error: cannot save the history: {}", e);
+        }
+    }
+}
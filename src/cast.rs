@@ -50,12 +50,72 @@ impl Cast<WhereClauseGoal> for Normalize {
     }
 }
 
+impl Cast<WhereClause> for NormalizeConst {
+    fn cast(self) -> WhereClause {
+        WhereClause::NormalizeConst(self)
+    }
+}
+
+impl Cast<WhereClauseGoal> for NormalizeConst {
+    fn cast(self) -> WhereClauseGoal {
+        WhereClauseGoal::NormalizeConst(self)
+    }
+}
+
+impl Cast<WhereClause> for TypeOutlives {
+    fn cast(self) -> WhereClause {
+        WhereClause::TypeOutlives(self)
+    }
+}
+
+impl Cast<WhereClauseGoal> for TypeOutlives {
+    fn cast(self) -> WhereClauseGoal {
+        WhereClauseGoal::TypeOutlives(self)
+    }
+}
+
+impl Cast<WhereClause> for LifetimeOutlives {
+    fn cast(self) -> WhereClause {
+        WhereClause::LifetimeOutlives(self)
+    }
+}
+
+impl Cast<WhereClauseGoal> for LifetimeOutlives {
+    fn cast(self) -> WhereClauseGoal {
+        WhereClauseGoal::LifetimeOutlives(self)
+    }
+}
+
 impl Cast<WhereClauseGoal> for LocalTo<Ty> {
     fn cast(self) -> WhereClauseGoal {
         WhereClauseGoal::TyLocalTo(self)
     }
 }
 
+impl Cast<WhereClause> for WellFormed {
+    fn cast(self) -> WhereClause {
+        WhereClause::WellFormed(self)
+    }
+}
+
+impl Cast<WhereClauseGoal> for Subtype {
+    fn cast(self) -> WhereClauseGoal {
+        WhereClauseGoal::Subtype(self)
+    }
+}
+
+impl Cast<WhereClauseGoal> for Unsize {
+    fn cast(self) -> WhereClauseGoal {
+        WhereClauseGoal::Unsize(self)
+    }
+}
+
+impl Cast<WhereClauseGoal> for Numeric {
+    fn cast(self) -> WhereClauseGoal {
+        WhereClauseGoal::Numeric(self)
+    }
+}
+
 impl Cast<WhereClauseGoal> for WellFormed {
     fn cast(self) -> WhereClauseGoal {
         WhereClauseGoal::WellFormed(self)
@@ -101,24 +161,30 @@ impl Cast<Goal> for Not<Unify<Ty>> {
     }
 }
 
-impl Cast<WhereClauseGoal> for WhereClause {
-    fn cast(self) -> WhereClauseGoal {
-        match self {
-            WhereClause::Implemented(a) => a.cast(),
-            WhereClause::Normalize(a) => a.cast(),
-        }
-    }
-}
-
 impl Cast<Goal> for TraitRef {
     fn cast(self) -> Goal {
         Goal::Leaf(self.cast())
     }
 }
 
+/// Proving a where-clause; a higher-ranked one must hold for all
+/// choices of its bound parameters.
 impl Cast<Goal> for WhereClause {
     fn cast(self) -> Goal {
-        Goal::Leaf(self.cast())
+        match self {
+            WhereClause::Implemented(a) => a.cast(),
+            WhereClause::Normalize(a) => a.cast(),
+            WhereClause::NormalizeConst(a) => Goal::Leaf(a.cast()),
+            WhereClause::TypeOutlives(a) => Goal::Leaf(a.cast()),
+            WhereClause::LifetimeOutlives(a) => Goal::Leaf(a.cast()),
+            WhereClause::WellFormed(a) => a.cast(),
+            WhereClause::ForAll(binders) => {
+                Goal::Quantified(QuantifierKind::ForAll, binders.map_ref(|wc| {
+                    let wc: WhereClause = (**wc).clone();
+                    Box::new(wc.cast())
+                }))
+            }
+        }
     }
 }
 
@@ -163,7 +229,7 @@ macro_rules! map_impl {
 }
 
 map_impl!(impl[T: Cast<U>, U] Cast<Option<U>> for Option<T>);
-map_impl!(impl[T: Cast<U>, U] Cast<Query<U>> for Query<T>);
+map_impl!(impl[T: Cast<U>, U] Cast<Canonical<U>> for Canonical<T>);
 map_impl!(impl[T: Cast<U>, U] Cast<Solution<U>> for Solution<T>);
 map_impl!(impl[T: Cast<U>, U] Cast<InEnvironment<U>> for InEnvironment<T>);
 map_impl!(impl[T: Cast<U>, U] Cast<Constrained<U>> for Constrained<T>);
@@ -0,0 +1,252 @@
+//! Coherence: the rules ensuring that any given trait-ref is
+//! implemented by at most one impl, except where one impl explicitly
+//! specializes another, and that impls only appear in crates that are
+//! entitled to write them (the orphan rules).
+
+use cast::Cast;
+use errors::*;
+use fold::Shifter;
+use ir::*;
+use solve::goal::Prove;
+use solve::solver::Solver;
+use solve::Successful;
+use std::sync::Arc;
+
+#[cfg(test)] mod test;
+
+/// Checks the impls of a freshly lowered program for coherence,
+/// recording which impls specialize which along the way.
+pub fn check_coherence(program: &mut Program) -> Result<()> {
+    let env: Arc<ChalkDatabase> = Arc::new(program.environment());
+    check_orphan_rules(program, &env)?;
+    record_specializations(program, &env)?;
+    check_overlap(program, &env)
+}
+
+/// Reports an error for any impl that is neither of a trait defined in
+/// the impl's own crate nor for some type local to that crate. Local
+/// types include fundamental types applied to local types, like
+/// `Box<Local>`. That is, for `impl<T...> Trait<P1...Pn> for P0` in
+/// crate `C`, either `Trait` is defined in `C` or, for some `Pi`:
+///
+/// ```notrust
+/// forall<T...> { LocalTo(Pi, C) }
+/// ```
+fn check_orphan_rules(program: &Program, env: &Arc<ChalkDatabase>) -> Result<()> {
+    for impl_id in sorted_impl_ids(program) {
+        let impl_datum = &program.impl_data[&impl_id];
+        let trait_ref = impl_datum.binders.value.trait_ref.trait_ref();
+        if program.trait_data[&trait_ref.trait_id].krate_id == impl_datum.krate_id {
+            continue;
+        }
+
+        let has_local_type = trait_ref.parameters.iter().any(|parameter| {
+            let ty = match *parameter {
+                ParameterKind::Ty(ref ty) => ty.clone(),
+                _ => return false,
+            };
+            let goal = Goal::Quantified(QuantifierKind::ForAll, Binders {
+                binders: impl_datum.binders.binders.clone(),
+                value: Box::new(Goal::Leaf(LocalTo {
+                    value: ty,
+                    krate: Krate::Id(impl_datum.krate_id),
+                }.cast())),
+            });
+            prove(env, goal) == Some(Successful::Yes)
+        });
+
+        if !has_local_type {
+            bail!(ErrorKind::OrphanImpl(program.type_kinds[&trait_ref.trait_id].name,
+                                        program.impl_spans[&impl_id]));
+        }
+    }
+    Ok(())
+}
+
+/// Impl ids in a deterministic order, so that the errors we report
+/// don't depend on hashing.
+fn sorted_impl_ids(program: &Program) -> Vec<ItemId> {
+    let mut impl_ids: Vec<ItemId> = program.impl_data.keys().cloned().collect();
+    impl_ids.sort();
+    impl_ids
+}
+
+/// Reports an error if two positive impls of the same trait might
+/// apply to the same trait-ref, unless the trait is a marker trait or
+/// one impl specializes the other, which must be specializable.
+/// (Negative impls are meant to carve exceptions out of positive
+/// ones, so they may overlap freely.)
+fn check_overlap(program: &Program, env: &Arc<ChalkDatabase>) -> Result<()> {
+    let impl_ids = sorted_impl_ids(program);
+    for (index, a) in impl_ids.iter().enumerate() {
+        for b in &impl_ids[index + 1..] {
+            let a_datum = &program.impl_data[a];
+            let b_datum = &program.impl_data[b];
+            let trait_id = a_datum.binders.value.trait_ref.trait_ref().trait_id;
+            if trait_id != b_datum.binders.value.trait_ref.trait_ref().trait_id {
+                continue;
+            }
+
+            let exempt =
+                program.trait_data[&trait_id].binders.value.flags.marker ||
+                !a_datum.binders.value.trait_ref.is_positive() ||
+                !b_datum.binders.value.trait_ref.is_positive() ||
+                (a_datum.specializes.contains(b) && is_specializable(b_datum)) ||
+                (b_datum.specializes.contains(a) && is_specializable(a_datum));
+
+            if !exempt && overlaps(env, a_datum, b_datum) {
+                bail!(ErrorKind::OverlappingImpls(program.type_kinds[&trait_id].name,
+                                                  program.impl_spans[b]));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// True if some trait-ref might be implemented by both `a` and `b`,
+/// i.e., if we can't rule out:
+///
+/// ```notrust
+/// exists<A...> {
+///     exists<B...> { TraitRef_A = TraitRef_B, WC_A, WC_B }
+/// }
+/// ```
+fn overlaps(env: &Arc<ChalkDatabase>, a: &ImplDatum, b: &ImplDatum) -> bool {
+    let num_b_binders = b.binders.len();
+    let a_trait_ref = a.binders.value.trait_ref.trait_ref().up_shift(num_b_binders);
+    let b_trait_ref = b.binders.value.trait_ref.trait_ref();
+    let mut goal = unify_trait_refs(&a_trait_ref, b_trait_ref);
+    for wc in &a.binders.value.where_clauses {
+        goal = and(goal, Shifter::up_shift(num_b_binders, wc));
+    }
+    for wc in &b.binders.value.where_clauses {
+        goal = and(goal, wc.clone());
+    }
+
+    let goal = Goal::Quantified(QuantifierKind::Exists, Binders {
+        binders: a.binders.binders.clone(),
+        value: Box::new(Goal::Quantified(QuantifierKind::Exists, Binders {
+            binders: b.binders.binders.clone(),
+            value: Box::new(goal),
+        })),
+    });
+
+    prove(env, goal).is_some()
+}
+
+/// Determines, for every pair of positive impls of the same trait,
+/// whether one specializes the other, and records it in
+/// `ImplDatum::specializes`. Impls may only override associated
+/// types that the impls they specialize declared `default`.
+fn record_specializations(program: &mut Program, env: &Arc<ChalkDatabase>) -> Result<()> {
+    let impl_ids = sorted_impl_ids(program);
+    for &a in &impl_ids {
+        for &b in &impl_ids {
+            if a == b {
+                continue;
+            }
+
+            let specializes = {
+                let a_datum = &program.impl_data[&a];
+                let b_datum = &program.impl_data[&b];
+                let a_trait_ref = a_datum.binders.value.trait_ref.trait_ref();
+                let b_trait_ref = b_datum.binders.value.trait_ref.trait_ref();
+                a_datum.binders.value.trait_ref.is_positive() &&
+                    b_datum.binders.value.trait_ref.is_positive() &&
+                    a_trait_ref.trait_id == b_trait_ref.trait_id &&
+                    is_subset(env, a_datum, b_datum) &&
+                    !is_subset(env, b_datum, a_datum)
+            };
+
+            if specializes {
+                check_overrides(program, &program.impl_data[&a], &program.impl_data[&b])
+                    .map_err(|e| e.at(program.impl_spans[&a]))?;
+                program.impl_data.get_mut(&a).unwrap().specializes.push(b);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// True if every trait-ref implemented by `a` is also implemented by
+/// `b`, i.e., if we can prove:
+///
+/// ```notrust
+/// forall<A...> {
+///     if (WC_A) {
+///         exists<B...> { TraitRef_A = TraitRef_B, WC_B }
+///     }
+/// }
+/// ```
+fn is_subset(env: &Arc<ChalkDatabase>, a: &ImplDatum, b: &ImplDatum) -> bool {
+    let num_b_binders = b.binders.len();
+
+    let a_trait_ref = a.binders.value.trait_ref.trait_ref().up_shift(num_b_binders);
+    let b_trait_ref = b.binders.value.trait_ref.trait_ref();
+    let mut b_goal = unify_trait_refs(&a_trait_ref, b_trait_ref);
+    for wc in &b.binders.value.where_clauses {
+        b_goal = and(b_goal, wc.clone());
+    }
+
+    let goal = Goal::Quantified(QuantifierKind::ForAll, Binders {
+        binders: a.binders.binders.clone(),
+        value: Box::new(Goal::Implies(
+            a.binders.value.where_clauses.clone(),
+            Box::new(Goal::Quantified(QuantifierKind::Exists, Binders {
+                binders: b.binders.binders.clone(),
+                value: Box::new(b_goal),
+            })),
+        )),
+    });
+
+    prove(env, goal) == Some(Successful::Yes)
+}
+
+/// True if `impl_datum` opts into being specialized, either as a
+/// whole (`default impl`) or through its `default` items.
+fn is_specializable(impl_datum: &ImplDatum) -> bool {
+    impl_datum.default || impl_datum.binders.value.associated_ty_values.iter().any(|v| v.default)
+}
+
+/// Checks that `a`, which specializes `b`, only overrides associated
+/// types that `b` declared `default`.
+fn check_overrides(program: &Program, a: &ImplDatum, b: &ImplDatum) -> Result<()> {
+    for a_value in &a.binders.value.associated_ty_values {
+        let overridden = b.binders.value.associated_ty_values.iter().find(|b_value| {
+            b_value.associated_ty_id == a_value.associated_ty_id
+        });
+        if let Some(b_value) = overridden {
+            if !b_value.default {
+                let name = program.associated_ty_data[&b_value.associated_ty_id].name;
+                bail!("cannot specialize non-default associated type `{}`", name);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// A goal unifying two trait-refs, which we express by packaging up
+/// their parameters as applications of the trait's (type) name.
+fn unify_trait_refs(a: &TraitRef, b: &TraitRef) -> Goal {
+    let apply = |trait_ref: &TraitRef| -> Ty {
+        Ty::Apply(ApplicationTy {
+            name: TypeName::ItemId(trait_ref.trait_id),
+            parameters: trait_ref.parameters.clone(),
+        })
+    };
+    Goal::Leaf(WhereClauseGoal::UnifyTys(Unify { a: apply(a), b: apply(b) }))
+}
+
+fn and(goal: Goal, wc: WhereClause) -> Goal {
+    Goal::And(Box::new(goal), Box::new(wc.cast()))
+}
+
+/// How successfully `goal` can be proven, if at all.
+fn prove(env: &Arc<ChalkDatabase>, goal: Goal) -> Option<Successful> {
+    let mut solver = Solver::new(env, 10);
+    match Prove::new(&mut solver, Box::new(goal)).solve() {
+        Ok(solution) => Some(solution.successful()),
+        Err(_) => None,
+    }
+}
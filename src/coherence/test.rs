@@ -0,0 +1,166 @@
+#![cfg(test)]
+
+use chalk_parse;
+use errors::*;
+use ir::*;
+use lower::LowerProgram;
+
+fn parse_and_lower(text: &str) -> Result<Program> {
+    chalk_parse::parse_program(text)?.lower()
+}
+
+macro_rules! coherence_error {
+    (program $program:tt error_msg { $expected:expr }) => {
+        let program_text = stringify!($program);
+        assert!(program_text.starts_with("{"));
+        assert!(program_text.ends_with("}"));
+        let error = parse_and_lower(&program_text[1..program_text.len()-1]).unwrap_err();
+        let expected = Error::from($expected);
+        assert_eq!(
+            error.to_string(),
+            expected.to_string()
+        );
+    }
+}
+
+#[test]
+fn overlapping_impls() {
+    coherence_error! {
+        program {
+            trait Foo { }
+            struct Bar { }
+            impl<T> Foo for T { }
+            impl Foo for Bar { }
+        }
+        error_msg {
+            "overlapping impls of trait `Foo`"
+        }
+    }
+
+    coherence_error! {
+        program {
+            trait Foo { }
+            trait A { }
+            trait B { }
+            struct Bar { }
+            impl A for Bar { }
+            impl B for Bar { }
+            impl<T> Foo for T where T: A { }
+            impl<T> Foo for T where T: B { }
+        }
+        error_msg {
+            "overlapping impls of trait `Foo`"
+        }
+    }
+
+    coherence_error! {
+        program {
+            trait Foo<T> { }
+            struct Bar { }
+            struct Vec<T> { }
+            impl<T> Foo<T> for Vec<Bar> { }
+            impl<T> Foo<Bar> for Vec<T> { }
+        }
+        error_msg {
+            "overlapping impls of trait `Foo`"
+        }
+    }
+}
+
+#[test]
+fn non_overlapping_impls() {
+    parse_and_lower("trait Foo { } struct Bar { } struct Baz { } struct Vec<T> { } \
+                     impl Foo for Bar { } impl Foo for Baz { } impl<T> Foo for Vec<T> { }")
+        .unwrap();
+
+    // The where clauses rule out `Baz`.
+    parse_and_lower("trait Foo { } trait Copy { } struct Bar { } struct Baz { } \
+                     impl Copy for Bar { } \
+                     impl<T> Foo for T where T: Copy { } impl Foo for Baz { }")
+        .unwrap();
+}
+
+#[test]
+fn overlap_exemptions() {
+    // marker traits
+    parse_and_lower("#[marker] trait Foo { } struct Bar { } \
+                     impl<T> Foo for T { } impl Foo for Bar { }")
+        .unwrap();
+
+    // negative impls
+    parse_and_lower("trait Foo { } struct Bar { } \
+                     impl<T> Foo for T { } impl !Foo for Bar { }")
+        .unwrap();
+
+    // specialization, which the general impl must opt into
+    parse_and_lower("trait Foo { } struct Bar { } \
+                     default impl<T> Foo for T { } impl Foo for Bar { }")
+        .unwrap();
+    parse_and_lower("trait Foo { type Item; } struct Bar { } \
+                     impl<T> Foo for T { default type Item = T; } impl Foo for Bar { type Item = Bar; }")
+        .unwrap();
+}
+
+#[test]
+fn orphan_rules() {
+    coherence_error! {
+        program {
+            crate std {
+                trait Clone { }
+                struct Vec<T> { }
+            }
+            impl<T> Clone for Vec<T> { }
+        }
+        error_msg {
+            "impl of trait `Clone` violates the orphan rules: \
+             neither the trait nor any of its type parameters are local"
+        }
+    }
+
+    coherence_error! {
+        program {
+            #[upstream] trait Clone { }
+            #[upstream] struct Vec<T> { }
+            struct Local { }
+            impl<T> Clone for Vec<T> { }
+        }
+        error_msg {
+            "impl of trait `Clone` violates the orphan rules: \
+             neither the trait nor any of its type parameters are local"
+        }
+    }
+
+    coherence_error! {
+        program {
+            #[upstream] trait Clone { }
+            #[upstream] struct Vec<T> { }
+            struct Local { }
+            impl<T> Clone for T { }
+        }
+        error_msg {
+            "impl of trait `Clone` violates the orphan rules: \
+             neither the trait nor any of its type parameters are local"
+        }
+    }
+}
+
+#[test]
+fn orphan_rules_local_impls() {
+    // local trait
+    parse_and_lower("#[upstream] struct Vec<T> { } trait Foo { } impl<T> Foo for Vec<T> { }").unwrap();
+
+    // local self type, or a local type among the trait's parameters
+    parse_and_lower("#[upstream] trait Into<T> { } struct Local { } impl<T> Into<T> for Local { }")
+        .unwrap();
+    parse_and_lower("#[upstream] trait Into<T> { } #[upstream] struct Vec<T> { } struct Local { } \
+                     impl Into<Local> for Vec<Local> { }")
+        .unwrap();
+
+    // fundamental types applied to local types
+    parse_and_lower("#[upstream] trait Clone { } #[upstream] #[fundamental] struct Box<T> { } \
+                     struct Local { } impl Clone for Box<Local> { }")
+        .unwrap();
+    parse_and_lower("#[upstream] trait Clone { } #[upstream] struct Vec<T> { } \
+                     struct Local { } impl Clone for Vec<Local> { }")
+        .unwrap_err();
+}
@@ -1,4 +1,5 @@
 use chalk_parse::{self, ast};
+use ir;
 
 error_chain! {
     links {
@@ -27,6 +28,261 @@ error_chain! {
             description("not a trait")
             display("expected a trait, found `{}`, which is not a trait", identifier.str)
         }
+
+        NotObjectSafe(identifier: ast::Identifier) {
+            description("trait is not object safe")
+            display("the trait `{}` cannot be made into an object", identifier.str)
+        }
+
+        UnknownAttribute(attribute: ast::Identifier) {
+            description("unknown attribute")
+            display("unknown attribute `#[{}]`", attribute.str)
+        }
+
+        AttributeArity(attribute: ast::Identifier, expected: usize, actual: usize) {
+            description("wrong number of attribute arguments")
+            display("attribute `#[{}]` takes {} arguments, not {}", attribute.str, expected, actual)
+        }
+
+        InvalidLifetimeName(identifier: ast::Identifier) {
+            description("invalid lifetime name")
+            display("invalid lifetime name: {:?}", identifier.str)
+        }
+
+        InvalidConstName(identifier: ast::Identifier) {
+            description("invalid const name")
+            display("invalid const name: {:?}", identifier.str)
+        }
+
+        UnknownLangItem(lang: ast::Identifier) {
+            description("unknown lang item")
+            display("unknown lang item `{}`", lang.str)
+        }
+
+        DuplicateLangItem(lang: ast::Identifier) {
+            description("duplicate lang item")
+            display("duplicate lang item `{}`", lang.str)
+        }
+
+        DuplicateTypeAlias(identifier: ast::Identifier) {
+            description("duplicate type alias")
+            display("type alias `{}` is defined more than once", identifier.str)
+        }
+
+        DefaultsNotLast(identifier: ast::Identifier) {
+            description("type parameter defaults not last")
+            display("type parameters with defaults must come last in `{}`", identifier.str)
+        }
+
+        DuplicateVariant(variant: ast::Identifier, enum_name: ast::Identifier) {
+            description("duplicate variant")
+            display("duplicate variant `{}` in enum `{}`", variant.str, enum_name.str)
+        }
+
+        DuplicateField(field: ast::Identifier, item: String) {
+            description("duplicate field")
+            display("duplicate field `{}` in {}", field.str, item)
+        }
+
+        UnknownClosureKind(closure: ast::Identifier, kind: ast::Identifier) {
+            description("unknown closure kind")
+            display("closure `{}` has unknown kind `{}`, expected `Fn`, `FnMut` or `FnOnce`",
+                    closure.str, kind.str)
+        }
+
+        NoAssociatedType(identifier: ast::Identifier) {
+            description("no such associated type")
+            display("no associated type `{}` defined in trait", identifier.str)
+        }
+
+        NoAssociatedConst(identifier: ast::Identifier) {
+            description("no such associated const")
+            display("no associated const `{}` defined in trait", identifier.str)
+        }
+
+        IncorrectNumberOfAssociatedTyParameters(identifier: ast::Identifier,
+                                                expected: usize,
+                                                actual: usize) {
+            description("incorrect number of associated type parameters")
+            display("wrong number of parameters for associated type (expected {}, got {})",
+                    expected, actual)
+        }
+
+        AssociatedTyParametersMismatch(identifier: ast::Identifier) {
+            description("associated type parameters do not match the trait")
+            display("parameters of associated type `{}` do not match its declaration in the trait",
+                    identifier.str)
+        }
+
+        DynAssociatedTyUnspecified(associated_ty: ir::Identifier, trait_name: ast::Identifier) {
+            description("associated type of a trait object not specified")
+            display("the value of the associated type `{}` in `{}` must be specified",
+                    associated_ty, trait_name.str)
+        }
+
+        DynAssociatedTySpecifiedTwice(associated_ty: ir::Identifier, trait_name: ast::Identifier) {
+            description("associated type of a trait object specified twice")
+            display("the value of the associated type `{}` in `{}` is specified twice",
+                    associated_ty, trait_name.str)
+        }
+
+        MaybeBoundNotSized(trait_name: ast::Identifier) {
+            description("`?` bound on a trait other than `Sized`")
+            display("`?{}` bounds are only allowed for the `Sized` lang item", trait_name.str)
+        }
+
+        InvalidAutoTrait(identifier: ast::Identifier, what: &'static str) {
+            description("invalid auto trait")
+            display("auto trait `{}` cannot {}", identifier.str, what)
+        }
+
+        MarkerTraitWithItems(identifier: ast::Identifier) {
+            description("marker trait with associated items")
+            display("marker trait `{}` cannot have associated items", identifier.str)
+        }
+
+        ImplForOpaqueTy(trait_name: ast::Identifier, opaque_ty: ir::Identifier) {
+            description("impl for an opaque type")
+            display("cannot implement `{}` for opaque type `{}`", trait_name.str, opaque_ty)
+        }
+
+        NegativeImplWithValues(span: ast::Span) {
+            description("negative impl with associated values")
+            display("negative impls cannot define associated values")
+        }
+
+        ImplOfTraitAlias(trait_name: ir::Identifier, span: ast::Span) {
+            description("impl of a trait alias")
+            display("cannot implement trait alias `{}`", trait_name)
+        }
+
+        UnsafeNegativeImpl(trait_name: ir::Identifier, span: ast::Span) {
+            description("unsafe negative impl")
+            display("negative impls of `{}` cannot be unsafe", trait_name)
+        }
+
+        SafeImplOfUnsafeTrait(trait_name: ir::Identifier, span: ast::Span) {
+            description("safe impl of an unsafe trait")
+            display("implementing the unsafe trait `{}` requires an `unsafe impl`", trait_name)
+        }
+
+        UnsafeImplOfSafeTrait(trait_name: ir::Identifier, span: ast::Span) {
+            description("unsafe impl of a safe trait")
+            display("implementing the trait `{}` is not unsafe", trait_name)
+        }
+
+        OverlappingImpls(trait_name: ir::Identifier, span: ast::Span) {
+            description("overlapping impls")
+            display("overlapping impls of trait `{}`", trait_name)
+        }
+
+        OrphanImpl(trait_name: ir::Identifier, span: ast::Span) {
+            description("orphan impl")
+            display("impl of trait `{}` violates the orphan rules: \
+                     neither the trait nor any of its type parameters are local", trait_name)
+        }
+
+        IllFormedImpl(trait_name: ir::Identifier, obligation: String, span: ast::Span) {
+            description("ill-formed impl")
+            display("impl of trait `{}` is not well-formed: cannot prove `{}`", trait_name, obligation)
+        }
+
+        // Any other error, once we know where it was found; see
+        // `Error::at`.
+        Located(message: String, span: ast::Span) {
+            description("error")
+            display("{}", message)
+        }
+
+        PlaceholderLeak(placeholder: String, universe: ir::UniverseIndex) {
+            description("placeholder escapes its binder")
+            display("`{}` would escape the `forall` that introduced it, \
+                     into a variable of universe {:?}", placeholder, universe)
+        }
+
+        TimedOut {
+            description("timed out")
+            display("timed out before the goal was solved")
+        }
     }
 }
 
+impl ErrorKind {
+    /// Where in the program text the error was found: the name it is
+    /// about, for the errors that are about a name, and otherwise the
+    /// item or where clause it was found in.
+    pub fn span(&self) -> Option<ast::Span> {
+        match *self {
+            ErrorKind::InvalidTypeName(ref identifier) |
+            ErrorKind::CannotApplyTypeParameter(ref identifier) |
+            ErrorKind::IncorrectNumberOfTypeParameters(ref identifier, ..) |
+            ErrorKind::NotTrait(ref identifier) |
+            ErrorKind::NotObjectSafe(ref identifier) |
+            ErrorKind::UnknownAttribute(ref identifier) |
+            ErrorKind::AttributeArity(ref identifier, ..) |
+            ErrorKind::InvalidLifetimeName(ref identifier) |
+            ErrorKind::InvalidConstName(ref identifier) |
+            ErrorKind::UnknownLangItem(ref identifier) |
+            ErrorKind::DuplicateLangItem(ref identifier) |
+            ErrorKind::DuplicateTypeAlias(ref identifier) |
+            ErrorKind::DefaultsNotLast(ref identifier) |
+            ErrorKind::DuplicateVariant(ref identifier, _) |
+            ErrorKind::DuplicateField(ref identifier, _) |
+            ErrorKind::UnknownClosureKind(_, ref identifier) |
+            ErrorKind::NoAssociatedType(ref identifier) |
+            ErrorKind::NoAssociatedConst(ref identifier) |
+            ErrorKind::IncorrectNumberOfAssociatedTyParameters(ref identifier, ..) |
+            ErrorKind::AssociatedTyParametersMismatch(ref identifier) |
+            ErrorKind::DynAssociatedTyUnspecified(_, ref identifier) |
+            ErrorKind::DynAssociatedTySpecifiedTwice(_, ref identifier) |
+            ErrorKind::MaybeBoundNotSized(ref identifier) |
+            ErrorKind::InvalidAutoTrait(ref identifier, _) |
+            ErrorKind::MarkerTraitWithItems(ref identifier) |
+            ErrorKind::ImplForOpaqueTy(ref identifier, _) => Some(identifier.span),
+            ErrorKind::NegativeImplWithValues(span) |
+            ErrorKind::ImplOfTraitAlias(_, span) |
+            ErrorKind::UnsafeNegativeImpl(_, span) |
+            ErrorKind::SafeImplOfUnsafeTrait(_, span) |
+            ErrorKind::UnsafeImplOfSafeTrait(_, span) |
+            ErrorKind::OverlappingImpls(_, span) |
+            ErrorKind::OrphanImpl(_, span) |
+            ErrorKind::IllFormedImpl(_, _, span) |
+            ErrorKind::Located(_, span) => Some(span),
+            _ => None,
+        }
+    }
+}
+
+impl Error {
+    /// Points the error at `span`, the item or where clause it was
+    /// found in, unless it already points at something within it.
+    pub fn at(self, span: ast::Span) -> Error {
+        if self.0.span().is_some() {
+            return self;
+        }
+        ErrorKind::Located(self.to_string(), span).into()
+    }
+
+    /// The message of the error, prefixed with where it was found in
+    /// `text`, the program it came from, if that is known: "error at
+    /// line 14, column 7: invalid type name `Seralize`".
+    pub fn located(&self, text: &str) -> String {
+        self.located_in(&[text])
+    }
+
+    /// As `located`, for a program parsed from several sources with
+    /// `parse_programs`: "error in source 1 at line 3, column 9: ...".
+    pub fn located_in(&self, texts: &[&str]) -> String {
+        match self.0.span() {
+            Some(span) if span.source < texts.len() => {
+                let (line, column) = span.line_column(texts[span.source]);
+                if texts.len() == 1 {
+                    format!("error at line {}, column {}: {}", line, column, self)
+                } else {
+                    format!("error in source {} at line {}, column {}: {}", span.source, line, column, self)
+                }
+            }
+            _ => format!("error: {}", self),
+        }
+    }
+}
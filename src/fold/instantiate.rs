@@ -72,4 +72,15 @@ impl<'b> Folder for Subst<'b> {
             }
         }
     }
+
+    fn fold_free_const_var(&mut self, depth: usize, binders: usize) -> Result<Const> {
+        if depth >= self.parameters.len() {
+            Ok(Const::Var(depth - self.parameters.len() + binders))
+        } else {
+            match self.parameters[depth] {
+                ParameterKind::Const(ref n) => Ok(n.up_shift(binders)),
+                _ => panic!("mismatched kinds in substitution"),
+            }
+        }
+    }
 }
@@ -15,6 +15,14 @@ pub trait Folder {
     fn fold_free_var(&mut self, depth: usize, binders: usize) -> Result<Ty>;
     fn fold_free_lifetime_var(&mut self, depth: usize, binders: usize) -> Result<Lifetime>;
     fn fold_free_krate_var(&mut self, depth: usize, binders: usize) -> Result<Krate>;
+    fn fold_free_const_var(&mut self, depth: usize, binders: usize) -> Result<Const>;
+
+    /// Invoked for each universe a value mentions: that of each
+    /// placeholder, like `!1`, and of each environment. Most folders
+    /// leave them alone.
+    fn fold_universe(&mut self, universe: UniverseIndex) -> UniverseIndex {
+        universe
+    }
 }
 
 impl<'f, F: Folder + ?Sized> Folder for &'f mut F {
@@ -29,6 +37,14 @@ impl<'f, F: Folder + ?Sized> Folder for &'f mut F {
     fn fold_free_krate_var(&mut self, depth: usize, binders: usize) -> Result<Krate> {
         (**self).fold_free_krate_var(depth, binders)
     }
+
+    fn fold_free_const_var(&mut self, depth: usize, binders: usize) -> Result<Const> {
+        (**self).fold_free_const_var(depth, binders)
+    }
+
+    fn fold_universe(&mut self, universe: UniverseIndex) -> UniverseIndex {
+        (**self).fold_universe(universe)
+    }
 }
 
 impl<F1: Folder, F2: Folder> Folder for (F1, F2) {
@@ -43,6 +59,15 @@ impl<F1: Folder, F2: Folder> Folder for (F1, F2) {
     fn fold_free_krate_var(&mut self, depth: usize, binders: usize) -> Result<Krate> {
         self.0.fold_free_krate_var(depth, binders)?.fold_with(&mut self.1, binders)
     }
+
+    fn fold_free_const_var(&mut self, depth: usize, binders: usize) -> Result<Const> {
+        self.0.fold_free_const_var(depth, binders)?.fold_with(&mut self.1, binders)
+    }
+
+    fn fold_universe(&mut self, universe: UniverseIndex) -> UniverseIndex {
+        let universe = self.0.fold_universe(universe);
+        self.1.fold_universe(universe)
+    }
 }
 
 pub trait Fold: Debug {
@@ -143,7 +168,8 @@ impl Fold for Lifetime {
             } else {
                 Ok(Lifetime::Var(depth))
             },
-            Lifetime::ForAll(universe) => Ok(Lifetime::ForAll(universe)),
+            Lifetime::ForAll(universe) => Ok(Lifetime::ForAll(folder.fold_universe(universe))),
+            Lifetime::Static => Ok(Lifetime::Static),
         }
     }
 }
@@ -162,6 +188,21 @@ impl Fold for Krate {
     }
 }
 
+impl Fold for Const {
+    type Result = Self;
+    fn fold_with(&self, folder: &mut Folder, binders: usize) -> Result<Self::Result> {
+        match *self {
+            Const::Var(depth) => if depth >= binders {
+                folder.fold_free_const_var(depth - binders, binders)
+            } else {
+                Ok(Const::Var(depth))
+            },
+            Const::ForAll(universe) => Ok(Const::ForAll(folder.fold_universe(universe))),
+            Const::Value(value) => Ok(Const::Value(value)),
+        }
+    }
+}
+
 macro_rules! copy_fold {
     ($t:ty) => {
         impl Fold for $t {
@@ -178,12 +219,31 @@ macro_rules! copy_fold {
 
 copy_fold!(Identifier);
 copy_fold!(KrateId);
-copy_fold!(UniverseIndex);
 copy_fold!(ItemId);
-copy_fold!(TypeName);
+copy_fold!(ScalarType);
+copy_fold!(Mutability);
+copy_fold!(NumericKind);
 copy_fold!(usize);
+copy_fold!(bool);
 copy_fold!(QuantifierKind);
 
+impl Fold for UniverseIndex {
+    type Result = Self;
+    fn fold_with(&self, folder: &mut Folder, _binders: usize) -> Result<Self::Result> {
+        Ok(folder.fold_universe(*self))
+    }
+}
+
+impl Fold for TypeName {
+    type Result = Self;
+    fn fold_with(&self, folder: &mut Folder, _binders: usize) -> Result<Self::Result> {
+        match *self {
+            TypeName::ForAll(universe) => Ok(TypeName::ForAll(folder.fold_universe(universe))),
+            name => Ok(name),
+        }
+    }
+}
+
 macro_rules! enum_fold {
     ($s:ident [$($n:ident),*] { $($variant:ident($($name:ident),*)),* } $($w:tt)*) => {
         impl<$($n),*> Fold for $s<$($n),*> $($w)* {
@@ -201,14 +261,18 @@ macro_rules! enum_fold {
     }
 }
 
-enum_fold!(ParameterKind[T,L, C] { Ty(a), Lifetime(a), Krate(a) } where T: Fold, L: Fold, C: Fold);
-enum_fold!(WhereClause[] { Implemented(a), Normalize(a) });
+enum_fold!(ParameterKind[T, L, C, N] { Ty(a), Lifetime(a), Krate(a), Const(a) }
+           where T: Fold, L: Fold, C: Fold, N: Fold);
+enum_fold!(WhereClause[] { Implemented(a), Normalize(a), NormalizeConst(a), TypeOutlives(a),
+                           LifetimeOutlives(a), WellFormed(a), ForAll(a) });
 enum_fold!(WellFormed[] { Ty(a), TraitRef(a) });
-enum_fold!(WhereClauseGoal[] { Implemented(a), Normalize(a), UnifyTys(a), UnifyKrates(a),
-                               UnifyLifetimes(a), WellFormed(a), TyLocalTo(a), NotImplemented(a),
+enum_fold!(WhereClauseGoal[] { Implemented(a), Normalize(a), NormalizeConst(a), TypeOutlives(a),
+                               LifetimeOutlives(a), UnifyTys(a), Subtype(a), UnifyKrates(a), UnifyLifetimes(a),
+                               WellFormed(a), TyLocalTo(a), Unsize(a), Numeric(a), NotImplemented(a),
                                NotNormalize(a), NotUnifyTys(a) });
-enum_fold!(Constraint[] { LifetimeEq(a, b) });
-enum_fold!(Goal[] { Quantified(qkind, subgoal), Implies(wc, subgoal), And(g1, g2), Leaf(wc) });
+enum_fold!(Constraint[] { LifetimeEq(a, b), TypeOutlives(a, b), LifetimeOutlives(a, b) });
+enum_fold!(Goal[] { Quantified(qkind, subgoal), Implies(wc, subgoal), And(g1, g2), Not(g),
+                    Leaf(wc) });
 
 macro_rules! struct_fold {
     ($s:ident $([$($n:ident),*])* { $($name:ident),* } $($w:tt)*) => {
@@ -227,12 +291,20 @@ struct_fold!(ApplicationTy { name, parameters });
 struct_fold!(ProjectionTy { associated_ty_id, parameters });
 struct_fold!(TraitRef { trait_id, parameters });
 struct_fold!(Normalize { projection, ty });
-struct_fold!(AssociatedTyValue { associated_ty_id, value });
+struct_fold!(ConstProjection { associated_const_id, parameters });
+struct_fold!(NormalizeConst { projection, value });
+struct_fold!(TypeOutlives { ty, lifetime });
+struct_fold!(LifetimeOutlives { a, b });
+struct_fold!(AssociatedTyValue { associated_ty_id, value, default });
 struct_fold!(AssociatedTyValueBound { ty, where_clauses });
 struct_fold!(Environment { universe, clauses });
 struct_fold!(InEnvironment[F] { environment, goal } where F: Fold);
 struct_fold!(Unify[T] { a, b } where T: Fold);
 struct_fold!(Constrained[F] { value, constraints } where F: Fold);
+struct_fold!(Conditional[F] { value, constraints, goals } where F: Fold);
 struct_fold!(ProgramClauseImplication { consequence, conditions });
 struct_fold!(LocalTo[T] { value, krate } where T: Fold);
+struct_fold!(Unsize { source, target });
+struct_fold!(Numeric { ty, kind });
+struct_fold!(Subtype { a, b });
 struct_fold!(Not[T] { predicate, krate } where T: Fold);
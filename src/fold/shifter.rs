@@ -35,6 +35,7 @@ up_shift_method!(Parameter);
 up_shift_method!(Lifetime);
 up_shift_method!(TraitRef);
 up_shift_method!(Krate);
+up_shift_method!(Const);
 
 impl Folder for Shifter {
     fn fold_free_var(&mut self, depth: usize, binders: usize) -> Result<Ty> {
@@ -48,6 +49,10 @@ impl Folder for Shifter {
     fn fold_free_krate_var(&mut self, depth: usize, binders: usize) -> Result<Krate> {
         Ok(Krate::Var(depth + self.adjustment + binders))
     }
+
+    fn fold_free_const_var(&mut self, depth: usize, binders: usize) -> Result<Const> {
+        Ok(Const::Var(depth + self.adjustment + binders))
+    }
 }
 
 
@@ -0,0 +1,127 @@
+use ir::*;
+
+/// The facts about a program that the solvers look up as they go:
+/// the data of its items, and the clauses that might prove a goal.
+/// `ProgramEnvironment` provides these from a program that chalk has
+/// lowered; an embedder can instead provide them from storage of its
+/// own, as long as it outlives the solve. Each lookup of an item
+/// returns `None` if there is no such item.
+pub trait ChalkDatabase: Send + Sync {
+    fn struct_datum(&self, struct_id: ItemId) -> Option<&StructDatum>;
+
+    fn enum_datum(&self, enum_id: ItemId) -> Option<&EnumDatum>;
+
+    fn trait_datum(&self, trait_id: ItemId) -> Option<&TraitDatum>;
+
+    fn associated_ty_datum(&self, associated_ty_id: ItemId) -> Option<&AssociatedTyDatum>;
+
+    fn associated_const_datum(&self, associated_const_id: ItemId) -> Option<&AssociatedConstDatum>;
+
+    /// The variances of the parameters of a struct or enum.
+    fn variances(&self, id: ItemId) -> Option<&[Variance]>;
+
+    /// The clauses that might prove `goal`, such as those of the impls
+    /// of its trait. It does no harm to include clauses that cannot
+    /// prove `goal` after all, but leaving them out saves trying them.
+    fn program_clauses_for(&self, goal: &WhereClauseGoal) -> Vec<&ProgramClause>;
+
+    /// The clauses for the negative impls of the trait.
+    fn negative_impl_clauses(&self, trait_id: ItemId) -> Vec<&ProgramClause>;
+
+    /// Like `program_clauses_for`, but for the clauses of `default`
+    /// associated type values.
+    fn default_program_clauses_for(&self, goal: &WhereClauseGoal) -> Vec<&DefaultProgramClause>;
+
+    /// Splits the parameters of `projection` into those of the trait
+    /// and those of the associated type itself; `None` if there is no
+    /// such associated type.
+    fn split_projection<'p>(&self, projection: &'p ProjectionTy)
+                            -> Option<(&AssociatedTyDatum, &'p [Parameter], &'p [Parameter])> {
+        let ProjectionTy { associated_ty_id, ref parameters } = *projection;
+        let associated_ty_data = match self.associated_ty_datum(associated_ty_id) {
+            Some(associated_ty_data) => associated_ty_data,
+            None => return None,
+        };
+        let trait_datum = match self.trait_datum(associated_ty_data.trait_id) {
+            Some(trait_datum) => trait_datum,
+            None => return None,
+        };
+        let trait_num_params = trait_datum.binders.len();
+        let split_point = parameters.len() - trait_num_params;
+        let (other_params, trait_params) = parameters.split_at(split_point);
+        Some((associated_ty_data, trait_params, other_params))
+    }
+}
+
+impl ChalkDatabase for ProgramEnvironment {
+    fn struct_datum(&self, struct_id: ItemId) -> Option<&StructDatum> {
+        self.struct_data.get(&struct_id)
+    }
+
+    fn enum_datum(&self, enum_id: ItemId) -> Option<&EnumDatum> {
+        self.enum_data.get(&enum_id)
+    }
+
+    fn trait_datum(&self, trait_id: ItemId) -> Option<&TraitDatum> {
+        self.trait_data.get(&trait_id)
+    }
+
+    fn associated_ty_datum(&self, associated_ty_id: ItemId) -> Option<&AssociatedTyDatum> {
+        self.associated_ty_data.get(&associated_ty_id)
+    }
+
+    fn associated_const_datum(&self, associated_const_id: ItemId) -> Option<&AssociatedConstDatum> {
+        self.associated_const_data.get(&associated_const_id)
+    }
+
+    fn variances(&self, id: ItemId) -> Option<&[Variance]> {
+        self.variances.get(&id).map(|variances| &variances[..])
+    }
+
+    fn program_clauses_for(&self, goal: &WhereClauseGoal) -> Vec<&ProgramClause> {
+        // For a trait goal, only the clauses whose self type has the
+        // same head as the goal's, or could be anything, might apply.
+        if let WhereClauseGoal::Implemented(ref trait_ref) = *goal {
+            let by_head = match self.trait_clause_index.get(&trait_ref.trait_id) {
+                Some(by_head) => by_head,
+                None => return vec![],
+            };
+            let mut positions: Vec<usize> = match goal.self_ty_head() {
+                Some(head) => {
+                    by_head.get(&None)
+                           .into_iter()
+                           .chain(by_head.get(&Some(head)))
+                           .flat_map(|positions| positions.iter().cloned())
+                           .collect()
+                }
+                None => by_head.values().flat_map(|positions| positions.iter().cloned()).collect(),
+            };
+            // Keep the clauses in the order of the program.
+            positions.sort();
+            return positions.into_iter().map(|position| &self.program_clauses[position]).collect();
+        }
+
+        let item_id = goal.item_id();
+        self.program_clauses
+            .iter()
+            .filter(|clause| item_id.is_none() || clause.implication.value.consequence.item_id() == item_id)
+            .collect()
+    }
+
+    fn negative_impl_clauses(&self, trait_id: ItemId) -> Vec<&ProgramClause> {
+        self.negative_impl_clauses
+            .iter()
+            .filter(|clause| clause.implication.value.consequence.item_id() == Some(trait_id))
+            .collect()
+    }
+
+    fn default_program_clauses_for(&self, goal: &WhereClauseGoal) -> Vec<&DefaultProgramClause> {
+        let item_id = goal.item_id();
+        self.default_program_clauses
+            .iter()
+            .filter(|clause| {
+                item_id.is_none() || clause.clause.implication.value.consequence.item_id() == item_id
+            })
+            .collect()
+    }
+}
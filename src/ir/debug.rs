@@ -10,6 +10,8 @@ impl Debug for ItemId {
                     write!(fmt, "{}", k.name)
                 } else if let Some(k) = prog.associated_ty_data.get(self) {
                     write!(fmt, "({:?}::{})", k.trait_id, k.name)
+                } else if let Some(k) = prog.associated_const_data.get(self) {
+                    write!(fmt, "({:?}::{})", k.trait_id, k.name)
                 } else {
                     fmt.debug_struct("ItemId").field("index", &self.index).finish()
                 }
@@ -31,16 +33,29 @@ impl Debug for TypeName {
             TypeName::ItemId(id) => write!(fmt, "{:?}", id),
             TypeName::ForAll(universe) => write!(fmt, "!{}", universe.counter),
             TypeName::AssociatedType(assoc_ty) => write!(fmt, "{:?}", assoc_ty),
+            TypeName::Scalar(scalar) => write!(fmt, "{}", scalar.name()),
+            TypeName::Tuple(arity) => write!(fmt, "{}-tuple", arity),
+            TypeName::Ref(Mutability::Not) => write!(fmt, "&"),
+            TypeName::Ref(Mutability::Mut) => write!(fmt, "&mut"),
+            TypeName::Raw(Mutability::Not) => write!(fmt, "*const"),
+            TypeName::Raw(Mutability::Mut) => write!(fmt, "*mut"),
+            TypeName::Slice => write!(fmt, "[]"),
+            TypeName::Str => write!(fmt, "str"),
+            TypeName::Array => write!(fmt, "[;]"),
+            TypeName::FnPtr(arity) => write!(fmt, "fn/{}", arity),
+            TypeName::Never => write!(fmt, "!"),
+            TypeName::PhantomData => write!(fmt, "PhantomData"),
         }
     }
 }
 
-impl<T: Debug, L: Debug, C: Debug> Debug for ParameterKind<T, L, C> {
+impl<T: Debug, L: Debug, C: Debug, N: Debug> Debug for ParameterKind<T, L, C, N> {
     fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
         match *self {
             ParameterKind::Ty(ref n) => write!(fmt, "{:?}", n),
             ParameterKind::Lifetime(ref n) => write!(fmt, "{:?}", n),
             ParameterKind::Krate(ref n) => write!(fmt, "{:?}", n),
+            ParameterKind::Const(ref n) => write!(fmt, "{:?}", n),
         }
     }
 }
@@ -69,13 +84,58 @@ impl Debug for Lifetime {
         match *self {
             Lifetime::Var(depth) => write!(fmt, "'?{}", depth),
             Lifetime::ForAll(universe) => write!(fmt, "'!{}", universe.counter),
+            Lifetime::Static => write!(fmt, "'static"),
+        }
+    }
+}
+
+impl Debug for Const {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            Const::Var(depth) => write!(fmt, "?{}", depth),
+            Const::ForAll(universe) => write!(fmt, "!{}", universe.counter),
+            Const::Value(value) => write!(fmt, "{}", value),
         }
     }
 }
 
 impl Debug for ApplicationTy {
     fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
-        write!(fmt, "{:?}{:?}", self.name, Angle(&self.parameters))
+        match self.name {
+            TypeName::Tuple(1) => write!(fmt, "({:?},)", self.parameters[0]),
+            TypeName::Tuple(_) => {
+                write!(fmt, "(")?;
+                for (index, elem) in self.parameters.iter().enumerate() {
+                    if index > 0 {
+                        write!(fmt, ", ")?;
+                    }
+                    write!(fmt, "{:?}", elem)?;
+                }
+                write!(fmt, ")")
+            }
+            TypeName::Ref(Mutability::Not) => {
+                write!(fmt, "&{:?} {:?}", self.parameters[0], self.parameters[1])
+            }
+            TypeName::Ref(Mutability::Mut) => {
+                write!(fmt, "&{:?} mut {:?}", self.parameters[0], self.parameters[1])
+            }
+            TypeName::Raw(Mutability::Not) => write!(fmt, "*const {:?}", self.parameters[0]),
+            TypeName::Raw(Mutability::Mut) => write!(fmt, "*mut {:?}", self.parameters[0]),
+            TypeName::Slice => write!(fmt, "[{:?}]", self.parameters[0]),
+            TypeName::Array => write!(fmt, "[{:?}; {:?}]", self.parameters[0], self.parameters[1]),
+            TypeName::FnPtr(arity) => {
+                write!(fmt, "fn(")?;
+                for (index, arg) in self.parameters[..arity].iter().enumerate() {
+                    if index > 0 {
+                        write!(fmt, ", ")?;
+                    }
+                    write!(fmt, "{:?}", arg)?;
+                }
+                write!(fmt, ") -> {:?}", self.parameters[arity])
+            }
+            TypeName::Never => write!(fmt, "!"),
+            _ => write!(fmt, "{:?}{:?}", self.name, Angle(&self.parameters)),
+        }
     }
 }
 
@@ -89,6 +149,21 @@ impl Debug for TraitRef {
     }
 }
 
+impl Debug for PolarizedTraitRef {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            PolarizedTraitRef::Positive(ref tr) => write!(fmt, "{:?}", tr),
+            PolarizedTraitRef::Negative(ref tr) => {
+                write!(fmt,
+                       "{:?} as !{:?}{:?}",
+                       tr.parameters[0],
+                       tr.trait_id,
+                       Angle(&tr.parameters[1..]))
+            }
+        }
+    }
+}
+
 impl<T: Debug> Debug for Not<T> {
     fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
         write!(fmt, "Not({:?})", self.predicate)
@@ -152,10 +227,50 @@ impl Debug for Normalize {
     }
 }
 
+impl Debug for ConstProjection {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        with_current_program(|p| match p {
+            Some(program) => {
+                let datum = &program.associated_const_data[&self.associated_const_id];
+                write!(fmt,
+                       "<{:?} as {:?}{:?}>::{}",
+                       &self.parameters[0],
+                       datum.trait_id,
+                       Angle(&self.parameters[1..]),
+                       datum.name)
+            }
+            None => write!(fmt, "({:?}){:?}", self.associated_const_id, Angle(&self.parameters)),
+        })
+    }
+}
+
+impl Debug for NormalizeConst {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt, "{:?} ==> {:?}", self.projection, self.value)
+    }
+}
+
+impl Debug for TypeOutlives {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt, "{:?}: {:?}", self.ty, self.lifetime)
+    }
+}
+
+impl Debug for LifetimeOutlives {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt, "{:?}: {:?}", self.a, self.b)
+    }
+}
+
 impl Debug for WhereClause {
     fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
         match *self {
             WhereClause::Normalize(ref n) => write!(fmt, "{:?}", n),
+            WhereClause::NormalizeConst(ref n) => write!(fmt, "{:?}", n),
+            WhereClause::TypeOutlives(ref n) => write!(fmt, "{:?}", n),
+            WhereClause::LifetimeOutlives(ref n) => write!(fmt, "{:?}", n),
+            WhereClause::WellFormed(ref n) => write!(fmt, "{:?}", n),
+            WhereClause::ForAll(ref n) => write!(fmt, "{:?}", n),
             WhereClause::Implemented(ref n) => {
                 write!(fmt,
                        "{:?}: {:?}{:?}",
@@ -171,6 +286,9 @@ impl Debug for WhereClauseGoal {
     fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
         match *self {
             WhereClauseGoal::Normalize(ref n) => write!(fmt, "{:?}", n),
+            WhereClauseGoal::NormalizeConst(ref n) => write!(fmt, "{:?}", n),
+            WhereClauseGoal::TypeOutlives(ref n) => write!(fmt, "{:?}", n),
+            WhereClauseGoal::LifetimeOutlives(ref n) => write!(fmt, "{:?}", n),
             WhereClauseGoal::Implemented(ref n) => {
                 write!(fmt,
                        "{:?}: {:?}{:?}",
@@ -179,10 +297,13 @@ impl Debug for WhereClauseGoal {
                        Angle(&n.parameters[1..]))
             }
             WhereClauseGoal::UnifyTys(ref n) => write!(fmt, "{:?}", n),
+            WhereClauseGoal::Subtype(ref n) => write!(fmt, "{:?}", n),
             WhereClauseGoal::UnifyKrates(ref n) => write!(fmt, "{:?}", n),
             WhereClauseGoal::UnifyLifetimes(ref n) => write!(fmt, "{:?}", n),
             WhereClauseGoal::WellFormed(ref n) => write!(fmt, "{:?}", n),
             WhereClauseGoal::TyLocalTo(ref n) => write!(fmt, "{:?}", n),
+            WhereClauseGoal::Unsize(ref n) => write!(fmt, "{:?}", n),
+            WhereClauseGoal::Numeric(ref n) => write!(fmt, "{:?}", n),
             WhereClauseGoal::NotUnifyTys(ref n) => write!(fmt, "{:?}", n),
             WhereClauseGoal::NotNormalize(ref n) => write!(fmt, "{:?}", n),
             WhereClauseGoal::NotImplemented(ref n) => write!(fmt, "{:?}", n),
@@ -197,6 +318,27 @@ impl<T: Debug> Debug for LocalTo<T> {
     }
 }
 
+impl Debug for Subtype {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt, "Subtype({:?}, {:?})", self.a, self.b)
+    }
+}
+
+impl Debug for Numeric {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        match self.kind {
+            NumericKind::Integer => write!(fmt, "{:?}: {{integer}}", self.ty),
+            NumericKind::Float => write!(fmt, "{:?}: {{float}}", self.ty),
+        }
+    }
+}
+
+impl Debug for Unsize {
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+        write!(fmt, "Unsize({:?}, {:?})", self.source, self.target)
+    }
+}
+
 impl Debug for WellFormed {
     fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
         let value: &Debug = match *self {
@@ -226,12 +368,14 @@ impl Debug for Goal {
                         ParameterKind::Ty(()) => write!(fmt, "type")?,
                         ParameterKind::Lifetime(()) => write!(fmt, "lifetime")?,
                         ParameterKind::Krate(()) => write!(fmt, "krate")?,
+                        ParameterKind::Const(()) => write!(fmt, "const")?,
                     }
                 }
                 write!(fmt, "> {{ {:?} }}", subgoal.value)
             }
             Goal::Implies(ref wc, ref g) => write!(fmt, "if ({:?}) {{ {:?} }}", wc, g),
             Goal::And(ref g1, ref g2) => write!(fmt, "({:?}, {:?})", g1, g2),
+            Goal::Not(ref g) => write!(fmt, "not {{ {:?} }}", g),
             Goal::Leaf(ref wc) => write!(fmt, "{:?}", wc),
         }
     }
@@ -256,6 +400,7 @@ impl<T: Debug> Debug for Binders<T> {
                     ParameterKind::Ty(()) => write!(fmt, "type")?,
                     ParameterKind::Lifetime(()) => write!(fmt, "lifetime")?,
                     ParameterKind::Krate(()) => write!(fmt, "krate")?,
+                    ParameterKind::Const(()) => write!(fmt, "const")?,
                 }
             }
             write!(fmt, "> ")?;
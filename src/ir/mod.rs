@@ -1,8 +1,9 @@
 use cast::Cast;
 use chalk_parse::ast;
 use fold::Subst;
-use lalrpop_intern::InternedString;
-use std::collections::{HashSet, HashMap};
+use lalrpop_intern::{intern, InternedString};
+use std::collections::{BTreeSet, HashSet, HashMap};
+use std::hash::Hash;
 use std::sync::Arc;
 
 pub type Identifier = InternedString;
@@ -15,17 +16,57 @@ pub struct Program {
     /// For each struct/trait:
     pub type_kinds: HashMap<ItemId, TypeKind>,
 
+    /// For each type alias, its expansion. Used during lowering only.
+    pub type_aliases: HashMap<Identifier, Binders<Ty>>,
+
+    /// For each struct/enum/trait with defaulted type parameters, the
+    /// default of each parameter. Used during lowering only.
+    pub parameter_defaults: HashMap<ItemId, Vec<Option<Binders<Ty>>>>,
+
     /// For each struct:
     pub struct_data: HashMap<ItemId, StructDatum>,
 
+    /// For each enum:
+    pub enum_data: HashMap<ItemId, EnumDatum>,
+
+    /// For each closure:
+    pub closure_data: HashMap<ItemId, ClosureDatum>,
+
+    /// For each fn item:
+    pub fn_def_data: HashMap<ItemId, FnDefDatum>,
+
+    /// For each generator:
+    pub generator_data: HashMap<ItemId, GeneratorDatum>,
+
+    /// For each extern type:
+    pub extern_ty_data: HashMap<ItemId, ExternTyDatum>,
+
+    /// For each opaque type:
+    pub opaque_ty_data: HashMap<ItemId, OpaqueTyDatum>,
+
     /// For each impl:
     pub impl_data: HashMap<ItemId, ImplDatum>,
 
+    /// For each impl, where it was written; for errors.
+    pub impl_spans: HashMap<ItemId, ast::Span>,
+
     /// For each trait:
     pub trait_data: HashMap<ItemId, TraitDatum>,
 
     /// For each trait:
     pub associated_ty_data: HashMap<ItemId, AssociatedTyDatum>,
+
+    /// For each trait:
+    pub associated_const_data: HashMap<ItemId, AssociatedConstDatum>,
+
+    /// The traits declared with `#[lang(..)]`, which built-in rules
+    /// (like those for `Sized` and closures) attach to.
+    pub lang_items: HashMap<LangItem, ItemId>,
+
+    /// The tuple and fn pointer types that the program uses, like
+    /// `Tuple(2)` for `(A, B)` and `FnPtr(1)` for `fn(A) -> B`, which
+    /// get built-in clauses for every arity used.
+    pub builtin_arities: BTreeSet<TypeName>,
 }
 
 impl Program {
@@ -43,29 +84,128 @@ impl Program {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ProgramEnvironment {
+    /// For each struct:
+    pub struct_data: HashMap<ItemId, StructDatum>,
+
+    /// For each enum:
+    pub enum_data: HashMap<ItemId, EnumDatum>,
+
     /// For each trait:
     pub trait_data: HashMap<ItemId, TraitDatum>,
 
     /// For each trait:
     pub associated_ty_data: HashMap<ItemId, AssociatedTyDatum>,
 
+    /// For each trait:
+    pub associated_const_data: HashMap<ItemId, AssociatedConstDatum>,
+
+    /// For each struct and enum, the variance of its parameters:
+    pub variances: HashMap<ItemId, Vec<Variance>>,
+
     /// Compiled forms of the above:
     pub program_clauses: Vec<ProgramClause>,
+
+    /// Compiled forms of the negative impls. These never prove
+    /// anything on their own; rather, a goal matching one of these
+    /// is known to be unprovable.
+    pub negative_impl_clauses: Vec<ProgramClause>,
+
+    /// Compiled forms of `default` associated type values that some
+    /// more specialized impl overrides.
+    pub default_program_clauses: Vec<DefaultProgramClause>,
+
+    /// For each trait, the positions in `program_clauses` of the
+    /// clauses that prove it, by the head of their self type (see
+    /// `WhereClauseGoal::self_ty_head`); those whose self type could
+    /// be anything, like that of `impl<T> Foo for T`, are under `None`.
+    pub trait_clause_index: HashMap<ItemId, HashMap<Option<TypeName>, Vec<usize>>>,
 }
 
 impl ProgramEnvironment {
-    pub fn split_projection<'p>(&self, projection: &'p ProjectionTy)
-                            -> (&AssociatedTyDatum, &'p [Parameter], &'p [Parameter]) {
-        let ProjectionTy { associated_ty_id, ref parameters } = *projection;
-        let associated_ty_data = &self.associated_ty_data[&associated_ty_id];
-        let trait_datum = &self.trait_data[&associated_ty_data.trait_id];
-        let trait_num_params = trait_datum.binders.len();
-        let split_point = parameters.len() - trait_num_params;
-        let (other_params, trait_params) = parameters.split_at(split_point);
-        (associated_ty_data, trait_params, other_params)
+    /// Builds the `trait_clause_index` for `program_clauses`.
+    pub fn index_trait_clauses(program_clauses: &[ProgramClause])
+                               -> HashMap<ItemId, HashMap<Option<TypeName>, Vec<usize>>> {
+        let mut index: HashMap<_, HashMap<_, Vec<_>>> = HashMap::new();
+        for (position, clause) in program_clauses.iter().enumerate() {
+            let consequence = &clause.implication.value.consequence;
+            if let WhereClauseGoal::Implemented(ref trait_ref) = *consequence {
+                index.entry(trait_ref.trait_id)
+                     .or_insert_with(HashMap::new)
+                     .entry(consequence.self_ty_head())
+                     .or_insert_with(Vec::new)
+                     .push(position);
+            }
+        }
+        index
+    }
+
+    /// The items whose clauses differ between `self` and `other`, as
+    /// given by `WhereClauseGoal::item_id`; a goal about any other
+    /// item is solved the same way in both. Returns `None` if they
+    /// differ in some way that can't be pinned to particular items,
+    /// such as a changed struct.
+    pub fn changed_items(&self, other: &ProgramEnvironment) -> Option<HashSet<ItemId>> {
+        if self.struct_data != other.struct_data ||
+            self.enum_data != other.enum_data ||
+            self.associated_ty_data != other.associated_ty_data ||
+            self.associated_const_data != other.associated_const_data ||
+            self.variances != other.variances
+        {
+            return None;
+        }
+
+        let mut changed: HashSet<ItemId> = HashSet::new();
+        for (&id, datum) in &self.trait_data {
+            if other.trait_data.get(&id) != Some(datum) {
+                changed.insert(id);
+            }
+        }
+        for &id in other.trait_data.keys() {
+            if !self.trait_data.contains_key(&id) {
+                changed.insert(id);
+            }
+        }
+
+        let consequence = |c: &ProgramClause| c.implication.value.consequence.item_id();
+        let found = changed_clauses(&self.program_clauses, &other.program_clauses,
+                                    &consequence, &mut changed) &&
+            changed_clauses(&self.negative_impl_clauses, &other.negative_impl_clauses,
+                            &consequence, &mut changed) &&
+            changed_clauses(&self.default_program_clauses, &other.default_program_clauses,
+                            &|c: &DefaultProgramClause| consequence(&c.clause), &mut changed);
+        if !found {
+            return None;
+        }
+
+        Some(changed)
     }
 }
 
+/// Adds to `changed` the items of the clauses that occur more often in
+/// one of `a` and `b` than in the other. Returns false if one of those
+/// clauses is not about any item.
+fn changed_clauses<T, F>(a: &[T], b: &[T], item_id: &F, changed: &mut HashSet<ItemId>) -> bool
+    where T: Hash + Eq, F: Fn(&T) -> Option<ItemId>
+{
+    let count = |clauses: &[T]| {
+        let mut counts: HashMap<&T, usize> = HashMap::new();
+        for clause in clauses {
+            *counts.entry(clause).or_insert(0) += 1;
+        }
+        counts
+    };
+    let (a, b) = (count(a), count(b));
+    for clause in a.keys().chain(b.keys()) {
+        if a.get(clause) != b.get(clause) {
+            match item_id(clause) {
+                Some(id) => { changed.insert(id); }
+                None => return false,
+            }
+        }
+    }
+    true
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Environment {
     pub universe: UniverseIndex,
@@ -91,7 +231,7 @@ impl Environment {
         Arc::new(env)
     }
 
-    pub fn elaborated_clauses(&self, program: &ProgramEnvironment) -> impl Iterator<Item = WhereClause> {
+    pub fn elaborated_clauses(&self, program: &ChalkDatabase) -> impl Iterator<Item = WhereClause> {
         let mut set = HashSet::new();
         set.extend(self.clauses.iter().cloned());
 
@@ -112,10 +252,11 @@ impl Environment {
                     // ----------------------------------------------------------
                     // T: Bar<U>
 
-                    let trait_datum = &program.trait_data[&trait_ref.trait_id];
-                    for where_clause in &trait_datum.binders.value.where_clauses {
-                        let where_clause = Subst::apply(&trait_ref.parameters, where_clause);
-                        push_clause(where_clause);
+                    if let Some(trait_datum) = program.trait_datum(trait_ref.trait_id) {
+                        for where_clause in &trait_datum.binders.value.where_clauses {
+                            let where_clause = Subst::apply(&trait_ref.binder_parameters(), where_clause);
+                            push_clause(where_clause);
+                        }
                     }
                 }
                 WhereClause::Normalize(Normalize { ref projection, ty: _ }) => {
@@ -123,13 +264,79 @@ impl Environment {
                     // ----------------------------------------------------------
                     // T: Trait<U>
 
-                    let (associated_ty_data, trait_params, _) = program.split_projection(projection);
+                    let (associated_ty_data, trait_params, other_params) = match program.split_projection(projection) {
+                        Some(split) => split,
+                        None => continue,
+                    };
                     let trait_ref = TraitRef {
                         trait_id: associated_ty_data.trait_id,
                         parameters: trait_params.to_owned()
                     };
+
+                    // trait Trait<U> { type Foo where Self: Bar; }
+                    // <T as Trait<U>>::Foo ===> V
+                    // ----------------------------------------------------------
+                    // T: Bar
+
+                    let parameters: Vec<_> = other_params.iter()
+                                                         .cloned()
+                                                         .chain(trait_ref.binder_parameters())
+                                                         .collect();
+                    for where_clause in &associated_ty_data.where_clauses {
+                        push_clause(Subst::apply(&parameters, where_clause));
+                    }
+
+                    push_clause(trait_ref.cast());
+                }
+                WhereClause::NormalizeConst(NormalizeConst { ref projection, value: _ }) => {
+                    // <T as Trait<U>>::N ===> V
+                    // ----------------------------------------------------------
+                    // T: Trait<U>
+
+                    let associated_const_data = match program.associated_const_datum(projection.associated_const_id) {
+                        Some(data) => data,
+                        None => continue,
+                    };
+                    let trait_ref = TraitRef {
+                        trait_id: associated_const_data.trait_id,
+                        parameters: projection.parameters.clone(),
+                    };
                     push_clause(trait_ref.cast());
                 }
+                WhereClause::WellFormed(WellFormed::Ty(Ty::Apply(ref apply))) => {
+                    // struct Foo<T> where T: Bar { }
+                    // WF(Foo<U>)
+                    // ----------------------------------------------------------
+                    // U: Bar
+
+                    if let TypeName::ItemId(id) = apply.name {
+                        let where_clauses = match (program.struct_datum(id), program.enum_datum(id)) {
+                            (Some(datum), _) => &datum.binders.value.where_clauses[..],
+                            (_, Some(datum)) => &datum.binders.value.where_clauses[..],
+                            (None, None) => &[],
+                        };
+                        for where_clause in where_clauses {
+                            push_clause(Subst::apply(&apply.parameters, where_clause));
+                        }
+                    }
+                }
+                WhereClause::WellFormed(WellFormed::TraitRef(ref trait_ref)) => {
+                    // trait Foo<A> where A: Bar { }
+                    // WF(T: Foo<U>)
+                    // ----------------------------------------------------------
+                    // U: Bar
+
+                    if let Some(trait_datum) = program.trait_datum(trait_ref.trait_id) {
+                        for where_clause in &trait_datum.binders.value.where_clauses {
+                            let where_clause = Subst::apply(&trait_ref.binder_parameters(), where_clause);
+                            push_clause(where_clause);
+                        }
+                    }
+                }
+                WhereClause::WellFormed(WellFormed::Ty(_)) |
+                WhereClause::TypeOutlives(_) |
+                WhereClause::LifetimeOutlives(_) |
+                WhereClause::ForAll(_) => { }
             }
         }
 
@@ -168,6 +375,129 @@ pub enum TypeName {
 
     /// an associated type like `Iterator::Item`; see `AssociatedType` for details
     AssociatedType(ItemId),
+
+    /// a built-in scalar type like `i32` or `bool`
+    Scalar(ScalarType),
+
+    /// a tuple type of the given arity, like `(A, B)`
+    Tuple(usize),
+
+    /// a reference type like `&'a T` or `&'a mut T`; the parameters
+    /// are the lifetime followed by the referent type
+    Ref(Mutability),
+
+    /// a raw pointer type like `*const T` or `*mut T`
+    Raw(Mutability),
+
+    /// a slice type like `[T]`
+    Slice,
+
+    /// the string slice type `str`, which (like a slice) is unsized
+    Str,
+
+    /// an array type like `[T; N]`; the parameters are the element
+    /// type followed by the (const) length
+    Array,
+
+    /// a function pointer type with the given number of arguments,
+    /// like `fn(A, B) -> C`; the parameters are the argument types
+    /// followed by the return type. Higher-ranked function pointers
+    /// like `for<'a> fn(&'a T)` are represented with `Ty::ForAll`.
+    FnPtr(usize),
+
+    /// the never type `!`, the type of computations that never
+    /// produce a value
+    Never,
+
+    /// the marker type `PhantomData<T>`, which acts as though it owns
+    /// a `T` for variance and auto traits, but is always `Sized`
+    PhantomData,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Mutability {
+    Not,
+    Mut,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ScalarType {
+    Bool,
+    Char,
+    I8, I16, I32, I64, I128, Isize,
+    U8, U16, U32, U64, U128, Usize,
+    F32, F64,
+}
+
+const ALL_SCALARS: &[ScalarType] = {
+    use self::ScalarType::*;
+    &[Bool, Char,
+      I8, I16, I32, I64, I128, Isize,
+      U8, U16, U32, U64, U128, Usize,
+      F32, F64]
+};
+
+impl ScalarType {
+    pub fn all() -> &'static [ScalarType] {
+        ALL_SCALARS
+    }
+
+    /// The name by which this scalar is written in a program.
+    pub fn name(self) -> &'static str {
+        use self::ScalarType::*;
+        match self {
+            Bool => "bool",
+            Char => "char",
+            I8 => "i8",
+            I16 => "i16",
+            I32 => "i32",
+            I64 => "i64",
+            I128 => "i128",
+            Isize => "isize",
+            U8 => "u8",
+            U16 => "u16",
+            U32 => "u32",
+            U64 => "u64",
+            U128 => "u128",
+            Usize => "usize",
+            F32 => "f32",
+            F64 => "f64",
+        }
+    }
+
+    pub fn from_name(name: Identifier) -> Option<ScalarType> {
+        ScalarType::all().iter().cloned().find(|s| intern(s.name()) == name)
+    }
+
+    /// The kind of numeric literal that may have this type, if any.
+    pub fn numeric_kind(self) -> Option<NumericKind> {
+        use self::ScalarType::*;
+        match self {
+            I8 | I16 | I32 | I64 | I128 | Isize |
+            U8 | U16 | U32 | U64 | U128 | Usize => Some(NumericKind::Integer),
+            F32 | F64 => Some(NumericKind::Float),
+            Bool | Char => None,
+        }
+    }
+}
+
+/// The kind of type a numeric literal may have; see `Numeric`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NumericKind {
+    Integer,
+    Float,
+}
+
+impl NumericKind {
+    /// The type that a literal of this kind has if nothing else
+    /// decides it: `i32` for integers and `f64` for floats, as in
+    /// Rust.
+    pub fn default_scalar(self) -> ScalarType {
+        match self {
+            NumericKind::Integer => ScalarType::I32,
+            NumericKind::Float => ScalarType::F64,
+        }
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -201,6 +531,19 @@ pub enum Krate {
     Id(KrateId),
 }
 
+/// A const value, such as the length of an array type.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Const {
+    /// one of the "variables" (either bound or inference)
+    Var(usize),
+
+    /// skolemized form of a const parameter like `const N`
+    ForAll(UniverseIndex),
+
+    /// a concrete value, like `3`
+    Value(u64),
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct TypeKind {
     pub sort: TypeSort,
@@ -212,20 +555,62 @@ pub struct TypeKind {
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum TypeSort {
     Struct,
+    Enum,
     Trait,
+    Closure,
+    FnDef,
+    Generator,
+    Extern,
+    Opaque,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ImplDatum {
     pub krate_id: KrateId,
     pub binders: Binders<ImplDatumBound>,
+
+    /// True for a `default impl`, which more specialized impls may
+    /// overlap with, even if it has no items to override.
+    pub default: bool,
+
+    /// True for an `unsafe impl`; see `TraitFlags::is_unsafe`.
+    pub is_unsafe: bool,
+
+    /// The impls that this impl specializes, i.e., those that apply
+    /// to every type this one does (and more).
+    pub specializes: Vec<ItemId>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ImplDatumBound {
-    pub trait_ref: TraitRef,
+    pub trait_ref: PolarizedTraitRef,
     pub where_clauses: Vec<WhereClause>,
     pub associated_ty_values: Vec<AssociatedTyValue>,
+    pub associated_const_values: Vec<AssociatedConstValue>,
+}
+
+/// The trait-ref of an impl header, along with whether the impl is
+/// positive (`impl Foo for Bar`) or negative (`impl !Foo for Bar`).
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum PolarizedTraitRef {
+    Positive(TraitRef),
+    Negative(TraitRef),
+}
+
+impl PolarizedTraitRef {
+    pub fn is_positive(&self) -> bool {
+        match *self {
+            PolarizedTraitRef::Positive(_) => true,
+            PolarizedTraitRef::Negative(_) => false,
+        }
+    }
+
+    pub fn trait_ref(&self) -> &TraitRef {
+        match *self {
+            PolarizedTraitRef::Positive(ref tr) |
+            PolarizedTraitRef::Negative(ref tr) => tr,
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -237,9 +622,188 @@ pub struct StructDatum {
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct StructDatumBound {
     pub self_ty: ApplicationTy,
+    pub fields: Vec<Field>,
+    pub where_clauses: Vec<WhereClause>,
+    pub flags: StructFlags,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Field {
+    pub name: Identifier,
+    pub ty: Ty,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EnumDatum {
+    pub krate_id: KrateId,
+    pub binders: Binders<EnumDatumBound>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EnumDatumBound {
+    pub self_ty: ApplicationTy,
+    pub variants: Vec<Variant>,
+    pub where_clauses: Vec<WhereClause>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Variant {
+    pub name: Identifier,
+    pub fields: Vec<Field>,
+}
+
+impl EnumDatumBound {
+    /// The types of the fields of all the variants, in order.
+    pub fn field_tys(&self) -> Vec<Ty> {
+        self.variants.iter()
+                     .flat_map(|variant| variant.fields.iter().map(|field| field.ty.clone()))
+                     .collect()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StructFlags {
+    pub fundamental: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ClosureDatum {
+    pub krate_id: KrateId,
+    pub binders: Binders<ClosureDatumBound>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ClosureDatumBound {
+    pub self_ty: ApplicationTy,
+    pub kind: ClosureKind,
+    pub arg_tys: Vec<Ty>,
+    pub ret_ty: Ty,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FnDefDatum {
+    pub krate_id: KrateId,
+    pub binders: Binders<FnDefDatumBound>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FnDefDatumBound {
+    pub self_ty: ApplicationTy,
+    pub arg_tys: Vec<Ty>,
+    pub ret_ty: Ty,
     pub where_clauses: Vec<WhereClause>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GeneratorDatum {
+    pub krate_id: KrateId,
+    pub binders: Binders<GeneratorDatumBound>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GeneratorDatumBound {
+    pub self_ty: ApplicationTy,
+    pub resume_ty: Ty,
+    pub yield_ty: Ty,
+    pub return_ty: Ty,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ExternTyDatum {
+    pub krate_id: KrateId,
+    pub self_ty: ApplicationTy,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OpaqueTyDatum {
+    pub krate_id: KrateId,
+    pub binders: Binders<OpaqueTyDatumBound>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OpaqueTyDatumBound {
+    pub self_ty: ApplicationTy,
+
+    /// The declared bounds, each with `self_ty` as its self type.
+    pub bounds: Vec<TraitRef>,
+}
+
+/// A trait the solver has built-in rules for, declared by the program
+/// with an attribute like `#[lang(sized)]`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LangItem {
+    Sized,
+    Fn,
+    FnMut,
+    FnOnce,
+    Generator,
+}
+
+impl LangItem {
+    pub fn from_name(name: Identifier) -> Option<LangItem> {
+        [LangItem::Sized, LangItem::Fn, LangItem::FnMut, LangItem::FnOnce, LangItem::Generator]
+            .iter()
+            .cloned()
+            .find(|l| intern(l.name()) == name)
+    }
+
+    /// The name used in `#[lang(..)]`.
+    pub fn name(self) -> &'static str {
+        match self {
+            LangItem::Sized => "sized",
+            LangItem::Fn => "fn",
+            LangItem::FnMut => "fn_mut",
+            LangItem::FnOnce => "fn_once",
+            LangItem::Generator => "generator",
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ClosureKind {
+    Fn,
+    FnMut,
+    FnOnce,
+}
+
+impl ClosureKind {
+    pub fn from_name(name: Identifier) -> Option<ClosureKind> {
+        [ClosureKind::Fn, ClosureKind::FnMut, ClosureKind::FnOnce]
+            .iter()
+            .cloned()
+            .find(|k| intern(k.trait_name()) == name)
+    }
+
+    /// The name of the call trait corresponding to this kind.
+    pub fn trait_name(self) -> &'static str {
+        match self {
+            ClosureKind::Fn => "Fn",
+            ClosureKind::FnMut => "FnMut",
+            ClosureKind::FnOnce => "FnOnce",
+        }
+    }
+
+    /// The lang item for the call trait corresponding to this kind.
+    pub fn lang_item(self) -> LangItem {
+        match self {
+            ClosureKind::Fn => LangItem::Fn,
+            ClosureKind::FnMut => LangItem::FnMut,
+            ClosureKind::FnOnce => LangItem::FnOnce,
+        }
+    }
+
+    /// The call traits implemented by a closure of this kind: an
+    /// `Fn` closure is also `FnMut` and `FnOnce`, and so on.
+    pub fn implemented_kinds(self) -> &'static [ClosureKind] {
+        const FN: &[ClosureKind] = &[ClosureKind::Fn, ClosureKind::FnMut, ClosureKind::FnOnce];
+        match self {
+            ClosureKind::Fn => FN,
+            ClosureKind::FnMut => &FN[1..],
+            ClosureKind::FnOnce => &FN[2..],
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct TraitDatum {
     pub krate_id: KrateId,
@@ -250,6 +814,32 @@ pub struct TraitDatum {
 pub struct TraitDatumBound {
     pub trait_ref: TraitRef,
     pub where_clauses: Vec<WhereClause>,
+    pub flags: TraitFlags,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TraitFlags {
+    pub auto: bool,
+    pub alias: bool,
+    pub marker: bool,
+    pub sized: bool,
+
+    /// True for an `unsafe trait`, whose positive impls must be
+    /// `unsafe impl`s (and which no other trait's impls may be).
+    pub is_unsafe: bool,
+
+    /// True for a `#[coinductive] trait`; see `coinductive`.
+    pub coinductive: bool,
+}
+
+impl TraitFlags {
+    /// True if a cycle of goals implementing traits like this one
+    /// holds, rather than failing for lack of a finite proof. A
+    /// struct is thus `Send` even if one of its fields is a `Box`
+    /// pointing back to the struct itself.
+    pub fn coinductive(&self) -> bool {
+        self.auto || self.marker || self.coinductive
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -264,8 +854,31 @@ pub struct AssociatedTyDatum {
     /// but possibly including more.
     pub parameter_kinds: Vec<ParameterKind<Identifier>>,
 
-    /// Where clauses that must hold for the projection be well-formed.
+    /// Where clauses that must hold for the projection be well-formed:
+    /// the trait-ref itself, followed by any declared on the `type`,
+    /// like the `Self: Sized` in `type Item where Self: Sized;`.
     pub where_clauses: Vec<WhereClause>,
+
+    /// The default value, if any, in terms of `parameter_kinds`. Used
+    /// for impls that do not give a value of their own.
+    pub default: Option<Ty>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AssociatedConstDatum {
+    /// The trait this associated const is defined in.
+    pub trait_id: ItemId,
+
+    /// Name of this associated const.
+    pub name: Identifier,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AssociatedConstValue {
+    pub associated_const_id: ItemId,
+
+    /// The X in `const N = X`, in terms of the impl's parameters.
+    pub value: Const,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -274,6 +887,10 @@ pub struct AssociatedTyValue {
 
     // note: these binders are in addition to those from the impl
     pub value: Binders<AssociatedTyValueBound>,
+
+    /// True for a `default type`, which more specialized impls may
+    /// override.
+    pub default: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -310,6 +927,9 @@ pub enum Lifetime {
     /// See Ty::Var(_).
     Var(usize),
     ForAll(UniverseIndex),
+
+    /// `'static`, which outlives every other lifetime.
+    Static,
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -319,10 +939,11 @@ pub struct ApplicationTy {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub enum ParameterKind<T, L = T, C = T> {
+pub enum ParameterKind<T, L = T, C = T, N = T> {
     Ty(T),
     Lifetime(L),
     Krate(C),
+    Const(N),
 }
 
 impl<T> ParameterKind<T> {
@@ -333,16 +954,18 @@ impl<T> ParameterKind<T> {
             ParameterKind::Ty(t) => ParameterKind::Ty(op(t)),
             ParameterKind::Lifetime(t) => ParameterKind::Lifetime(op(t)),
             ParameterKind::Krate(t) => ParameterKind::Krate(op(t)),
+            ParameterKind::Const(t) => ParameterKind::Const(op(t)),
         }
     }
 }
 
-impl<T, L, C> ParameterKind<T, L, C> {
-    pub fn as_ref(&self) -> ParameterKind<&T, &L, &C> {
+impl<T, L, C, N> ParameterKind<T, L, C, N> {
+    pub fn as_ref(&self) -> ParameterKind<&T, &L, &C, &N> {
         match *self {
             ParameterKind::Ty(ref t) => ParameterKind::Ty(t),
             ParameterKind::Lifetime(ref l) => ParameterKind::Lifetime(l),
             ParameterKind::Krate(ref c) => ParameterKind::Krate(c),
+            ParameterKind::Const(ref n) => ParameterKind::Const(n),
         }
     }
 
@@ -366,19 +989,27 @@ impl<T, L, C> ParameterKind<T, L, C> {
             _ => None,
         }
     }
+
+    pub fn constant(self) -> Option<N> {
+        match self {
+            ParameterKind::Const(t) => Some(t),
+            _ => None,
+        }
+    }
 }
 
-impl<T, L, C> ast::Kinded for ParameterKind<T, L, C> {
+impl<T, L, C, N> ast::Kinded for ParameterKind<T, L, C, N> {
     fn kind(&self) -> ast::Kind {
         match *self {
             ParameterKind::Ty(_) => ast::Kind::Ty,
             ParameterKind::Lifetime(_) => ast::Kind::Lifetime,
             ParameterKind::Krate(_) => ast::Kind::Krate,
+            ParameterKind::Const(_) => ast::Kind::Const,
         }
     }
 }
 
-pub type Parameter = ParameterKind<Ty, Lifetime, Krate>;
+pub type Parameter = ParameterKind<Ty, Lifetime, Krate, Const>;
 
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ProjectionTy {
@@ -386,33 +1017,101 @@ pub struct ProjectionTy {
     pub parameters: Vec<Parameter>,
 }
 
+/// An associated const, like `<T as Foo>::N`. The parameters are
+/// those of the trait ref.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ConstProjection {
+    pub associated_const_id: ItemId,
+    pub parameters: Vec<Parameter>,
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct TraitRef {
     pub trait_id: ItemId,
     pub parameters: Vec<Parameter>,
 }
 
+impl TraitRef {
+    /// The parameters of this trait-ref in the order of the trait's
+    /// binders. A trait-ref lists `Self` first, as in `Self: Foo<A>`,
+    /// but the trait binds its declared parameters first and `Self`
+    /// last (see `LowerParameterMap::parameter_map`).
+    pub fn binder_parameters(&self) -> Vec<Parameter> {
+        self.parameters[1..]
+            .iter()
+            .cloned()
+            .chain(Some(self.parameters[0].clone()))
+            .collect()
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum WhereClause {
     Implemented(TraitRef),
     Normalize(Normalize),
+    NormalizeConst(NormalizeConst),
+    TypeOutlives(TypeOutlives),
+    LifetimeOutlives(LifetimeOutlives),
+    WellFormed(WellFormed),
+
+    /// A higher-ranked where-clause like `forall<'a> T: Foo<'a>`. As a
+    /// hypothesis its binders are instantiated with fresh inference
+    /// variables; as something to prove, with placeholders.
+    ForAll(Binders<Box<WhereClause>>),
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum WhereClauseGoal {
     Implemented(TraitRef),
     Normalize(Normalize),
+    NormalizeConst(NormalizeConst),
+    TypeOutlives(TypeOutlives),
+    LifetimeOutlives(LifetimeOutlives),
     UnifyTys(Unify<Ty>),
+    Subtype(Subtype),
     UnifyKrates(Unify<Krate>),
     UnifyLifetimes(Unify<Lifetime>),
     WellFormed(WellFormed),
     TyLocalTo(LocalTo<Ty>),
+    Unsize(Unsize),
+    Numeric(Numeric),
 
     NotImplemented(Not<TraitRef>),
     NotNormalize(Not<Normalize>),
     NotUnifyTys(Not<Unify<Ty>>),
 }
 
+impl WhereClauseGoal {
+    /// The trait, associated type or associated const whose clauses
+    /// prove this goal, if it is a goal about one.
+    pub fn item_id(&self) -> Option<ItemId> {
+        match *self {
+            WhereClauseGoal::Implemented(ref trait_ref) => Some(trait_ref.trait_id),
+            WhereClauseGoal::NotImplemented(ref not) => Some(not.predicate.trait_id),
+            WhereClauseGoal::Normalize(ref normalize) => Some(normalize.projection.associated_ty_id),
+            WhereClauseGoal::NotNormalize(ref not) => Some(not.predicate.projection.associated_ty_id),
+            WhereClauseGoal::NormalizeConst(ref normalize) => {
+                Some(normalize.projection.associated_const_id)
+            }
+            _ => None,
+        }
+    }
+
+    /// For a trait goal, the head of its self type: its `TypeName`,
+    /// like the struct in `Vec<T>: Clone`. `None` if this is not a
+    /// trait goal, or its self type could turn out to be anything, as
+    /// a variable or a projection can.
+    pub fn self_ty_head(&self) -> Option<TypeName> {
+        match *self {
+            WhereClauseGoal::Implemented(ref trait_ref) => match trait_ref.parameters[0] {
+                ParameterKind::Ty(Ty::Apply(ref apply)) => Some(apply.name),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum WellFormed {
     Ty(Ty),
@@ -425,6 +1124,34 @@ pub struct LocalTo<F> {
     pub krate: Krate,
 }
 
+/// `Unsize(A, B)`: a value of type `A` may be coerced to the
+/// dynamically sized type `B`, as `[T; N]` to `[T]` or `T` to
+/// `dyn Trait`.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Unsize {
+    pub source: Ty,
+    pub target: Ty,
+}
+
+/// `T: {integer}` (or `T: {float}`): `T` is some integer (or float)
+/// type, as is the type of a numeric literal. With `T` still unknown,
+/// the goal is ambiguous; if nothing else settles `T` by the time the
+/// solver is done, it defaults to `NumericKind::default_scalar`.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Numeric {
+    pub ty: Ty,
+    pub kind: NumericKind,
+}
+
+/// `Subtype(A, B)`: `A` is a subtype of `B`, given the variance of
+/// the type parameters involved; for example `&'static u32` is a
+/// subtype of `&'a u32`, and `for<'a> fn(&'a u32)` of `fn(&'x u32)`.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Subtype {
+    pub a: Ty,
+    pub b: Ty,
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Unify<T> {
     pub a: T,
@@ -443,6 +1170,27 @@ pub struct Normalize {
     pub ty: Ty,
 }
 
+/// `T: 'a`. Proving this never fails; instead, the relation is
+/// recorded as a constraint on the solution.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TypeOutlives {
+    pub ty: Ty,
+    pub lifetime: Lifetime,
+}
+
+/// `'a: 'b`, recorded as a constraint just like `TypeOutlives`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LifetimeOutlives {
+    pub a: Lifetime,
+    pub b: Lifetime,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NormalizeConst {
+    pub projection: ConstProjection,
+    pub value: Const,
+}
+
 /// Indicates that the `value` is universally quantified over `N`
 /// parameters of the given kinds, where `N == self.binders.len()`. A
 /// variable with depth `i < N` refers to the value at
@@ -477,6 +1225,19 @@ pub struct ProgramClause {
     pub implication: Binders<ProgramClauseImplication>
 }
 
+/// A program clause for a `default` associated type value. It only
+/// applies to goals that none of the overriding impls could match;
+/// otherwise, the projection is left opaque, since we can't tell
+/// which value it has.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DefaultProgramClause {
+    pub clause: ProgramClause,
+
+    /// The normalizations provided by the overriding impls, with
+    /// the target type left as a variable.
+    pub specializations: Vec<Binders<Normalize>>,
+}
+
 /// Represents one clause of the form `consequence :- conditions`.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ProgramClauseImplication {
@@ -484,25 +1245,34 @@ pub struct ProgramClauseImplication {
     pub conditions: Vec<Goal>,
 }
 
-/// Wraps a "canonicalized query". Queries are canonicalized as follows:
+/// A value in canonical form, as produced by
+/// `InferenceTable::canonicalize`:
 ///
 /// - All unresolved existential variables are "renumbered" according
 ///   to their first appearance; the kind/universe of the variable is
 ///   recorded in the `binders` field.
+///
+/// Two values that differ only in the inference variables they use
+/// thus have the same canonical form, which is what lets the solver
+/// compare and cache goals.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct Query<T> {
+pub struct Canonical<T> {
     pub value: T,
     pub binders: Vec<ParameterKind<UniverseIndex>>,
 }
 
-impl<T> Query<T> {
-    pub fn map<OP, U>(self, op: OP) -> Query<U>
+impl<T> Canonical<T> {
+    pub fn map<OP, U>(self, op: OP) -> Canonical<U>
         where OP: FnOnce(T) -> U
     {
-        Query { value: op(self.value), binders: self.binders }
+        Canonical { value: op(self.value), binders: self.binders }
     }
 }
 
+/// A value together with the region constraints under which it
+/// holds. The solver does not check region relationships itself;
+/// constraints it could not discharge (like `'!1: '!2`, or equating
+/// two distinct placeholders) are handed back to the caller here.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Constrained<T> {
     pub value: T,
@@ -517,6 +1287,17 @@ impl<T> Constrained<T> {
     }
 }
 
+/// Like `Constrained`, but also with the `goals` that the solver could
+/// not decide either way -- typically because they mention variables
+/// that nothing else determined, like `?0: Clone` -- and on which the
+/// value is conditional. See `Prove::solve_conditional`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Conditional<T> {
+    pub value: T,
+    pub constraints: Vec<InEnvironment<Constraint>>,
+    pub goals: Vec<InEnvironment<Goal>>,
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Goal {
     /// Introduces a binding at depth 0, shifting other bindings up
@@ -524,21 +1305,49 @@ pub enum Goal {
     Quantified(QuantifierKind, Binders<Box<Goal>>),
     Implies(Vec<WhereClause>, Box<Goal>),
     And(Box<Goal>, Box<Goal>),
+
+    /// Negation as failure: holds if the goal, which must not contain
+    /// any unresolved inference variables, cannot be proven.
+    Not(Box<Goal>),
     Leaf(WhereClauseGoal),
 }
 
+impl Goal {
+    /// `if (hypotheses) { self }`: this goal, to be proven with the
+    /// `hypotheses` assumed to hold, as if they were where-clauses in
+    /// scope. This saves adding them to the program as impls, or
+    /// lowering the program afresh, to ask a question in a different
+    /// environment. The hypotheses sit outside the goal, and so cannot
+    /// name the variables that it binds.
+    pub fn assuming<I>(self, hypotheses: I) -> Goal
+        where I: IntoIterator<Item = WhereClause>
+    {
+        Goal::Implies(hypotheses.into_iter().collect(), Box::new(self))
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum QuantifierKind {
     ForAll, Exists
 }
 
+/// A region constraint; see `Constrained`.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Constraint {
     LifetimeEq(Lifetime, Lifetime),
+    TypeOutlives(Ty, Lifetime),
+    LifetimeOutlives(Lifetime, Lifetime),
 }
 
+mod database;
 pub mod debug;
+mod object_safety;
+mod simplify;
 mod tls;
+mod variance;
+
+pub use self::database::ChalkDatabase;
+pub use self::variance::Variance;
 
 pub use self::tls::set_current_program;
 pub use self::tls::with_current_program;
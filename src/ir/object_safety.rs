@@ -0,0 +1,115 @@
+//! Object safety: whether a trait `Foo` may be used as a type, as in
+//! `dyn Foo`. Chalk does not model methods, so the rules here only
+//! cover the parts of a trait that the IR knows about -- its where
+//! clauses, associated types and associated consts.
+
+use errors::*;
+use fold::{Fold, Folder};
+use ir::*;
+
+impl Program {
+    /// True if the trait `trait_id` is object safe. A trait is *not*
+    /// object safe if:
+    ///
+    /// - it is the `Sized` lang item, or requires `Self: Sized`;
+    /// - `Self` appears in one of its where clauses anywhere other
+    ///   than as the self type, as in `trait Eq where Self: PartialEq<Self>`;
+    /// - it has an associated type with parameters of its own;
+    /// - it has an associated const.
+    pub fn is_object_safe(&self, trait_id: ItemId) -> bool {
+        let trait_datum = &self.trait_data[&trait_id];
+        let self_index = trait_datum.binders.len() - 1;
+
+        if trait_datum.binders.value.flags.sized {
+            return false;
+        }
+
+        let where_clauses_ok = trait_datum.binders.value.where_clauses.iter().all(|wc| {
+            match *wc {
+                WhereClause::Implemented(ref trait_ref) => {
+                    let self_bound = trait_ref.parameters[0] == ParameterKind::Ty(Ty::Var(self_index));
+                    let sized = self.trait_data[&trait_ref.trait_id].binders.value.flags.sized;
+                    if self_bound && sized {
+                        return false;
+                    }
+                    !mentions_var(&trait_ref.parameters[1..], self_index)
+                }
+                WhereClause::Normalize(ref normalize) => {
+                    let (_, trait_params, other_params) = self.split_projection(&normalize.projection);
+                    !mentions_var(&trait_params[1..], self_index) &&
+                        !mentions_var(other_params, self_index) &&
+                        !mentions_var(&[normalize.ty.clone()], self_index)
+                }
+                WhereClause::NormalizeConst(ref normalize) => {
+                    !mentions_var(&normalize.projection.parameters[1..], self_index) &&
+                        !mentions_var(&[normalize.value], self_index)
+                }
+                WhereClause::TypeOutlives(_) |
+                WhereClause::LifetimeOutlives(_) |
+                WhereClause::WellFormed(_) |
+                WhereClause::ForAll(_) => true,
+            }
+        });
+
+        let associated_tys_ok = self.associated_ty_data.values().all(|datum| {
+            datum.trait_id != trait_id ||
+                datum.parameter_kinds.len() == trait_datum.binders.len()
+        });
+
+        let associated_consts_ok = self.associated_const_data.values().all(|datum| {
+            datum.trait_id != trait_id
+        });
+
+        where_clauses_ok && associated_tys_ok && associated_consts_ok
+    }
+
+    /// The associated types of the trait `trait_id`, in the order they
+    /// are declared. A `dyn Trait` type carries a value for each, after
+    /// the trait's own parameters.
+    pub fn dyn_associated_ty_ids(&self, trait_id: ItemId) -> Vec<ItemId> {
+        let mut ids: Vec<_> = self.associated_ty_data
+                                  .iter()
+                                  .filter(|&(_, datum)| datum.trait_id == trait_id)
+                                  .map(|(&id, _)| id)
+                                  .collect();
+        ids.sort();
+        ids
+    }
+}
+
+/// True if the bound variable with the given index (of any kind)
+/// appears free in any of `values`.
+fn mentions_var<T: Fold>(values: &[T], index: usize) -> bool {
+    let mut finder = VarFinder { index, found: false };
+    for value in values {
+        value.fold_with(&mut finder, 0).unwrap();
+    }
+    finder.found
+}
+
+struct VarFinder {
+    index: usize,
+    found: bool,
+}
+
+impl Folder for VarFinder {
+    fn fold_free_var(&mut self, depth: usize, binders: usize) -> Result<Ty> {
+        self.found |= depth == self.index;
+        Ok(Ty::Var(depth + binders))
+    }
+
+    fn fold_free_lifetime_var(&mut self, depth: usize, binders: usize) -> Result<Lifetime> {
+        self.found |= depth == self.index;
+        Ok(Lifetime::Var(depth + binders))
+    }
+
+    fn fold_free_krate_var(&mut self, depth: usize, binders: usize) -> Result<Krate> {
+        self.found |= depth == self.index;
+        Ok(Krate::Var(depth + binders))
+    }
+
+    fn fold_free_const_var(&mut self, depth: usize, binders: usize) -> Result<Const> {
+        self.found |= depth == self.index;
+        Ok(Const::Var(depth + binders))
+    }
+}
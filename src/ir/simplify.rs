@@ -0,0 +1,82 @@
+//! Simplifying goals before they are solved.
+//!
+//! Goals put together by lowering (or by coherence and the like) are
+//! often more deeply nested than they need to be: a chain of `And`s
+//! leaning every which way, `if` inside `if`, and equalities like
+//! `T = T` that hold whatever `T` is. None of that changes what it
+//! takes to prove the goal, but it does cost the solver, and makes
+//! the goal hard to read in a debug dump.
+
+use ir::*;
+
+impl Goal {
+    /// Returns an equivalent goal in which:
+    ///
+    /// - conjunctions are flattened into a single chain, `(A, (B, C))`;
+    /// - conjuncts that trivially hold, like `T = T`, are dropped;
+    /// - nested `if`s are merged, so that `if (A) { if (B) { G } }`
+    ///   becomes `if (A, B) { G }`, and `if` without hypotheses is
+    ///   dropped.
+    pub fn simplify(self) -> Goal {
+        match self {
+            Goal::Quantified(kind, Binders { binders, value }) => {
+                Goal::Quantified(kind, Binders { binders, value: Box::new(value.simplify()) })
+            }
+            Goal::Implies(mut hypotheses, goal) => {
+                match goal.simplify() {
+                    Goal::Implies(more_hypotheses, goal) => {
+                        hypotheses.extend(more_hypotheses);
+                        Goal::Implies(hypotheses, goal)
+                    }
+                    goal => if hypotheses.is_empty() {
+                        goal
+                    } else {
+                        Goal::Implies(hypotheses, Box::new(goal))
+                    },
+                }
+            }
+            goal @ Goal::And(..) => {
+                let mut conjuncts = vec![];
+                goal.push_conjuncts(&mut conjuncts);
+
+                // If everything holds trivially, keep one of them, as
+                // there is no such thing as an empty goal.
+                let first = conjuncts[0].clone();
+                conjuncts.retain(|goal| !goal.is_trivial());
+                let last = conjuncts.pop().unwrap_or(first);
+                conjuncts.into_iter()
+                         .rev()
+                         .fold(last, |goal, conjunct| Goal::And(Box::new(conjunct), Box::new(goal)))
+            }
+            Goal::Not(goal) => Goal::Not(Box::new(goal.simplify())),
+            goal @ Goal::Leaf(_) => goal,
+        }
+    }
+
+    /// Pushes the simplified conjuncts of `self` onto `conjuncts`, in
+    /// order.
+    fn push_conjuncts(self, conjuncts: &mut Vec<Goal>) {
+        match self {
+            Goal::And(goal1, goal2) => {
+                goal1.push_conjuncts(conjuncts);
+                goal2.push_conjuncts(conjuncts);
+            }
+            goal => match goal.simplify() {
+                goal @ Goal::And(..) => goal.push_conjuncts(conjuncts),
+                goal => conjuncts.push(goal),
+            },
+        }
+    }
+
+    /// True if `self` holds whatever its variables are, as `T = T`
+    /// and `T <: T` do.
+    fn is_trivial(&self) -> bool {
+        match *self {
+            Goal::Leaf(WhereClauseGoal::UnifyTys(Unify { ref a, ref b })) => a == b,
+            Goal::Leaf(WhereClauseGoal::UnifyLifetimes(Unify { ref a, ref b })) => a == b,
+            Goal::Leaf(WhereClauseGoal::UnifyKrates(Unify { ref a, ref b })) => a == b,
+            Goal::Leaf(WhereClauseGoal::Subtype(Subtype { ref a, ref b })) => a == b,
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,205 @@
+//! Variance: how subtyping between the arguments of a struct or enum
+//! carries over to the type as a whole. `Vec<&'static u32>` is a
+//! subtype of `Vec<&'a u32>` because `Vec` is covariant in its
+//! parameter, whereas `Cell<T>` is invariant in `T`.
+//!
+//! The variance of each parameter is inferred from how it is used in
+//! the type's fields. Since types may refer to one another (or to
+//! themselves), this is computed as a fixed point: every parameter
+//! starts out bivariant (unused) and is only ever made more
+//! restrictive, until nothing changes.
+
+use ir::*;
+use std::collections::HashMap;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Variance {
+    /// The parameter does not appear at all; any two arguments relate.
+    Bivariant,
+
+    /// `Foo<A> <: Foo<B>` if `A <: B`.
+    Covariant,
+
+    /// `Foo<A> <: Foo<B>` if `B <: A`.
+    Contravariant,
+
+    /// `Foo<A> <: Foo<B>` only if `A == B`.
+    Invariant,
+}
+
+impl Variance {
+    /// The variance of a position with variance `other` that is
+    /// nested inside a position with variance `self`; for example,
+    /// a function argument (contravariant) inside a function argument
+    /// is covariant again.
+    pub fn xform(self, other: Variance) -> Variance {
+        match (self, other) {
+            (Variance::Invariant, _) => Variance::Invariant,
+            (Variance::Bivariant, _) => Variance::Bivariant,
+            (Variance::Covariant, v) => v,
+            (Variance::Contravariant, v) => v.invert(),
+        }
+    }
+
+    /// The variance of a parameter used both with variance `self`
+    /// and with variance `other`.
+    pub fn join(self, other: Variance) -> Variance {
+        match (self, other) {
+            (Variance::Bivariant, v) | (v, Variance::Bivariant) => v,
+            (a, b) if a == b => a,
+            _ => Variance::Invariant,
+        }
+    }
+
+    pub fn invert(self) -> Variance {
+        match self {
+            Variance::Covariant => Variance::Contravariant,
+            Variance::Contravariant => Variance::Covariant,
+            v => v,
+        }
+    }
+}
+
+impl Program {
+    /// The variance of each parameter of every struct and enum. Other
+    /// sorts of types (closures, opaque types, `dyn Trait`) are
+    /// treated as invariant in all of their parameters.
+    pub fn variances(&self) -> HashMap<ItemId, Vec<Variance>> {
+        let mut variances: HashMap<ItemId, Vec<Variance>> = HashMap::new();
+        for (&id, datum) in &self.struct_data {
+            variances.insert(id, vec![Variance::Bivariant; datum.binders.len()]);
+        }
+        for (&id, datum) in &self.enum_data {
+            variances.insert(id, vec![Variance::Bivariant; datum.binders.len()]);
+        }
+
+        let field_tys: Vec<(ItemId, Vec<Ty>)> =
+            self.struct_data.iter()
+                            .map(|(&id, datum)| {
+                                (id, datum.binders.value.fields.iter().map(|f| f.ty.clone()).collect())
+                            })
+                            .chain(self.enum_data.iter().map(|(&id, datum)| {
+                                (id, datum.binders.value.field_tys())
+                            }))
+                            .collect();
+
+        loop {
+            let mut changed = false;
+            for &(id, ref tys) in &field_tys {
+                let result = {
+                    let mut walker = VarianceWalker {
+                        variances: &variances,
+                        result: variances[&id].clone(),
+                    };
+                    for ty in tys {
+                        walker.walk_ty(ty, Variance::Covariant, 0);
+                    }
+                    walker.result
+                };
+                if result != variances[&id] {
+                    variances.insert(id, result);
+                    changed = true;
+                }
+            }
+            if !changed {
+                return variances;
+            }
+        }
+    }
+}
+
+impl TypeName {
+    /// The variance of each of the `num_parameters` parameters of an
+    /// application of this type name, given a way to look up the
+    /// variances of the structs and enums in the program.
+    pub fn parameter_variances<'v, F>(&self, num_parameters: usize, variances: F) -> Vec<Variance>
+        where F: Fn(ItemId) -> Option<&'v [Variance]>
+    {
+        if let TypeName::ItemId(id) = *self {
+            if let Some(variances) = variances(id) {
+                return variances.to_vec();
+            }
+        }
+
+        match *self {
+            TypeName::Tuple(_) |
+            TypeName::Slice |
+            TypeName::Array |
+            TypeName::Raw(Mutability::Not) |
+            TypeName::PhantomData => {
+                vec![Variance::Covariant; num_parameters]
+            }
+            TypeName::Ref(Mutability::Not) => vec![Variance::Covariant, Variance::Covariant],
+            TypeName::Ref(Mutability::Mut) => vec![Variance::Covariant, Variance::Invariant],
+            TypeName::FnPtr(arity) => {
+                let mut variances = vec![Variance::Contravariant; arity];
+                variances.push(Variance::Covariant);
+                variances
+            }
+            TypeName::ItemId(_) |
+            TypeName::ForAll(_) |
+            TypeName::AssociatedType(_) |
+            TypeName::Scalar(_) |
+            TypeName::Never |
+            TypeName::Str |
+            TypeName::Raw(Mutability::Mut) => vec![Variance::Invariant; num_parameters],
+        }
+    }
+}
+
+/// Accumulates the variance of the bound variables of a single type
+/// definition, given the current estimates for all others.
+struct VarianceWalker<'v> {
+    variances: &'v HashMap<ItemId, Vec<Variance>>,
+    result: Vec<Variance>,
+}
+
+impl<'v> VarianceWalker<'v> {
+    fn use_var(&mut self, depth: usize, binders: usize, variance: Variance) {
+        if depth >= binders {
+            let slot = &mut self.result[depth - binders];
+            *slot = slot.join(variance);
+        }
+    }
+
+    fn walk_ty(&mut self, ty: &Ty, variance: Variance, binders: usize) {
+        match *ty {
+            Ty::Var(depth) => self.use_var(depth, binders, variance),
+            Ty::Apply(ref apply) => self.walk_apply(apply, variance, binders),
+            Ty::Projection(ref proj) => {
+                for parameter in &proj.parameters {
+                    self.walk_parameter(parameter, Variance::Invariant, binders);
+                }
+            }
+            Ty::ForAll(ref quantified_ty) => {
+                self.walk_ty(&quantified_ty.ty, variance, binders + quantified_ty.num_binders);
+            }
+        }
+    }
+
+    fn walk_apply(&mut self, apply: &ApplicationTy, variance: Variance, binders: usize) {
+        let variances = {
+            let variances = self.variances;
+            apply.name.parameter_variances(apply.parameters.len(),
+                                           |id| variances.get(&id).map(|v| &v[..]))
+        };
+        for (parameter, v) in apply.parameters.iter().zip(variances) {
+            self.walk_parameter(parameter, variance.xform(v), binders);
+        }
+    }
+
+    fn walk_parameter(&mut self, parameter: &Parameter, variance: Variance, binders: usize) {
+        match *parameter {
+            ParameterKind::Ty(ref ty) => self.walk_ty(ty, variance, binders),
+            ParameterKind::Lifetime(Lifetime::Var(depth)) => self.use_var(depth, binders, variance),
+            ParameterKind::Krate(Krate::Var(depth)) |
+            ParameterKind::Const(Const::Var(depth)) => {
+                // Krates and consts have no notion of subtyping.
+                self.use_var(depth, binders, Variance::Invariant)
+            }
+            ParameterKind::Lifetime(_) |
+            ParameterKind::Krate(_) |
+            ParameterKind::Const(_) => { }
+        }
+    }
+}
@@ -11,15 +11,18 @@ extern crate ena;
 extern crate lalrpop_intern;
 #[macro_use]
 extern crate lazy_static;
+#[cfg(feature = "parallel")]
+extern crate rayon;
 
 #[macro_use]
 mod macros;
 
 mod cast;
-mod errors;
+mod coherence;
+pub mod errors;
 mod fold;
-mod ir;
-mod lower;
-mod solve;
+pub mod ir;
+pub mod lower;
+pub mod solve;
 mod zip;
 
@@ -1,16 +1,26 @@
 use cast::Cast;
+use coherence::check_coherence;
 use chalk_parse::ast::*;
 use lalrpop_intern::intern;
 use errors::*;
+use fold::Subst;
 use ir;
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap};
+use self::wf::check_wf;
 
 mod test;
+mod wf;
 
 type TypeIds = HashMap<ir::Identifier, ir::ItemId>;
 type TypeKinds = HashMap<ir::ItemId, ir::TypeKind>;
 type AssociatedTyInfos = HashMap<(ir::ItemId, ir::Identifier), AssociatedTyInfo>;
+type AssociatedConstIds = HashMap<(ir::ItemId, ir::Identifier), ir::ItemId>;
 type ParameterMap = HashMap<ir::ParameterKind<ir::Identifier>, usize>;
+type DynTraits = RefCell<Vec<(Identifier, ir::ItemId)>>;
+type BuiltinArities = RefCell<BTreeSet<ir::TypeName>>;
+type TypeAliases = HashMap<ir::Identifier, ir::Binders<ir::Ty>>;
+type ParameterDefaults = HashMap<ir::ItemId, Vec<Option<ir::Binders<ir::Ty>>>>;
 
 #[derive(Clone, Debug)]
 struct Env<'k> {
@@ -18,7 +28,26 @@ struct Env<'k> {
     type_ids: &'k TypeIds,
     type_kinds: &'k TypeKinds,
     associated_ty_infos: &'k AssociatedTyInfos,
+    associated_const_ids: &'k AssociatedConstIds,
     parameter_map: ParameterMap,
+
+    /// Traits used as `dyn Trait` types; these are checked for
+    /// object safety once all the traits have been lowered.
+    dyn_traits: &'k DynTraits,
+
+    /// The tuple and fn pointer types used so far; see
+    /// `Program::builtin_arities`.
+    builtin_arities: &'k BuiltinArities,
+
+    /// The `#[lang(sized)]` trait, if the program declares one.
+    sized_trait_id: Option<ir::ItemId>,
+
+    /// The expansions of the type aliases declared so far.
+    type_aliases: &'k TypeAliases,
+
+    /// The defaults of the type parameters of each struct, enum and
+    /// trait lowered so far.
+    parameter_defaults: &'k ParameterDefaults,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -30,12 +59,20 @@ struct AssociatedTyInfo {
 enum NameLookup {
     Type(ir::ItemId),
     Parameter(usize),
+
+    /// A built-in type that is named like a struct, like `u32`, `str`
+    /// or `PhantomData`.
+    Builtin(ir::TypeName),
 }
 
 enum LifetimeLookup {
     Parameter(usize),
 }
 
+enum ConstLookup {
+    Parameter(usize),
+}
+
 enum KrateLookup {
     Parameter(usize),
     Id(ir::KrateId),
@@ -53,6 +90,20 @@ impl<'k> Env<'k> {
             return Ok(NameLookup::Type(*id));
         }
 
+        // Built-in scalars (and `str` and `PhantomData`) come last, so
+        // that a program may declare its own `struct u32 { }` if it likes.
+        if let Some(scalar) = ir::ScalarType::from_name(name.str) {
+            return Ok(NameLookup::Builtin(ir::TypeName::Scalar(scalar)));
+        }
+
+        if name.str == intern("str") {
+            return Ok(NameLookup::Builtin(ir::TypeName::Str));
+        }
+
+        if name.str == intern("PhantomData") {
+            return Ok(NameLookup::Builtin(ir::TypeName::PhantomData));
+        }
+
         bail!(ErrorKind::InvalidTypeName(name))
     }
 
@@ -61,7 +112,15 @@ impl<'k> Env<'k> {
             return Ok(LifetimeLookup::Parameter(*k));
         }
 
-        bail!("invalid lifetime name: {:?}", name.str);
+        bail!(ErrorKind::InvalidLifetimeName(name));
+    }
+
+    fn lookup_const(&self, name: Identifier) -> Result<ConstLookup> {
+        if let Some(k) = self.parameter_map.get(&ir::ParameterKind::Const(name.str)) {
+            return Ok(ConstLookup::Parameter(*k));
+        }
+
+        bail!(ErrorKind::InvalidConstName(name));
     }
 
     // krate lookup is infallible because we assume free identifiers are just krate ids
@@ -107,6 +166,72 @@ impl<'k> Env<'k> {
     fn in_krate(&self, krate: ir::Krate) -> Self {
         Env { krate: krate, ..self.clone() }
     }
+
+    /// The expansion of the type alias `name`, unless `name` is
+    /// shadowed by a type parameter.
+    fn type_alias(&self, name: Identifier) -> Option<&'k ir::Binders<ir::Ty>> {
+        if self.parameter_map.contains_key(&ir::ParameterKind::Ty(name.str)) {
+            return None;
+        }
+        self.type_aliases.get(&name.str)
+    }
+
+    /// Expands a use of the type alias `name` with the given arguments.
+    fn expand_type_alias(&self,
+                         name: Identifier,
+                         alias: &ir::Binders<ir::Ty>,
+                         args: &[Parameter])
+                         -> Result<ir::Ty>
+    {
+        if alias.len() != args.len() {
+            bail!(ErrorKind::IncorrectNumberOfTypeParameters(name, alias.len(), args.len()))
+        }
+
+        let parameters = args.iter().map(|a| Ok(a.lower(self)?)).collect::<Result<Vec<_>>>()?;
+        for (param, arg) in alias.binders.iter().zip(parameters.iter()) {
+            check_type_kinds("incorrect parameter kind", param, arg)?;
+        }
+
+        Ok(Subst::apply(&parameters, &alias.value))
+    }
+
+    /// Fills in the defaults of any trailing type parameters of `id`
+    /// omitted from `parameters`, as for `Foo` given `struct Foo<T = i32>`.
+    /// For a trait, `self_ty` is what `Self` stands for in the defaults;
+    /// defaults that mention `Self` cannot be used without one.
+    fn apply_parameter_defaults(&self,
+                                id: ir::ItemId,
+                                parameters: &mut Vec<ir::Parameter>,
+                                self_ty: Option<&ir::Parameter>) {
+        let defaults = match self.parameter_defaults.get(&id) {
+            Some(defaults) => defaults,
+            None => return,
+        };
+
+        while let Some(&Some(ref default)) = defaults.get(parameters.len()) {
+            let mut subst = parameters.clone();
+            subst.extend(self_ty.cloned());
+            if subst.len() != default.len() {
+                return;
+            }
+            parameters.push(Subst::apply(&subst, &default.value).cast());
+        }
+    }
+
+    /// The associated types of the trait `trait_id` whose values a
+    /// `dyn Trait` type carries, in the order they are declared.
+    /// Associated types with parameters of their own are left out;
+    /// they make the trait not object safe anyway.
+    fn dyn_associated_tys(&self, trait_id: ir::ItemId) -> Vec<(ir::Identifier, ir::ItemId)> {
+        let mut associated_tys: Vec<_> =
+            self.associated_ty_infos
+                .iter()
+                .filter(|&(&(id, _), info)| id == trait_id && info.addl_parameter_kinds.is_empty())
+                .map(|(&(_, name), info)| (name, info.id))
+                .collect();
+        associated_tys.sort_by_key(|&(_, id)| id);
+        associated_tys
+    }
 }
 
 pub trait LowerProgram {
@@ -115,6 +240,10 @@ pub trait LowerProgram {
 
 const DEFAULT_KRATE_NAME: &str = "krate";
 
+/// The crate of the items marked `#[upstream]`, which is shorthand for
+/// `crate upstream { ... }`.
+const UPSTREAM_KRATE_NAME: &str = "upstream";
+
 impl LowerProgram for Program {
     fn lower(&self) -> Result<ir::Program> {
         let mut flat_items = vec![];
@@ -127,9 +256,21 @@ impl LowerProgram for Program {
             for item in items {
                 match *item {
                     Item::StructDefn(_) |
+                    Item::EnumDefn(_) |
                     Item::TraitDefn(_) |
-                    Item::Impl(_) =>
-                        into.push((krate_id, item)),
+                    Item::Impl(_) |
+                    Item::ClosureDefn(_) |
+                    Item::FnDefn(_) |
+                    Item::GeneratorDefn(_) |
+                    Item::ExternTyDefn(_) |
+                    Item::OpaqueTyDefn(_) |
+                    Item::TypeAliasDefn(_) => {
+                        if Attribute::find(item.attributes(), "upstream").is_some() {
+                            into.push((ir::KrateId { name: intern(UPSTREAM_KRATE_NAME) }, item));
+                        } else {
+                            into.push((krate_id, item));
+                        }
+                    }
                     Item::KrateDefn(ref defn) =>
                         flatten(ir::KrateId { name: defn.name.str }, &defn.items, into),
                 }
@@ -163,39 +304,162 @@ impl LowerProgram for Program {
             }
         }
 
+        // Create ids for associated consts
+        let mut associated_const_ids = HashMap::new();
+        for (&(_, item), &item_id) in flat_items.iter().zip(&item_ids) {
+            if let Item::TraitDefn(ref d) = *item {
+                for defn in &d.assoc_const_defns {
+                    associated_const_ids.insert((item_id, defn.name.str), next_item_id());
+                }
+            }
+        }
+
         let mut type_ids = HashMap::new();
         let mut type_kinds = HashMap::new();
         for (&(krate_id, item), &item_id) in flat_items.iter().zip(&item_ids) {
             let k = match *item {
                 Item::StructDefn(ref d) => d.lower_type_kind(krate_id)?,
+                Item::EnumDefn(ref d) => d.lower_type_kind(krate_id)?,
                 Item::TraitDefn(ref d) => d.lower_type_kind(krate_id)?,
+                Item::ClosureDefn(ref d) => d.lower_type_kind(krate_id)?,
+                Item::FnDefn(ref d) => d.lower_type_kind(krate_id)?,
+                Item::GeneratorDefn(ref d) => d.lower_type_kind(krate_id)?,
+                Item::ExternTyDefn(ref d) => d.lower_type_kind(krate_id)?,
+                Item::OpaqueTyDefn(ref d) => d.lower_type_kind(krate_id)?,
                 Item::Impl(_) => continue,
                 Item::KrateDefn(_) => continue,
+                Item::TypeAliasDefn(_) => continue,
             };
             type_ids.insert(k.name, item_id);
             type_kinds.insert(item_id, k);
         }
 
+        let mut lang_items = HashMap::new();
+        for (&(_, item), &item_id) in flat_items.iter().zip(&item_ids) {
+            if let Item::TraitDefn(TraitDefn { lang: Some(lang), .. }) = *item {
+                let lang_item = match ir::LangItem::from_name(lang.str) {
+                    Some(lang_item) => lang_item,
+                    None => bail!(ErrorKind::UnknownLangItem(lang)),
+                };
+                if lang_items.insert(lang_item, item_id).is_some() {
+                    bail!(ErrorKind::DuplicateLangItem(lang));
+                }
+            }
+        }
+        let sized_trait_id = lang_items.get(&ir::LangItem::Sized).cloned();
+
+        let dyn_traits = RefCell::new(vec![]);
+        let builtin_arities = RefCell::new(BTreeSet::new());
+
+        // Defaults for type parameters are filled in wherever a use
+        // omits them. Each may refer to the parameters before it (and
+        // `Self`, in a trait), and to the defaults of earlier items.
+        let no_type_aliases = HashMap::new();
+        let mut parameter_defaults = HashMap::new();
+        for (&(_, item), &item_id) in flat_items.iter().zip(&item_ids) {
+            let (name, parameter_kinds, defaults, self_param) = match *item {
+                Item::StructDefn(ref d) => (d.name, &d.parameter_kinds, &d.parameter_defaults, None),
+                Item::EnumDefn(ref d) => (d.name, &d.parameter_kinds, &d.parameter_defaults, None),
+                Item::TraitDefn(ref d) => {
+                    let self_param = ir::ParameterKind::Ty(intern(SELF));
+                    (d.name, &d.parameter_kinds, &d.parameter_defaults, Some(self_param))
+                }
+                _ => continue,
+            };
+            if defaults.iter().all(|d| d.is_none()) {
+                continue;
+            }
+            let lowered = {
+                let env = Env {
+                    krate: ir::Krate::Id(default_krate_id),
+                    type_ids: &type_ids,
+                    type_kinds: &type_kinds,
+                    associated_ty_infos: &associated_ty_infos,
+                    associated_const_ids: &associated_const_ids,
+                    parameter_map: HashMap::new(),
+                    dyn_traits: &dyn_traits,
+                    builtin_arities: &builtin_arities,
+                    sized_trait_id: sized_trait_id,
+                    type_aliases: &no_type_aliases,
+                    parameter_defaults: &parameter_defaults,
+                };
+                lower_parameter_defaults(name, parameter_kinds, defaults, self_param, &env)?
+            };
+            parameter_defaults.insert(item_id, lowered);
+        }
+
+        // Type aliases are expanded wherever they are used, so we
+        // lower them first. Each may only refer to the aliases
+        // declared before it, which rules out cycles.
+        let mut type_aliases = HashMap::new();
+        for &(_, item) in &flat_items {
+            if let Item::TypeAliasDefn(ref d) = *item {
+                if type_ids.contains_key(&d.name.str) || type_aliases.contains_key(&d.name.str) {
+                    bail!(ErrorKind::DuplicateTypeAlias(d.name));
+                }
+                let expansion = {
+                    let env = Env {
+                        krate: ir::Krate::Id(default_krate_id),
+                        type_ids: &type_ids,
+                        type_kinds: &type_kinds,
+                        associated_ty_infos: &associated_ty_infos,
+                        associated_const_ids: &associated_const_ids,
+                        parameter_map: HashMap::new(),
+                        dyn_traits: &dyn_traits,
+                        builtin_arities: &builtin_arities,
+                        sized_trait_id: sized_trait_id,
+                        type_aliases: &type_aliases,
+                        parameter_defaults: &parameter_defaults,
+                    };
+                    env.in_binders(d.all_parameters(), |env| d.ty.lower(env))?
+                };
+                type_aliases.insert(d.name.str, expansion);
+            }
+        }
+
         let mut struct_data = HashMap::new();
+        let mut enum_data = HashMap::new();
+        let mut closure_data = HashMap::new();
+        let mut fn_def_data = HashMap::new();
+        let mut generator_data = HashMap::new();
+        let mut extern_ty_data = HashMap::new();
+        let mut opaque_ty_data = HashMap::new();
         let mut trait_data = HashMap::new();
         let mut impl_data = HashMap::new();
+        let mut impl_spans = HashMap::new();
         let mut associated_ty_data = HashMap::new();
+        let mut associated_const_data = HashMap::new();
         for (&(krate_id, item), &item_id) in flat_items.iter().zip(&item_ids) {
             let empty_env = Env {
                 krate: ir::Krate::Id(default_krate_id),
                 type_ids: &type_ids,
                 type_kinds: &type_kinds,
                 associated_ty_infos: &associated_ty_infos,
+                associated_const_ids: &associated_const_ids,
                 parameter_map: HashMap::new(),
+                dyn_traits: &dyn_traits,
+                builtin_arities: &builtin_arities,
+                sized_trait_id: sized_trait_id,
+                type_aliases: &type_aliases,
+                parameter_defaults: &parameter_defaults,
             };
 
+            // An error that points at nothing more precise points at
+            // the item it was found in.
             match *item {
-                Item::KrateDefn(_) => { }
+                Item::KrateDefn(_) |
+                Item::TypeAliasDefn(_) => { }
                 Item::StructDefn(ref d) => {
-                    struct_data.insert(item_id, d.lower_struct(krate_id, item_id, &empty_env)?);
+                    struct_data.insert(item_id, d.lower_struct(krate_id, item_id, &empty_env)
+                                                .map_err(|e| e.at(d.span))?);
+                }
+                Item::EnumDefn(ref d) => {
+                    enum_data.insert(item_id, d.lower_enum(krate_id, item_id, &empty_env)
+                                              .map_err(|e| e.at(d.span))?);
                 }
                 Item::TraitDefn(ref d) => {
-                    trait_data.insert(item_id, d.lower_trait(krate_id, item_id, &empty_env)?);
+                    trait_data.insert(item_id, d.lower_trait(krate_id, item_id, &empty_env)
+                                               .map_err(|e| e.at(d.span))?);
 
                     let trait_datum = &trait_data[&item_id];
                     for defn in &d.assoc_ty_defns {
@@ -211,22 +475,148 @@ impl LowerProgram for Program {
                         let mut parameter_kinds = defn.all_parameters();
                         parameter_kinds.extend(d.all_parameters());
 
+                        let bound = empty_env.in_binders(parameter_kinds.clone(), |env| {
+                            let mut where_clauses = vec![ir::WhereClause::Implemented(trait_ref)];
+                            where_clauses.extend(defn.where_clauses.lower(env)?);
+                            let default = match defn.default {
+                                Some(ref ty) => Some(ty.lower(env)?),
+                                None => None,
+                            };
+                            Ok((where_clauses, default))
+                        })?;
+                        let (where_clauses, default) = bound.value;
+
                         associated_ty_data.insert(info.id, ir::AssociatedTyDatum {
                             trait_id: item_id,
                             name: defn.name.str,
                             parameter_kinds: parameter_kinds,
-                            where_clauses: vec![ir::WhereClause::Implemented(trait_ref)]
+                            where_clauses: where_clauses,
+                            default: default,
+                        });
+                    }
+
+                    for defn in &d.assoc_const_defns {
+                        let id = associated_const_ids[&(item_id, defn.name.str)];
+                        associated_const_data.insert(id, ir::AssociatedConstDatum {
+                            trait_id: item_id,
+                            name: defn.name.str,
                         });
                     }
                 }
                 Item::Impl(ref d) => {
-                    impl_data.insert(item_id, d.lower_impl(krate_id, &empty_env)?);
+                    impl_data.insert(item_id, d.lower_impl(krate_id, &empty_env)
+                                              .map_err(|e| e.at(d.span))?);
+                    impl_spans.insert(item_id, d.span);
+                }
+                Item::ClosureDefn(ref d) => {
+                    closure_data.insert(item_id, d.lower_closure(krate_id, item_id, &empty_env)
+                                                 .map_err(|e| e.at(d.span))?);
+                }
+                Item::FnDefn(ref d) => {
+                    fn_def_data.insert(item_id, d.lower_fn_def(krate_id, item_id, &empty_env)
+                                                .map_err(|e| e.at(d.span))?);
+                }
+                Item::GeneratorDefn(ref d) => {
+                    generator_data.insert(item_id, d.lower_generator(krate_id, item_id, &empty_env)
+                                                   .map_err(|e| e.at(d.span))?);
+                }
+                Item::ExternTyDefn(_) => {
+                    extern_ty_data.insert(item_id, ir::ExternTyDatum {
+                        krate_id: krate_id,
+                        self_ty: ir::ApplicationTy {
+                            name: ir::TypeName::ItemId(item_id),
+                            parameters: vec![],
+                        },
+                    });
                 }
+                Item::OpaqueTyDefn(ref d) => {
+                    opaque_ty_data.insert(item_id, d.lower_opaque_ty(krate_id, item_id, &empty_env)
+                                                   .map_err(|e| e.at(d.span))?);
+                }
+            }
+        }
+
+        let mut program = ir::Program {
+            type_ids,
+            type_kinds,
+            type_aliases,
+            parameter_defaults,
+            struct_data,
+            enum_data,
+            closure_data,
+            fn_def_data,
+            generator_data,
+            extern_ty_data,
+            opaque_ty_data,
+            trait_data,
+            impl_data,
+            impl_spans,
+            associated_ty_data,
+            associated_const_data,
+            lang_items,
+            builtin_arities: builtin_arities.into_inner(),
+        };
+
+        check_object_safety(&program, &dyn_traits)?;
+        check_trait_aliases(&program)?;
+        check_coherence(&mut program)?;
+        check_wf(&program)?;
+
+        Ok(program)
+    }
+}
+
+/// Lowers the defaults of an item's type parameters; the default of
+/// each parameter is bound by the parameters before it, followed by
+/// `self_param` if given.
+fn lower_parameter_defaults(name: Identifier,
+                            parameter_kinds: &[ParameterKind],
+                            defaults: &[Option<Ty>],
+                            self_param: Option<ir::ParameterKind<ir::Identifier>>,
+                            env: &Env)
+                            -> Result<Vec<Option<ir::Binders<ir::Ty>>>>
+{
+    let mut lowered = vec![];
+    for (i, default) in defaults.iter().enumerate() {
+        match *default {
+            Some(ref ty) => {
+                let binders = parameter_kinds[..i].iter()
+                                                  .map(|pk| pk.lower())
+                                                  .chain(self_param)
+                                                  .collect::<Vec<_>>();
+                lowered.push(Some(env.in_binders(binders, |env| ty.lower(env))?));
+            }
+            None => {
+                if lowered.iter().any(|d| d.is_some()) {
+                    bail!(ErrorKind::DefaultsNotLast(name));
+                }
+                lowered.push(None);
             }
         }
+    }
+    Ok(lowered)
+}
+
+/// Trait aliases are implemented through their bounds only; they
+/// cannot have impls of their own.
+fn check_trait_aliases(program: &ir::Program) -> Result<()> {
+    for (impl_id, impl_datum) in &program.impl_data {
+        let trait_id = impl_datum.binders.value.trait_ref.trait_ref().trait_id;
+        if program.trait_data[&trait_id].binders.value.flags.alias {
+            bail!(ErrorKind::ImplOfTraitAlias(program.type_kinds[&trait_id].name,
+                                              program.impl_spans[impl_id]));
+        }
+    }
+    Ok(())
+}
 
-        Ok(ir::Program { type_ids, type_kinds, struct_data, trait_data, impl_data, associated_ty_data, })
+fn check_object_safety(program: &ir::Program, dyn_traits: &DynTraits) -> Result<()> {
+    for &(name, trait_id) in dyn_traits.borrow().iter() {
+        if !program.is_object_safe(trait_id) {
+            bail!(ErrorKind::NotObjectSafe(name));
+        }
     }
+    Ok(())
 }
 
 trait LowerTypeKind {
@@ -281,6 +671,66 @@ impl LowerParameterMap for StructDefn {
     }
 }
 
+impl LowerParameterMap for EnumDefn {
+    fn synthetic_parameters(&self) -> Option<ir::ParameterKind<ir::Identifier>> {
+        None
+    }
+
+    fn declared_parameters(&self) -> &[ParameterKind] {
+        &self.parameter_kinds
+    }
+}
+
+impl LowerParameterMap for TypeAliasDefn {
+    fn synthetic_parameters(&self) -> Option<ir::ParameterKind<ir::Identifier>> {
+        None
+    }
+
+    fn declared_parameters(&self) -> &[ParameterKind] {
+        &self.parameter_kinds
+    }
+}
+
+impl LowerParameterMap for ClosureDefn {
+    fn synthetic_parameters(&self) -> Option<ir::ParameterKind<ir::Identifier>> {
+        None
+    }
+
+    fn declared_parameters(&self) -> &[ParameterKind] {
+        &self.parameter_kinds
+    }
+}
+
+impl LowerParameterMap for FnDefn {
+    fn synthetic_parameters(&self) -> Option<ir::ParameterKind<ir::Identifier>> {
+        None
+    }
+
+    fn declared_parameters(&self) -> &[ParameterKind] {
+        &self.parameter_kinds
+    }
+}
+
+impl LowerParameterMap for GeneratorDefn {
+    fn synthetic_parameters(&self) -> Option<ir::ParameterKind<ir::Identifier>> {
+        None
+    }
+
+    fn declared_parameters(&self) -> &[ParameterKind] {
+        &self.parameter_kinds
+    }
+}
+
+impl LowerParameterMap for OpaqueTyDefn {
+    fn synthetic_parameters(&self) -> Option<ir::ParameterKind<ir::Identifier>> {
+        None
+    }
+
+    fn declared_parameters(&self) -> &[ParameterKind] {
+        &self.parameter_kinds
+    }
+}
+
 impl LowerParameterMap for Impl {
     fn synthetic_parameters(&self) -> Option<ir::ParameterKind<ir::Identifier>> {
         None
@@ -332,15 +782,52 @@ impl LowerParameterKind for ParameterKind {
             ParameterKind::Ty(ref n) => ir::ParameterKind::Ty(n.str),
             ParameterKind::Lifetime(ref n) => ir::ParameterKind::Lifetime(n.str),
             ParameterKind::Krate(ref n) => ir::ParameterKind::Krate(n.str),
+            ParameterKind::Const(ref n) => ir::ParameterKind::Const(n.str),
         }
     }
 }
 
 trait LowerWhereClauses {
-    fn where_clauses(&self) -> &[WhereClause];
+    fn where_clauses(&self) -> &[Spanned<WhereClause>];
+
+    /// The parameters that are implicitly bound by `Sized`, unless
+    /// they opt out with `T: ?Sized`.
+    fn implicitly_sized_parameters(&self) -> &[ParameterKind] {
+        &[]
+    }
 
     fn lower_where_clauses(&self, env: &Env) -> Result<Vec<ir::WhereClause>> {
-        self.where_clauses().lower(env)
+        let mut where_clauses = vec![];
+        let mut unsized_tys = vec![];
+        for wc in self.where_clauses() {
+            match wc.value {
+                WhereClause::MaybeImplemented { trait_ref: ref ast_trait_ref } => {
+                    let trait_ref = ast_trait_ref.lower(env).map_err(|e| e.at(wc.span))?;
+                    if Some(trait_ref.trait_id) != env.sized_trait_id {
+                        bail!(ErrorKind::MaybeBoundNotSized(ast_trait_ref.trait_name));
+                    }
+                    unsized_tys.push(trait_ref.parameters[0].clone());
+                }
+                _ => where_clauses.extend(lower_spanned(wc, env)?),
+            }
+        }
+
+        if let Some(sized_trait_id) = env.sized_trait_id {
+            for parameter in self.implicitly_sized_parameters() {
+                if let ParameterKind::Ty(ref name) = *parameter {
+                    let index = env.parameter_map[&ir::ParameterKind::Ty(name.str)];
+                    let ty: ir::Parameter = ir::Ty::Var(index).cast();
+                    if !unsized_tys.contains(&ty) {
+                        where_clauses.push(ir::TraitRef {
+                            trait_id: sized_trait_id,
+                            parameters: vec![ty],
+                        }.cast());
+                    }
+                }
+            }
+        }
+
+        Ok(where_clauses)
     }
 }
 
@@ -358,10 +845,108 @@ impl LowerTypeKind for StructDefn {
     }
 }
 
+impl LowerTypeKind for EnumDefn {
+    fn lower_type_kind(&self, krate_id: ir::KrateId) -> Result<ir::TypeKind> {
+        Ok(ir::TypeKind {
+            sort: ir::TypeSort::Enum,
+            name: self.name.str,
+            krate_id: krate_id,
+            binders: ir::Binders {
+                binders: self.all_parameters().anonymize(),
+                value: (),
+            },
+        })
+    }
+}
+
+impl LowerTypeKind for ClosureDefn {
+    fn lower_type_kind(&self, krate_id: ir::KrateId) -> Result<ir::TypeKind> {
+        Ok(ir::TypeKind {
+            sort: ir::TypeSort::Closure,
+            name: self.name.str,
+            krate_id: krate_id,
+            binders: ir::Binders {
+                binders: self.all_parameters().anonymize(),
+                value: (),
+            },
+        })
+    }
+}
+
+impl LowerTypeKind for FnDefn {
+    fn lower_type_kind(&self, krate_id: ir::KrateId) -> Result<ir::TypeKind> {
+        Ok(ir::TypeKind {
+            sort: ir::TypeSort::FnDef,
+            name: self.name.str,
+            krate_id: krate_id,
+            binders: ir::Binders {
+                binders: self.all_parameters().anonymize(),
+                value: (),
+            },
+        })
+    }
+}
+
+impl LowerTypeKind for GeneratorDefn {
+    fn lower_type_kind(&self, krate_id: ir::KrateId) -> Result<ir::TypeKind> {
+        Ok(ir::TypeKind {
+            sort: ir::TypeSort::Generator,
+            name: self.name.str,
+            krate_id: krate_id,
+            binders: ir::Binders {
+                binders: self.all_parameters().anonymize(),
+                value: (),
+            },
+        })
+    }
+}
+
+impl LowerTypeKind for ExternTyDefn {
+    fn lower_type_kind(&self, krate_id: ir::KrateId) -> Result<ir::TypeKind> {
+        Ok(ir::TypeKind {
+            sort: ir::TypeSort::Extern,
+            name: self.name.str,
+            krate_id: krate_id,
+            binders: ir::Binders {
+                binders: vec![],
+                value: (),
+            },
+        })
+    }
+}
+
+impl LowerTypeKind for OpaqueTyDefn {
+    fn lower_type_kind(&self, krate_id: ir::KrateId) -> Result<ir::TypeKind> {
+        Ok(ir::TypeKind {
+            sort: ir::TypeSort::Opaque,
+            name: self.name.str,
+            krate_id: krate_id,
+            binders: ir::Binders {
+                binders: self.all_parameters().anonymize(),
+                value: (),
+            },
+        })
+    }
+}
+
 impl LowerWhereClauses for StructDefn {
-    fn where_clauses(&self) -> &[WhereClause] {
+    fn where_clauses(&self) -> &[Spanned<WhereClause>] {
+        &self.where_clauses
+    }
+
+    fn implicitly_sized_parameters(&self) -> &[ParameterKind] {
+        &self.parameter_kinds
+    }
+}
+
+impl LowerWhereClauses for EnumDefn {
+    fn where_clauses(&self) -> &[Spanned<WhereClause>] {
         &self.where_clauses
     }
+
+    fn implicitly_sized_parameters(&self) -> &[ParameterKind] {
+        &self.parameter_kinds
+    }
 }
 
 impl LowerTypeKind for TraitDefn {
@@ -381,26 +966,84 @@ impl LowerTypeKind for TraitDefn {
 }
 
 impl LowerWhereClauses for TraitDefn {
-    fn where_clauses(&self) -> &[WhereClause] {
+    fn where_clauses(&self) -> &[Spanned<WhereClause>] {
         &self.where_clauses
     }
+
+    // `Self` is not among these, so it is never implicitly `Sized`.
+    fn implicitly_sized_parameters(&self) -> &[ParameterKind] {
+        &self.parameter_kinds
+    }
 }
 
-impl LowerWhereClauses for Impl {
-    fn where_clauses(&self) -> &[WhereClause] {
+impl LowerWhereClauses for FnDefn {
+    fn where_clauses(&self) -> &[Spanned<WhereClause>] {
         &self.where_clauses
     }
-}
 
-trait LowerWhereClauseVec {
-    fn lower(&self, env: &Env) -> Result<Vec<ir::WhereClause>>;
+    fn implicitly_sized_parameters(&self) -> &[ParameterKind] {
+        &self.parameter_kinds
+    }
 }
 
-impl LowerWhereClauseVec for [WhereClause] {
+impl LowerWhereClauses for Impl {
+    fn where_clauses(&self) -> &[Spanned<WhereClause>] {
+        &self.where_clauses
+    }
+
+    fn implicitly_sized_parameters(&self) -> &[ParameterKind] {
+        &self.parameter_kinds
+    }
+}
+
+trait LowerWhereClauseVec {
+    fn lower(&self, env: &Env) -> Result<Vec<ir::WhereClause>>;
+}
+
+impl LowerWhereClauseVec for [WhereClause] {
     fn lower(&self, env: &Env) -> Result<Vec<ir::WhereClause>> {
-        self.iter()
-            .map(|wc| wc.lower(env))
-            .collect()
+        let mut where_clauses = vec![];
+        for wc in self {
+            where_clauses.extend(lower_expanded(wc, env)?);
+        }
+        Ok(where_clauses)
+    }
+}
+
+impl LowerWhereClauseVec for [Spanned<WhereClause>] {
+    fn lower(&self, env: &Env) -> Result<Vec<ir::WhereClause>> {
+        let mut where_clauses = vec![];
+        for wc in self {
+            where_clauses.extend(lower_spanned(wc, env)?);
+        }
+        Ok(where_clauses)
+    }
+}
+
+/// Lowers a where clause of an item like `lower_expanded`, pointing
+/// any error at the where clause.
+fn lower_spanned(wc: &Spanned<WhereClause>, env: &Env) -> Result<Vec<ir::WhereClause>> {
+    lower_expanded(&wc.value, env).map_err(|e| e.at(wc.span))
+}
+
+/// Lowers a where-clause in the context of a clause, spelling out what
+/// it is shorthand for: `T: Iterator<Item = u32>` is both `T:
+/// Iterator` and `<T as Iterator>::Item ==> u32`, just as in Rust.
+fn lower_expanded(wc: &WhereClause, env: &Env) -> Result<Vec<ir::WhereClause>> {
+    match *wc {
+        WhereClause::ProjectionEq { ref projection, eq: true, .. } => {
+            let trait_ref = projection.trait_ref.lower(env)?;
+            Ok(vec![ir::WhereClause::Implemented(trait_ref), wc.lower(env)?])
+        }
+        WhereClause::ForAll { ref parameter_kinds, ref where_clause } => {
+            let ir::Binders { binders, value } = env.in_binders(parameter_kinds.iter().map(|pk| pk.lower()), |env| {
+                lower_expanded(where_clause, env)
+            })?;
+            Ok(value.into_iter()
+                    .map(|wc| ir::WhereClause::ForAll(ir::Binders { binders: binders.clone(), value: Box::new(wc) }))
+                    .collect())
+        }
+        _ => Ok(vec![wc.lower(env)?]),
     }
 }
 
@@ -422,14 +1065,47 @@ impl LowerWhereClause<ir::WhereClause> for WhereClause {
                     ty: ty.lower(env)?,
                 })
             }
+            WhereClause::ConstProjectionEq { ref projection, ref value } => {
+                ir::WhereClause::NormalizeConst(ir::NormalizeConst {
+                    projection: projection.lower(env)?,
+                    value: value.lower(env)?,
+                })
+            }
+            WhereClause::TypeOutlives { ref ty, ref lifetime } => {
+                ir::WhereClause::TypeOutlives(ir::TypeOutlives {
+                    ty: ty.lower(env)?,
+                    lifetime: lifetime.lower(env)?,
+                })
+            }
+            WhereClause::LifetimeOutlives { ref a, ref b } => {
+                ir::WhereClause::LifetimeOutlives(ir::LifetimeOutlives {
+                    a: a.lower(env)?,
+                    b: b.lower(env)?,
+                })
+            }
+            WhereClause::TyWellFormed { ref ty } => {
+                ir::WellFormed::Ty(ty.lower(env)?).cast()
+            }
+            WhereClause::TraitRefWellFormed { ref trait_ref } => {
+                ir::WellFormed::TraitRef(trait_ref.lower(env)?).cast()
+            }
+            WhereClause::ForAll { ref parameter_kinds, ref where_clause } => {
+                let binders = env.in_binders(parameter_kinds.iter().map(|pk| pk.lower()), |env| {
+                    let wc: ir::WhereClause = where_clause.lower(env)?;
+                    Ok(Box::new(wc))
+                })?;
+                ir::WhereClause::ForAll(binders)
+            }
             WhereClause::ProjectionEq { eq: false, .. } |
-            WhereClause::TyWellFormed { .. } |
-            WhereClause::TraitRefWellFormed { .. } |
             WhereClause::LocalTo { .. } |
+            WhereClause::Unsize { .. } |
+            WhereClause::Subtype { .. } |
+            WhereClause::Numeric { .. } |
             WhereClause::UnifyTys { .. } |
             WhereClause::UnifyKrates { .. } |
             WhereClause::UnifyLifetimes { .. } |
-            WhereClause::NotImplemented { .. } => {
+            WhereClause::NotImplemented { .. } |
+            WhereClause::MaybeImplemented { .. } => {
                 bail!("this form of where-clause not allowed here")
             }
         })
@@ -443,15 +1119,17 @@ impl LowerWhereClause<ir::WhereClauseGoal> for WhereClause {
     fn lower(&self, env: &Env) -> Result<ir::WhereClauseGoal> {
         Ok(match *self {
             WhereClause::Implemented { .. } |
-            WhereClause::ProjectionEq { .. } => {
+            WhereClause::ProjectionEq { .. } |
+            WhereClause::ConstProjectionEq { .. } |
+            WhereClause::TypeOutlives { .. } |
+            WhereClause::LifetimeOutlives { .. } |
+            WhereClause::TyWellFormed { .. } |
+            WhereClause::TraitRefWellFormed { .. } => {
                 let wc: ir::WhereClause = self.lower(env)?;
-                wc.cast()
-            }
-            WhereClause::TyWellFormed { ref ty } => {
-                ir::WellFormed::Ty(ty.lower(env)?).cast()
-            }
-            WhereClause::TraitRefWellFormed { ref trait_ref } => {
-                ir::WellFormed::TraitRef(trait_ref.lower(env)?).cast()
+                match wc.cast() {
+                    ir::Goal::Leaf(leaf) => leaf,
+                    _ => unreachable!(),
+                }
             }
             WhereClause::LocalTo { ref ty, ref krate } => {
                 ir::LocalTo {
@@ -459,6 +1137,27 @@ impl LowerWhereClause<ir::WhereClauseGoal> for WhereClause {
                     krate: krate.lower(env)?,
                 }.cast()
             }
+            WhereClause::Unsize { ref source, ref target } => {
+                ir::Unsize {
+                    source: source.lower(env)?,
+                    target: target.lower(env)?,
+                }.cast()
+            }
+            WhereClause::Subtype { ref a, ref b } => {
+                ir::Subtype {
+                    a: a.lower(env)?,
+                    b: b.lower(env)?,
+                }.cast()
+            }
+            WhereClause::Numeric { ref ty, kind } => {
+                ir::Numeric {
+                    ty: ty.lower(env)?,
+                    kind: match kind {
+                        NumericKind::Integer => ir::NumericKind::Integer,
+                        NumericKind::Float => ir::NumericKind::Float,
+                    },
+                }.cast()
+            }
             WhereClause::UnifyTys { ref a, ref b, eq: true } => {
                 ir::Unify {
                     a: a.lower(env)?,
@@ -489,10 +1188,41 @@ impl LowerWhereClause<ir::WhereClauseGoal> for WhereClause {
             WhereClause::NotImplemented { .. } => {
                 unimplemented!() // oh the irony
             }
+            WhereClause::MaybeImplemented { .. } |
+            WhereClause::ForAll { .. } => {
+                bail!("this form of where-clause not allowed here")
+            }
         })
     }
 }
 
+/// The attributes that structs, traits and impls may have, each with
+/// the number of arguments it takes.
+const STRUCT_ATTRIBUTES: &'static [(&'static str, usize)] = &[("fundamental", 0), ("upstream", 0)];
+const TRAIT_ATTRIBUTES: &'static [(&'static str, usize)] = &[
+    ("auto", 0),
+    ("coinductive", 0),
+    ("lang", 1),
+    ("marker", 0),
+    ("upstream", 0),
+];
+const IMPL_ATTRIBUTES: &'static [(&'static str, usize)] = &[("upstream", 0)];
+
+/// Checks that each of `attributes` is one of `known`, with the right
+/// number of arguments.
+fn check_attributes(attributes: &[Attribute], known: &[(&str, usize)]) -> Result<()> {
+    for attribute in attributes {
+        match known.iter().find(|&&(name, _)| attribute.name.str == intern(name)) {
+            None => bail!(ErrorKind::UnknownAttribute(attribute.name)),
+            Some(&(_, arity)) if attribute.args.len() != arity => {
+                bail!(ErrorKind::AttributeArity(attribute.name, arity, attribute.args.len()))
+            }
+            Some(_) => (),
+        }
+    }
+    Ok(())
+}
+
 trait LowerStructDefn {
     fn lower_struct(&self,
                     krate_id: ir::KrateId,
@@ -508,6 +1238,8 @@ impl LowerStructDefn for StructDefn {
                     env: &Env)
                     -> Result<ir::StructDatum>
     {
+        check_attributes(&self.attributes, STRUCT_ATTRIBUTES)?;
+
         let binders = env.in_binders(self.all_parameters(), |env| {
             let self_ty = ir::ApplicationTy {
                 name: ir::TypeName::ItemId(item_id),
@@ -519,15 +1251,212 @@ impl LowerStructDefn for StructDefn {
                                 .collect()
             };
 
+            let fields = lower_fields(&self.fields, &format!("struct `{}`", self.name.str), env)?;
             let where_clauses = self.lower_where_clauses(env)?;
 
-            Ok(ir::StructDatumBound { self_ty, where_clauses })
+            let flags = ir::StructFlags {
+                fundamental: self.flags.fundamental,
+            };
+
+            Ok(ir::StructDatumBound { self_ty, fields, where_clauses, flags })
         })?;
 
         Ok(ir::StructDatum { krate_id, binders })
     }
 }
 
+trait LowerEnumDefn {
+    fn lower_enum(&self,
+                  krate_id: ir::KrateId,
+                  item_id: ir::ItemId,
+                  env: &Env)
+                  -> Result<ir::EnumDatum>;
+}
+
+impl LowerEnumDefn for EnumDefn {
+    fn lower_enum(&self,
+                  krate_id: ir::KrateId,
+                  item_id: ir::ItemId,
+                  env: &Env)
+                  -> Result<ir::EnumDatum>
+    {
+        let binders = env.in_binders(self.all_parameters(), |env| {
+            let self_ty = ir::ApplicationTy {
+                name: ir::TypeName::ItemId(item_id),
+                parameters: self.all_parameters()
+                                .anonymize()
+                                .iter()
+                                .zip(0..)
+                                .map(|p| p.to_parameter())
+                                .collect()
+            };
+
+            let mut variants: Vec<ir::Variant> = vec![];
+            for variant in &self.variants {
+                if variants.iter().any(|v| v.name == variant.name.str) {
+                    bail!(ErrorKind::DuplicateVariant(variant.name, self.name));
+                }
+                let description = format!("variant `{}::{}`", self.name.str, variant.name.str);
+                variants.push(ir::Variant {
+                    name: variant.name.str,
+                    fields: lower_fields(&variant.fields, &description, env)?,
+                });
+            }
+            let where_clauses = self.lower_where_clauses(env)?;
+
+            Ok(ir::EnumDatumBound { self_ty, variants, where_clauses })
+        })?;
+
+        Ok(ir::EnumDatum { krate_id, binders })
+    }
+}
+
+/// Lowers the fields of a struct or enum variant (described by
+/// `description`, for errors), checking that their names are distinct.
+fn lower_fields(fields: &[Field], description: &str, env: &Env) -> Result<Vec<ir::Field>> {
+    let mut lowered: Vec<ir::Field> = vec![];
+    for field in fields {
+        if lowered.iter().any(|f| f.name == field.name.str) {
+            bail!(ErrorKind::DuplicateField(field.name, description.to_string()));
+        }
+        lowered.push(ir::Field { name: field.name.str, ty: field.ty.lower(env)? });
+    }
+    Ok(lowered)
+}
+
+trait LowerClosureDefn {
+    fn lower_closure(&self,
+                     krate_id: ir::KrateId,
+                     item_id: ir::ItemId,
+                     env: &Env)
+                     -> Result<ir::ClosureDatum>;
+}
+
+impl LowerClosureDefn for ClosureDefn {
+    fn lower_closure(&self,
+                     krate_id: ir::KrateId,
+                     item_id: ir::ItemId,
+                     env: &Env)
+                     -> Result<ir::ClosureDatum>
+    {
+        let kind = match ir::ClosureKind::from_name(self.kind.str) {
+            Some(kind) => kind,
+            None => bail!(ErrorKind::UnknownClosureKind(self.name, self.kind)),
+        };
+
+        let binders = env.in_binders(self.all_parameters(), |env| {
+            let self_ty = ir::ApplicationTy {
+                name: ir::TypeName::ItemId(item_id),
+                parameters: self.parameter_refs(),
+            };
+
+            let arg_tys: Vec<_> = try!(self.arg_tys.iter().map(|t| t.lower(env)).collect());
+            let ret_ty = self.ret_ty.lower(env)?;
+
+            Ok(ir::ClosureDatumBound { self_ty, kind, arg_tys, ret_ty })
+        })?;
+
+        Ok(ir::ClosureDatum { krate_id, binders })
+    }
+}
+
+trait LowerFnDefn {
+    fn lower_fn_def(&self,
+                    krate_id: ir::KrateId,
+                    item_id: ir::ItemId,
+                    env: &Env)
+                    -> Result<ir::FnDefDatum>;
+}
+
+impl LowerFnDefn for FnDefn {
+    fn lower_fn_def(&self,
+                    krate_id: ir::KrateId,
+                    item_id: ir::ItemId,
+                    env: &Env)
+                    -> Result<ir::FnDefDatum>
+    {
+        let binders = env.in_binders(self.all_parameters(), |env| {
+            let self_ty = ir::ApplicationTy {
+                name: ir::TypeName::ItemId(item_id),
+                parameters: self.parameter_refs(),
+            };
+
+            let arg_tys: Vec<_> = try!(self.arg_tys.iter().map(|t| t.lower(env)).collect());
+            let ret_ty = self.ret_ty.lower(env)?;
+            let where_clauses = self.lower_where_clauses(env)?;
+
+            Ok(ir::FnDefDatumBound { self_ty, arg_tys, ret_ty, where_clauses })
+        })?;
+
+        Ok(ir::FnDefDatum { krate_id, binders })
+    }
+}
+
+trait LowerGeneratorDefn {
+    fn lower_generator(&self,
+                       krate_id: ir::KrateId,
+                       item_id: ir::ItemId,
+                       env: &Env)
+                       -> Result<ir::GeneratorDatum>;
+}
+
+impl LowerGeneratorDefn for GeneratorDefn {
+    fn lower_generator(&self,
+                       krate_id: ir::KrateId,
+                       item_id: ir::ItemId,
+                       env: &Env)
+                       -> Result<ir::GeneratorDatum>
+    {
+        let binders = env.in_binders(self.all_parameters(), |env| {
+            let self_ty = ir::ApplicationTy {
+                name: ir::TypeName::ItemId(item_id),
+                parameters: self.parameter_refs(),
+            };
+
+            let resume_ty = self.resume_ty.lower(env)?;
+            let yield_ty = self.yield_ty.lower(env)?;
+            let return_ty = self.return_ty.lower(env)?;
+
+            Ok(ir::GeneratorDatumBound { self_ty, resume_ty, yield_ty, return_ty })
+        })?;
+
+        Ok(ir::GeneratorDatum { krate_id, binders })
+    }
+}
+
+trait LowerOpaqueTyDefn {
+    fn lower_opaque_ty(&self,
+                       krate_id: ir::KrateId,
+                       item_id: ir::ItemId,
+                       env: &Env)
+                       -> Result<ir::OpaqueTyDatum>;
+}
+
+impl LowerOpaqueTyDefn for OpaqueTyDefn {
+    fn lower_opaque_ty(&self,
+                       krate_id: ir::KrateId,
+                       item_id: ir::ItemId,
+                       env: &Env)
+                       -> Result<ir::OpaqueTyDatum>
+    {
+        let binders = env.in_binders(self.all_parameters(), |env| {
+            let self_ty = ir::ApplicationTy {
+                name: ir::TypeName::ItemId(item_id),
+                parameters: self.parameter_refs(),
+            };
+
+            let bounds = self.bounds
+                             .iter()
+                             .map(|b| b.lower(self_ty.clone().cast(), env))
+                             .collect::<Result<Vec<_>>>()?;
+
+            Ok(ir::OpaqueTyDatumBound { self_ty, bounds })
+        })?;
+
+        Ok(ir::OpaqueTyDatum { krate_id, binders })
+    }
+}
+
 fn check_type_kinds<A: Kinded, B: Kinded>(msg: &str, expected: &A, actual: &B) -> Result<()> {
     let expected_kind = expected.kind();
     let actual_kind = actual.kind();
@@ -544,32 +1473,60 @@ trait LowerTraitRef {
 
 impl LowerTraitRef for TraitRef {
     fn lower(&self, env: &Env) -> Result<ir::TraitRef> {
-        let id = match env.lookup(self.trait_name)? {
-            NameLookup::Type(id) => id,
-            NameLookup::Parameter(_) => bail!(ErrorKind::NotTrait(self.trait_name)),
-        };
+        let parameters = self.args.iter().map(|a| Ok(a.lower(env)?)).collect::<Result<Vec<_>>>()?;
+        lower_trait_ref(self.trait_name, parameters, env).map_err(|e| e.at(self.span))
+    }
+}
+
+trait LowerTraitBound {
+    fn lower(&self, self_ty: ir::Ty, env: &Env) -> Result<ir::TraitRef>;
+}
 
-        let k = env.type_kind(id);
-        if k.sort != ir::TypeSort::Trait {
-            bail!(ErrorKind::NotTrait(self.trait_name));
+impl LowerTraitBound for TraitBound {
+    fn lower(&self, self_ty: ir::Ty, env: &Env) -> Result<ir::TraitRef> {
+        let mut parameters = vec![self_ty.cast()];
+        for arg in &self.args {
+            parameters.push(arg.lower(env)?);
         }
+        lower_trait_ref(self.trait_name, parameters, env)
+    }
+}
 
-        let parameters = self.args.iter().map(|a| Ok(a.lower(env)?)).collect::<Result<Vec<_>>>()?;
+/// Resolves `trait_name` and checks `parameters` (which include the
+/// self type) against the trait's binders.
+fn lower_trait_ref(trait_name: Identifier,
+                   mut parameters: Vec<ir::Parameter>,
+                   env: &Env)
+                   -> Result<ir::TraitRef>
+{
+    let id = match env.lookup(trait_name)? {
+        NameLookup::Type(id) => id,
+        NameLookup::Parameter(_) |
+        NameLookup::Builtin(_) => bail!(ErrorKind::NotTrait(trait_name)),
+    };
+
+    let k = env.type_kind(id);
+    if k.sort != ir::TypeSort::Trait {
+        bail!(ErrorKind::NotTrait(trait_name));
+    }
 
-        if parameters.len() != k.binders.len() + 1 {
-            bail!("wrong number of parameters, expected `{:?}`, got `{:?}`",
-                  k.binders.len() + 1, parameters.len())
-        }
+    let mut args = parameters.split_off(1);
+    env.apply_parameter_defaults(id, &mut args, parameters.first());
+    parameters.extend(args);
 
-        for (binder, param) in k.binders.binders.iter().zip(parameters.iter().skip(1)) {
-            check_type_kinds("incorrect kind for trait parameter", binder, param)?;
-        }
+    if parameters.len() != k.binders.len() + 1 {
+        bail!("wrong number of parameters, expected `{:?}`, got `{:?}`",
+              k.binders.len() + 1, parameters.len())
+    }
 
-        Ok(ir::TraitRef {
-            trait_id: id,
-            parameters: parameters,
-        })
+    for (binder, param) in k.binders.binders.iter().zip(parameters.iter().skip(1)) {
+        check_type_kinds("incorrect kind for trait parameter", binder, param)?;
     }
+
+    Ok(ir::TraitRef {
+        trait_id: id,
+        parameters: parameters,
+    })
 }
 
 trait LowerProjectionTy {
@@ -582,13 +1539,14 @@ impl LowerProjectionTy for ProjectionTy {
         let ir::TraitRef { trait_id, parameters: trait_parameters } = trait_ref.lower(env)?;
         let info = match env.associated_ty_infos.get(&(trait_id, name.str)) {
             Some(info) => info,
-            None => bail!("no associated type `{}` defined in trait", name.str)
+            None => bail!(ErrorKind::NoAssociatedType(*name))
         };
         let mut args: Vec<_> = try!(args.iter().map(|a| a.lower(env)).collect());
 
         if args.len() != info.addl_parameter_kinds.len() {
-            bail!("wrong number of parameters for associated type (expected {}, got {})",
-                  info.addl_parameter_kinds.len(), args.len())
+            bail!(ErrorKind::IncorrectNumberOfAssociatedTyParameters(*name,
+                                                                    info.addl_parameter_kinds.len(),
+                                                                    args.len()))
         }
 
         for (param, arg) in info.addl_parameter_kinds.iter().zip(args.iter()) {
@@ -601,68 +1559,259 @@ impl LowerProjectionTy for ProjectionTy {
     }
 }
 
+trait LowerConstProjection {
+    fn lower(&self, env: &Env) -> Result<ir::ConstProjection>;
+}
+
+impl LowerConstProjection for ConstProjection {
+    fn lower(&self, env: &Env) -> Result<ir::ConstProjection> {
+        let ConstProjection { ref trait_ref, ref name } = *self;
+        let ir::TraitRef { trait_id, parameters } = trait_ref.lower(env)?;
+        let associated_const_id = match env.associated_const_ids.get(&(trait_id, name.str)) {
+            Some(&id) => id,
+            None => bail!(ErrorKind::NoAssociatedConst(*name))
+        };
+        Ok(ir::ConstProjection { associated_const_id, parameters })
+    }
+}
+
 trait LowerTy {
     fn lower(&self, env: &Env) -> Result<ir::Ty>;
 }
 
 impl LowerTy for Ty {
     fn lower(&self, env: &Env) -> Result<ir::Ty> {
-        match *self {
-            Ty::Id { name } => {
-                match env.lookup(name)? {
-                    NameLookup::Type(id) => {
-                        let k = env.type_kind(id);
-                        if k.binders.len() > 0 {
-                            bail!(ErrorKind::IncorrectNumberOfTypeParameters(name,
-                                                                             k.binders.len(),
-                                                                             0))
-                        }
+        lower_ty(self, env).map_err(|e| e.at(self.span()))
+    }
+}
 
-                        Ok(ir::Ty::Apply(ir::ApplicationTy {
-                            name: ir::TypeName::ItemId(id),
-                            parameters: vec![],
-                        }))
+/// Lowers `ty`, leaving it to the caller to point any error at it.
+fn lower_ty(ty: &Ty, env: &Env) -> Result<ir::Ty> {
+    match *ty {
+        Ty::Id { name, .. } => {
+            if let Some(alias) = env.type_alias(name) {
+                return env.expand_type_alias(name, alias, &[]);
+            }
+
+            match env.lookup(name)? {
+                NameLookup::Type(id) => {
+                    let k = env.type_kind(id);
+                    let mut parameters = vec![];
+                    env.apply_parameter_defaults(id, &mut parameters, None);
+                    if k.binders.len() != parameters.len() {
+                        bail!(ErrorKind::IncorrectNumberOfTypeParameters(name,
+                                                                         k.binders.len(),
+                                                                         0))
+                    }
+
+                    Ok(ir::Ty::Apply(ir::ApplicationTy {
+                        name: ir::TypeName::ItemId(id),
+                        parameters: parameters,
+                    }))
+                }
+                NameLookup::Parameter(d) => Ok(ir::Ty::Var(d)),
+                NameLookup::Builtin(builtin) => {
+                    let binders = BuiltinType::binders_of(builtin);
+                    if !binders.is_empty() {
+                        bail!(ErrorKind::IncorrectNumberOfTypeParameters(name,
+                                                                         binders.len(),
+                                                                         0))
                     }
-                    NameLookup::Parameter(d) => Ok(ir::Ty::Var(d)),
+
+                    Ok(ir::Ty::Apply(ir::ApplicationTy {
+                        name: builtin,
+                        parameters: vec![],
+                    }))
                 }
             }
+        }
 
-            Ty::Apply { name, ref args } => {
-                let id = match env.lookup(name)? {
-                    NameLookup::Type(id) => id,
-                    NameLookup::Parameter(_) => bail!(ErrorKind::CannotApplyTypeParameter(name)),
-                };
+        Ty::Apply { name, ref args, .. } => {
+            if let Some(alias) = env.type_alias(name) {
+                return env.expand_type_alias(name, alias, args);
+            }
 
-                let k = env.type_kind(id);
-                if k.binders.len() != args.len() {
-                    bail!(ErrorKind::IncorrectNumberOfTypeParameters(name,
-                                                                     k.binders.len(),
-                                                                     args.len()))
-                }
+            let id = match env.lookup(name)? {
+                NameLookup::Type(id) => id,
+                NameLookup::Parameter(_) => bail!(ErrorKind::CannotApplyTypeParameter(name)),
+                NameLookup::Builtin(builtin) => {
+                    let binders = BuiltinType::binders_of(builtin);
+                    if binders.len() != args.len() {
+                        bail!(ErrorKind::IncorrectNumberOfTypeParameters(name,
+                                                                         binders.len(),
+                                                                         args.len()))
+                    }
 
-                let parameters = args.iter().map(|t| Ok(t.lower(env)?)).collect::<Result<Vec<_>>>()?;
+                    let parameters = args.iter().map(|t| Ok(t.lower(env)?)).collect::<Result<Vec<_>>>()?;
+                    for (param, arg) in binders.iter().zip(parameters.iter()) {
+                        check_type_kinds("incorrect parameter kind", param, arg)?;
+                    }
 
-                for (param, arg) in k.binders.binders.iter().zip(args.iter()) {
-                    check_type_kinds("incorrect parameter kind", param, arg)?;
+                    return Ok(ir::Ty::Apply(ir::ApplicationTy {
+                        name: builtin,
+                        parameters: parameters,
+                    }));
                 }
+            };
+
+            let k = env.type_kind(id);
+            let mut parameters = args.iter().map(|t| Ok(t.lower(env)?)).collect::<Result<Vec<_>>>()?;
+            env.apply_parameter_defaults(id, &mut parameters, None);
+            if k.binders.len() != parameters.len() {
+                bail!(ErrorKind::IncorrectNumberOfTypeParameters(name,
+                                                                 k.binders.len(),
+                                                                 args.len()))
+            }
+
+            for (param, arg) in k.binders.binders.iter().zip(parameters.iter()) {
+                check_type_kinds("incorrect parameter kind", param, arg)?;
+            }
+
+            Ok(ir::Ty::Apply(ir::ApplicationTy {
+                name: ir::TypeName::ItemId(id),
+                parameters: parameters,
+            }))
+        }
+
+        Ty::Projection { ref proj, .. } => Ok(ir::Ty::Projection(proj.lower(env)?)),
+
+        Ty::ForAll { ref lifetime_names, ref ty, .. } => {
+            let quantified_env =
+                env.introduce(lifetime_names
+                              .iter()
+                              .map(|id| ir::ParameterKind::Lifetime(id.str)));
+            let ty = ty.lower(&quantified_env)?;
+            let quantified_ty = ir::QuantifiedTy { num_binders: lifetime_names.len(), ty };
+            Ok(ir::Ty::ForAll(Box::new(quantified_ty)))
+        }
+
+        Ty::Tuple { ref types, .. } => {
+            let parameters = types.iter()
+                                  .map(|t| Ok(t.lower(env)?.cast()))
+                                  .collect::<Result<Vec<_>>>()?;
+            env.builtin_arities.borrow_mut().insert(ir::TypeName::Tuple(types.len()));
+            Ok(ir::Ty::Apply(ir::ApplicationTy {
+                name: ir::TypeName::Tuple(types.len()),
+                parameters: parameters,
+            }))
+        }
+
+        Ty::Ref { mutability, ref lifetime, ref ty, .. } => {
+            Ok(ir::Ty::Apply(ir::ApplicationTy {
+                name: ir::TypeName::Ref(mutability.lower()),
+                parameters: vec![lifetime.lower(env)?.cast(), ty.lower(env)?.cast()],
+            }))
+        }
+
+        Ty::Raw { mutability, ref ty, .. } => {
+            Ok(ir::Ty::Apply(ir::ApplicationTy {
+                name: ir::TypeName::Raw(mutability.lower()),
+                parameters: vec![ty.lower(env)?.cast()],
+            }))
+        }
+
+        Ty::Slice { ref ty, .. } => {
+            Ok(ir::Ty::Apply(ir::ApplicationTy {
+                name: ir::TypeName::Slice,
+                parameters: vec![ty.lower(env)?.cast()],
+            }))
+        }
+
+        Ty::Array { ref ty, ref len, .. } => {
+            Ok(ir::Ty::Apply(ir::ApplicationTy {
+                name: ir::TypeName::Array,
+                parameters: vec![ty.lower(env)?.cast(),
+                                 ir::ParameterKind::Const(len.lower(env)?)],
+            }))
+        }
+
+        Ty::Dyn { name, ref args, ref bindings, .. } => {
+            let id = match env.lookup(name)? {
+                NameLookup::Type(id) => id,
+                NameLookup::Parameter(_) |
+                NameLookup::Builtin(_) => bail!(ErrorKind::NotTrait(name)),
+            };
+
+            let k = env.type_kind(id);
+            if k.sort != ir::TypeSort::Trait {
+                bail!(ErrorKind::NotTrait(name));
+            }
+
+            let mut parameters = args.iter().map(|t| Ok(t.lower(env)?)).collect::<Result<Vec<_>>>()?;
+            env.apply_parameter_defaults(id, &mut parameters, None);
+            if k.binders.len() != parameters.len() {
+                bail!(ErrorKind::IncorrectNumberOfTypeParameters(name,
+                                                                 k.binders.len(),
+                                                                 args.len()))
+            }
 
-                Ok(ir::Ty::Apply(ir::ApplicationTy {
-                    name: ir::TypeName::ItemId(id),
-                    parameters: parameters,
-                }))
+            for (param, arg) in k.binders.binders.iter().zip(parameters.iter()) {
+                check_type_kinds("incorrect parameter kind", param, arg)?;
             }
 
-            Ty::Projection { ref proj } => Ok(ir::Ty::Projection(proj.lower(env)?)),
+            for binding in bindings {
+                if env.associated_ty_infos.get(&(id, binding.name.str)).is_none() {
+                    bail!(ErrorKind::NoAssociatedType(binding.name));
+                }
+            }
 
-            Ty::ForAll { ref lifetime_names, ref ty } => {
-                let quantified_env =
-                    env.introduce(lifetime_names
-                                  .iter()
-                                  .map(|id| ir::ParameterKind::Lifetime(id.str)));
-                let ty = ty.lower(&quantified_env)?;
-                let quantified_ty = ir::QuantifiedTy { num_binders: lifetime_names.len(), ty };
-                Ok(ir::Ty::ForAll(Box::new(quantified_ty)))
+            // The values of the trait's associated types follow
+            // its parameters, as in `Iterator<u32>` for
+            // `dyn Iterator<Item = u32>`.
+            for (associated_ty_name, _) in env.dyn_associated_tys(id) {
+                let mut values = bindings.iter().filter(|b| b.name.str == associated_ty_name);
+                match (values.next(), values.next()) {
+                    (Some(binding), None) => parameters.push(binding.ty.lower(env)?.cast()),
+                    (None, _) => {
+                        bail!(ErrorKind::DynAssociatedTyUnspecified(associated_ty_name, name))
+                    }
+                    (Some(_), Some(_)) => {
+                        bail!(ErrorKind::DynAssociatedTySpecifiedTwice(associated_ty_name, name))
+                    }
+                }
             }
+
+            env.dyn_traits.borrow_mut().push((name, id));
+
+            // A `dyn Trait` type is represented by applying the
+            // trait itself, just as when the trait is named as
+            // a type directly.
+            Ok(ir::Ty::Apply(ir::ApplicationTy {
+                name: ir::TypeName::ItemId(id),
+                parameters: parameters,
+            }))
+        }
+
+        Ty::Fn { ref args, ref ret, .. } => {
+            let mut parameters = args.iter()
+                                     .map(|t| Ok(t.lower(env)?.cast()))
+                                     .collect::<Result<Vec<_>>>()?;
+            parameters.push(ret.lower(env)?.cast());
+            env.builtin_arities.borrow_mut().insert(ir::TypeName::FnPtr(args.len()));
+            Ok(ir::Ty::Apply(ir::ApplicationTy {
+                name: ir::TypeName::FnPtr(args.len()),
+                parameters: parameters,
+            }))
+        }
+
+        Ty::Never { .. } => {
+            Ok(ir::Ty::Apply(ir::ApplicationTy {
+                name: ir::TypeName::Never,
+                parameters: vec![],
+            }))
+        }
+    }
+}
+
+trait LowerMutability {
+    fn lower(self) -> ir::Mutability;
+}
+
+impl LowerMutability for Mutability {
+    fn lower(self) -> ir::Mutability {
+        match self {
+            Mutability::Not => ir::Mutability::Not,
+            Mutability::Mut => ir::Mutability::Mut,
         }
     }
 }
@@ -673,16 +1822,40 @@ trait LowerParameter {
 
 impl LowerParameter for Parameter {
     fn lower(&self, env: &Env) -> Result<ir::Parameter> {
-        match *self {
-            Parameter::Ty(ref t) => Ok(ir::ParameterKind::Ty(t.lower(env)?)),
-            Parameter::Lifetime(ref l) => Ok(ir::ParameterKind::Lifetime(l.lower(env)?)),
-            Parameter::Krate(ref c) => Ok(ir::ParameterKind::Krate(c.lower(env)?)),
-        }
+        let parameter = match *self {
+            // The parser cannot tell a named const like `N` from a
+            // type, so check for a const parameter of that name first.
+            Parameter::Ty(Ty::Id { name, .. }) if env.lookup_const(name).is_ok() => {
+                Const::Id { name }.lower(env).map(ir::ParameterKind::Const)
+            }
+            Parameter::Ty(ref t) => t.lower(env).map(ir::ParameterKind::Ty),
+            Parameter::Lifetime(ref l) => l.lower(env).map(ir::ParameterKind::Lifetime),
+            Parameter::Krate(ref c) => c.lower(env).map(ir::ParameterKind::Krate),
+            Parameter::Const(ref n) => n.lower(env).map(ir::ParameterKind::Const),
+        };
+        parameter.map_err(|e| e.at(self.span()))
     }
 }
 
-trait LowerLifetime {
-    fn lower(&self, env: &Env) -> Result<ir::Lifetime>;
+trait LowerConst {
+    fn lower(&self, env: &Env) -> Result<ir::Const>;
+}
+
+impl LowerConst for Const {
+    fn lower(&self, env: &Env) -> Result<ir::Const> {
+        match *self {
+            Const::Id { name } => {
+                match env.lookup_const(name)? {
+                    ConstLookup::Parameter(d) => Ok(ir::Const::Var(d))
+                }
+            }
+            Const::Value { value, .. } => Ok(ir::Const::Value(value)),
+        }
+    }
+}
+
+trait LowerLifetime {
+    fn lower(&self, env: &Env) -> Result<ir::Lifetime>;
 }
 
 impl LowerLifetime for Lifetime {
@@ -693,6 +1866,7 @@ impl LowerLifetime for Lifetime {
                     LifetimeLookup::Parameter(d) => Ok(ir::Lifetime::Var(d))
                 }
             }
+            Lifetime::Static { .. } => Ok(ir::Lifetime::Static),
         }
     }
 }
@@ -720,37 +1894,100 @@ trait LowerImpl {
 
 impl LowerImpl for Impl {
     fn lower_impl(&self, krate_id: ir::KrateId, empty_env: &Env) -> Result<ir::ImplDatum> {
+        check_attributes(&self.attributes, IMPL_ATTRIBUTES)?;
+        if self.polarity == Polarity::Negative && !self.assoc_ty_values.is_empty() {
+            bail!(ErrorKind::NegativeImplWithValues(self.span));
+        }
+
         let binders = empty_env.in_binders(self.all_parameters(), |env| {
             let trait_ref = self.trait_ref.lower(env)?;
             let trait_id = trait_ref.trait_id;
+
+            // An opaque type implements its declared bounds and
+            // nothing else.
+            if let ir::ParameterKind::Ty(ir::Ty::Apply(ref apply)) = trait_ref.parameters[0] {
+                if let ir::TypeName::ItemId(id) = apply.name {
+                    let k = env.type_kind(id);
+                    if k.sort == ir::TypeSort::Opaque {
+                        bail!(ErrorKind::ImplForOpaqueTy(self.trait_ref.trait_name, k.name));
+                    }
+                }
+            }
+
+            let trait_ref = match self.polarity {
+                Polarity::Positive => ir::PolarizedTraitRef::Positive(trait_ref),
+                Polarity::Negative => ir::PolarizedTraitRef::Negative(trait_ref),
+            };
             let where_clauses = self.lower_where_clauses(&env)?;
-            let associated_ty_values = try!(self.assoc_ty_values.iter()
-                                            .map(|v| v.lower(trait_id, env))
-                                            .collect());
-            Ok(ir::ImplDatumBound { trait_ref, where_clauses, associated_ty_values })
+            let mut associated_ty_values: Vec<ir::AssociatedTyValue> =
+                try!(self.assoc_ty_values.iter()
+                                         .map(|v| v.lower(trait_id, env))
+                                         .collect());
+            if self.default {
+                for value in &mut associated_ty_values {
+                    value.default = true;
+                }
+            }
+            let associated_const_values = try!(self.assoc_const_values.iter()
+                                               .map(|v| v.lower(trait_id, env))
+                                               .collect());
+            Ok(ir::ImplDatumBound {
+                trait_ref,
+                where_clauses,
+                associated_ty_values,
+                associated_const_values,
+            })
         })?;
 
         Ok(ir::ImplDatum {
             krate_id: krate_id,
             binders: binders,
+            default: self.default,
+            is_unsafe: self.is_unsafe,
+            specializes: vec![],
         })
     }
 }
 
+trait LowerAssocConstValue {
+    fn lower(&self, trait_id: ir::ItemId, env: &Env) -> Result<ir::AssociatedConstValue>;
+}
+
+impl LowerAssocConstValue for AssocConstValue {
+    fn lower(&self, trait_id: ir::ItemId, env: &Env) -> Result<ir::AssociatedConstValue> {
+        let associated_const_id = match env.associated_const_ids.get(&(trait_id, self.name.str)) {
+            Some(&id) => id,
+            None => bail!(ErrorKind::NoAssociatedConst(self.name))
+        };
+        let value = self.value.lower(env).map_err(|e| e.at(self.span))?;
+        Ok(ir::AssociatedConstValue { associated_const_id, value })
+    }
+}
+
 trait LowerAssocTyValue {
     fn lower(&self, trait_id: ir::ItemId, env: &Env) -> Result<ir::AssociatedTyValue>;
 }
 
 impl LowerAssocTyValue for AssocTyValue {
     fn lower(&self, trait_id: ir::ItemId, env: &Env) -> Result<ir::AssociatedTyValue> {
-        let info = &env.associated_ty_infos[&(trait_id, self.name.str)];
+        let info = match env.associated_ty_infos.get(&(trait_id, self.name.str)) {
+            Some(info) => info,
+            None => bail!(ErrorKind::NoAssociatedType(self.name))
+        };
+
+        // The impl must declare the same parameters as the trait, as
+        // in `type Iter<'a> = Iter<'a, T>` for `type Iter<'a>;`.
+        if self.all_parameters().anonymize() != info.addl_parameter_kinds.anonymize() {
+            bail!(ErrorKind::AssociatedTyParametersMismatch(self.name))
+        }
+
         let value = env.in_binders(self.all_parameters(), |env| {
             Ok(ir::AssociatedTyValueBound {
                 ty: self.value.lower(env)?,
                 where_clauses: self.where_clauses.lower(env)?,
             })
-        })?;
-        Ok(ir::AssociatedTyValue { associated_ty_id: info.id, value: value })
+        }).map_err(|e| e.at(self.span))?;
+        Ok(ir::AssociatedTyValue { associated_ty_id: info.id, value: value, default: self.default })
     }
 }
 
@@ -766,14 +2003,59 @@ impl LowerTrait for TraitDefn {
                    trait_id: ir::ItemId,
                    env: &Env)
                    -> Result<ir::TraitDatum> {
+        check_attributes(&self.attributes, TRAIT_ATTRIBUTES)?;
+
+        if self.flags.auto {
+            if !self.parameter_kinds.is_empty() {
+                bail!(ErrorKind::InvalidAutoTrait(self.name, "have parameters"));
+            }
+            if !self.supertraits.is_empty() {
+                bail!(ErrorKind::InvalidAutoTrait(self.name, "have supertraits"));
+            }
+            if !self.where_clauses.is_empty() {
+                bail!(ErrorKind::InvalidAutoTrait(self.name, "have where clauses"));
+            }
+            if !self.assoc_ty_defns.is_empty() {
+                bail!(ErrorKind::InvalidAutoTrait(self.name, "define associated types"));
+            }
+        }
+
+        if self.flags.marker {
+            if !self.assoc_ty_defns.is_empty() || !self.assoc_const_defns.is_empty() {
+                bail!(ErrorKind::MarkerTraitWithItems(self.name));
+            }
+        }
+
         let binders = env.in_binders(self.all_parameters(), |env| {
+            // `Self` is the last binder, but comes first in the trait-ref.
+            let mut parameters = self.parameter_refs();
+            let self_parameter = parameters.pop().unwrap();
+            parameters.insert(0, self_parameter);
             let trait_ref = ir::TraitRef {
                 trait_id: trait_id,
-                parameters: self.parameter_refs()
+                parameters: parameters,
             };
+
+            // Supertraits become where clauses on `Self`, so that
+            // `trait Ord: Eq` is the same as `trait Ord where Self: Eq`.
+            let self_ty = ir::Ty::Var(env.parameter_map[&ir::ParameterKind::Ty(intern(SELF))]);
+            let mut where_clauses = vec![];
+            for bound in &self.supertraits {
+                where_clauses.push(ir::WhereClause::Implemented(bound.lower(self_ty.clone(), env)?));
+            }
+            where_clauses.extend(self.lower_where_clauses(env)?);
+
             Ok(ir::TraitDatumBound {
                 trait_ref: trait_ref,
-                where_clauses: self.lower_where_clauses(env)?,
+                where_clauses: where_clauses,
+                flags: ir::TraitFlags {
+                    auto: self.flags.auto,
+                    alias: self.flags.alias,
+                    marker: self.flags.marker,
+                    sized: self.lang.map_or(false, |lang| lang.str == intern("sized")),
+                    is_unsafe: self.flags.is_unsafe,
+                    coinductive: self.flags.coinductive,
+                },
             })
         })?;
 
@@ -790,29 +2072,129 @@ pub trait LowerGoal<A> {
 
 impl LowerGoal<ir::Program> for Goal {
     fn lower(&self, program: &ir::Program) -> Result<Box<ir::Goal>> {
-        let associated_ty_infos: HashMap<_, _> =
-            program.associated_ty_data
-                   .iter()
-                   .map(|(&associated_ty_id, datum)| {
-                       let trait_datum = &program.trait_data[&datum.trait_id];
-                       let num_trait_params = trait_datum.binders.len();
-                       let num_addl_params = datum.parameter_kinds.len() - num_trait_params;
-                       let addl_parameter_kinds = datum.parameter_kinds[..num_addl_params].to_owned();
-                       let info = AssociatedTyInfo { id: associated_ty_id, addl_parameter_kinds };
-                       ((datum.trait_id, datum.name), info)
-                   })
-                   .collect();
+        lower_in_program(program, |env| self.lower(env))
+    }
+}
+
+/// Lowers something against an already-lowered `program`, by way of
+/// `op`, in the default crate.
+fn lower_in_program<T, F>(program: &ir::Program, op: F) -> Result<T>
+    where F: FnOnce(&Env) -> Result<T>
+{
+    let associated_ty_infos: HashMap<_, _> =
+        program.associated_ty_data
+               .iter()
+               .map(|(&associated_ty_id, datum)| {
+                   let trait_datum = &program.trait_data[&datum.trait_id];
+                   let num_trait_params = trait_datum.binders.len();
+                   let num_addl_params = datum.parameter_kinds.len() - num_trait_params;
+                   let addl_parameter_kinds = datum.parameter_kinds[..num_addl_params].to_owned();
+                   let info = AssociatedTyInfo { id: associated_ty_id, addl_parameter_kinds };
+                   ((datum.trait_id, datum.name), info)
+               })
+               .collect();
+
+    let associated_const_ids: HashMap<_, _> =
+        program.associated_const_data
+               .iter()
+               .map(|(&associated_const_id, datum)| ((datum.trait_id, datum.name), associated_const_id))
+               .collect();
+
+    let default_krate_id = ir::KrateId { name: intern(DEFAULT_KRATE_NAME) };
+    let dyn_traits = RefCell::new(vec![]);
+    let builtin_arities = RefCell::new(BTreeSet::new());
+    let env = Env {
+        krate: ir::Krate::Id(default_krate_id),
+        type_ids: &program.type_ids,
+        type_kinds: &program.type_kinds,
+        associated_ty_infos: &associated_ty_infos,
+        associated_const_ids: &associated_const_ids,
+        parameter_map: HashMap::new(),
+        dyn_traits: &dyn_traits,
+        builtin_arities: &builtin_arities,
+        sized_trait_id: program.sized_trait_id(),
+        type_aliases: &program.type_aliases,
+        parameter_defaults: &program.parameter_defaults,
+    };
+
+    let value = op(&env)?;
+    check_object_safety(program, &dyn_traits)?;
+    Ok(value)
+}
 
+impl ir::Program {
+    /// Lowers `hypotheses` against the program, for use with
+    /// `Goal::assuming`.
+    pub fn lower_hypotheses(&self, hypotheses: &[WhereClause]) -> Result<Vec<ir::WhereClause>> {
+        lower_in_program(self, |env| hypotheses.lower(env))
+    }
+
+    /// Lowers `impl_` and adds it to the program, in the default crate,
+    /// under a fresh id. Unlike lowering the edited program afresh,
+    /// this leaves the ids of the other items as they were, so that a
+    /// solver can keep what it learned about them (see
+    /// `Solver::set_program`).
+    pub fn add_impl(&mut self, impl_: &Impl) -> Result<ir::ItemId> {
         let default_krate_id = ir::KrateId { name: intern(DEFAULT_KRATE_NAME) };
-        let env = Env {
-            krate: ir::Krate::Id(default_krate_id),
-            type_ids: &program.type_ids,
-            type_kinds: &program.type_kinds,
-            associated_ty_infos: &associated_ty_infos,
-            parameter_map: HashMap::new()
+        let (impl_datum, arities) = lower_in_program(self, |env| {
+            let impl_datum = impl_.lower_impl(default_krate_id, env)?;
+            Ok((impl_datum, env.builtin_arities.borrow().clone()))
+        })?;
+
+        let impl_id = {
+            let ids = self.type_kinds.keys()
+                                     .chain(self.impl_data.keys())
+                                     .chain(self.associated_ty_data.keys())
+                                     .chain(self.associated_const_data.keys());
+            ir::ItemId { index: ids.map(|id| id.index + 1).max().unwrap_or(0) }
+        };
+        self.impl_data.insert(impl_id, impl_datum);
+        self.impl_spans.insert(impl_id, impl_.span);
+        let new_arities: Vec<_> = arities.into_iter()
+                                         .filter(|&name| self.builtin_arities.insert(name))
+                                         .collect();
+
+        if let Err(error) = self.check_impls() {
+            self.impl_data.remove(&impl_id);
+            self.impl_spans.remove(&impl_id);
+            for name in &new_arities {
+                self.builtin_arities.remove(name);
+            }
+            self.check_impls().expect("impls were fine before");
+            return Err(error);
+        }
+        Ok(impl_id)
+    }
+
+    /// Removes the impl with the given id from the program, leaving the
+    /// ids of the other items as they were.
+    pub fn remove_impl(&mut self, impl_id: ir::ItemId) -> Result<ir::ImplDatum> {
+        let impl_datum = match self.impl_data.remove(&impl_id) {
+            Some(impl_datum) => impl_datum,
+            None => bail!("no impl with id `{:?}`", impl_id),
         };
+        let span = self.impl_spans.remove(&impl_id);
 
-        self.lower(&env)
+        if let Err(error) = self.check_impls() {
+            self.impl_data.insert(impl_id, impl_datum);
+            if let Some(span) = span {
+                self.impl_spans.insert(impl_id, span);
+            }
+            self.check_impls().expect("impls were fine before");
+            return Err(error);
+        }
+        Ok(impl_datum)
+    }
+
+    /// Repeats the checks that lowering makes of the impls, once they
+    /// have changed.
+    fn check_impls(&mut self) -> Result<()> {
+        for impl_datum in self.impl_data.values_mut() {
+            impl_datum.specializes.clear();
+        }
+        check_trait_aliases(self)?;
+        check_coherence(self)?;
+        check_wf(self)
     }
 }
 
@@ -827,8 +2209,18 @@ impl<'k> LowerGoal<Env<'k>> for Goal {
                 Ok(Box::new(ir::Goal::Implies(wc.lower(env)?, g.lower(env)?))),
             Goal::And(ref g1, ref g2) =>
                 Ok(Box::new(ir::Goal::And(g1.lower(env)?, g2.lower(env)?))),
-            Goal::Leaf(ref wc) =>
-                Ok(Box::new(ir::Goal::Leaf(wc.lower(env)?))),
+            Goal::Not(ref g) =>
+                Ok(Box::new(ir::Goal::Not(g.lower(env)?))),
+            Goal::Leaf(ref wc) => {
+                let goal = match wc.value {
+                    WhereClause::ForAll { .. } => {
+                        let where_clause: Result<ir::WhereClause> = wc.value.lower(env);
+                        where_clause.map(|wc| wc.cast())
+                    }
+                    _ => wc.value.lower(env).map(ir::Goal::Leaf),
+                };
+                Ok(Box::new(goal.map_err(|e| e.at(wc.span))?))
+            }
             Goal::Krate(ref k, ref g) => {
                 let k = k.lower(env)?;
                 let krate_env = env.in_krate(k);
@@ -872,22 +2264,329 @@ impl ir::Program {
         let mut program_clauses = vec![];
 
         program_clauses.extend(self.struct_data.values().flat_map(|d| d.to_program_clauses()));
+        program_clauses.extend(self.enum_data.values().flat_map(|d| d.to_program_clauses()));
+        program_clauses.extend(ir::ScalarType::all().iter().map(|s| s.to_wf_clause()));
+        program_clauses.extend(BuiltinType::all().iter().map(|b| b.to_wf_clause()));
+        program_clauses.extend(self.arity_builtins().iter().map(|b| b.to_wf_clause()));
         program_clauses.extend(self.trait_data.values().flat_map(|d| d.to_program_clauses()));
         program_clauses.extend(self.associated_ty_data.iter().flat_map(|(&id, d)| {
             d.to_program_clauses(id)
         }));
 
-        for datum in self.impl_data.values() {
-            program_clauses.push(datum.to_program_clause());
-            program_clauses.extend(datum.binders.value.associated_ty_values.iter().flat_map(|atv| {
-                atv.to_program_clauses(datum)
+        program_clauses.extend(self.closure_data.values().flat_map(|d| d.to_program_clauses(self)));
+        program_clauses.extend(self.fn_def_data.values().flat_map(|d| d.to_program_clauses(self)));
+        program_clauses.extend(self.generator_data.values().flat_map(|d| d.to_program_clauses(self)));
+        program_clauses.extend(self.extern_ty_data.values().flat_map(|d| d.to_program_clauses()));
+        program_clauses.extend(self.opaque_ty_data.values().flat_map(|d| d.to_program_clauses()));
+
+        // The values of each impl's associated types, including those
+        // it takes from the trait's defaults.
+        let associated_ty_values: HashMap<_, _> = self.impl_data.iter().map(|(&impl_id, datum)| {
+            let mut values = datum.binders.value.associated_ty_values.clone();
+            values.extend(self.default_associated_ty_values(datum));
+            (impl_id, values)
+        }).collect();
+
+        let mut negative_impl_clauses = vec![];
+        let mut default_program_clauses = vec![];
+        for (&impl_id, datum) in &self.impl_data {
+            if datum.binders.value.trait_ref.is_positive() {
+                program_clauses.push(datum.to_program_clause());
+                for atv in &associated_ty_values[&impl_id] {
+                    let clauses = self.associated_ty_value_clauses(atv, datum);
+                    let specializations =
+                        self.overriding_normalizations(impl_id, atv, &associated_ty_values);
+                    if specializations.is_empty() {
+                        program_clauses.extend(clauses);
+                    } else {
+                        default_program_clauses.extend(clauses.into_iter().map(|clause| {
+                            ir::DefaultProgramClause {
+                                clause: clause,
+                                specializations: specializations.clone(),
+                            }
+                        }));
+                    }
+                }
+                program_clauses.extend(datum.binders.value.associated_const_values.iter().map(|acv| {
+                    acv.to_program_clause(datum)
+                }));
+            } else {
+                negative_impl_clauses.push(datum.to_program_clause());
+            }
+        }
+
+        // Auto traits are implemented structurally for every struct,
+        // scalar, fn item and built-in type that doesn't have an
+        // explicit impl of its own.
+        for (&trait_id, trait_datum) in &self.trait_data {
+            if !trait_datum.binders.value.flags.auto {
+                continue;
+            }
+
+            for (&struct_id, struct_datum) in &self.struct_data {
+                if !self.has_explicit_impl(trait_id, ir::TypeName::ItemId(struct_id)) {
+                    program_clauses.push(struct_datum.to_auto_trait_clause(trait_id));
+                }
+            }
+
+            for (&enum_id, enum_datum) in &self.enum_data {
+                if !self.has_explicit_impl(trait_id, ir::TypeName::ItemId(enum_id)) {
+                    program_clauses.push(enum_datum.to_auto_trait_clause(trait_id));
+                }
+            }
+
+            for (&fn_def_id, fn_def_datum) in &self.fn_def_data {
+                if !self.has_explicit_impl(trait_id, ir::TypeName::ItemId(fn_def_id)) {
+                    program_clauses.push(fn_def_datum.to_auto_trait_clause(trait_id));
+                }
+            }
+
+            for &scalar in ir::ScalarType::all() {
+                if !self.has_explicit_impl(trait_id, ir::TypeName::Scalar(scalar)) {
+                    program_clauses.push(scalar.to_auto_trait_clause(trait_id));
+                }
+            }
+
+            for builtin in BuiltinType::all().into_iter().chain(self.arity_builtins()) {
+                if !self.has_explicit_impl(trait_id, builtin.name) {
+                    program_clauses.push(builtin.to_auto_trait_clause(trait_id));
+                }
+            }
+        }
+
+        // `Sized` is built in: scalars, references, raw pointers,
+        // arrays, `PhantomData`, fn items, fn pointers, closures,
+        // generators and enums are always sized, structs are sized if
+        // their last field is, tuples if all their elements are, and
+        // slices, `str`, extern types and `dyn Trait` types never are.
+        if let Some(sized_trait_id) = self.sized_trait_id() {
+            program_clauses.extend(self.struct_data.values().map(|d| d.to_sized_clause(sized_trait_id)));
+            program_clauses.extend(self.enum_data.values().map(|d| d.to_sized_clause(sized_trait_id)));
+            program_clauses.extend(self.fn_def_data.values().map(|d| {
+                d.to_auto_trait_clause(sized_trait_id)
+            }));
+            program_clauses.extend(self.closure_data.values().map(|d| d.to_sized_clause(sized_trait_id)));
+            program_clauses.extend(self.generator_data.values().map(|d| d.to_sized_clause(sized_trait_id)));
+            program_clauses.extend(ir::ScalarType::all().iter().map(|s| {
+                s.to_auto_trait_clause(sized_trait_id)
             }));
+            let builtins = BuiltinType::all().into_iter().chain(self.arity_builtins());
+            program_clauses.extend(builtins.filter_map(|b| b.to_sized_clause(sized_trait_id)));
         }
 
+        // So is `Unsize`, for arrays, struct tails and `dyn Trait`.
+        program_clauses.extend(BuiltinType::all().iter().filter_map(|b| b.to_unsize_clause()));
+        program_clauses.extend(self.struct_data.values().flat_map(|d| d.to_unsize_clauses()));
+        // Projections out of a `dyn Trait` type resolve to the values
+        // it carries for the trait's associated types.
+        for (&trait_id, trait_datum) in &self.trait_data {
+            if self.is_object_safe(trait_id) {
+                let associated_ty_ids = self.dyn_associated_ty_ids(trait_id);
+                program_clauses.push(trait_datum.to_unsize_clause(&associated_ty_ids,
+                                                                  self.sized_trait_id()));
+                program_clauses.extend(trait_datum.to_dyn_normalize_clauses(&associated_ty_ids));
+            }
+        }
+
+        let struct_data = self.struct_data.clone();
+        let enum_data = self.enum_data.clone();
         let trait_data = self.trait_data.clone();
         let associated_ty_data = self.associated_ty_data.clone();
+        let associated_const_data = self.associated_const_data.clone();
+        let variances = self.variances();
+        let trait_clause_index = ir::ProgramEnvironment::index_trait_clauses(&program_clauses);
+
+        ir::ProgramEnvironment {
+            struct_data,
+            enum_data,
+            trait_data,
+            associated_ty_data,
+            associated_const_data,
+            variances,
+            program_clauses,
+            negative_impl_clauses,
+            default_program_clauses,
+            trait_clause_index,
+        }
+    }
+
+    /// The normalizations that override `atv`, a value from the impl
+    /// `impl_id`, in the impls that specialize it. These are empty
+    /// unless `atv` is a `default type`.
+    fn overriding_normalizations(&self,
+                                 impl_id: ir::ItemId,
+                                 atv: &ir::AssociatedTyValue,
+                                 associated_ty_values: &HashMap<ir::ItemId, Vec<ir::AssociatedTyValue>>)
+                                 -> Vec<ir::Binders<ir::Normalize>> {
+        if !atv.default {
+            return vec![];
+        }
+
+        let mut normalizations = vec![];
+        for (id, datum) in &self.impl_data {
+            if !datum.specializes.contains(&impl_id) {
+                continue;
+            }
+            for value in &associated_ty_values[id] {
+                if value.associated_ty_id == atv.associated_ty_id {
+                    normalizations.push(value.normalization_pattern(datum));
+                }
+            }
+        }
+        normalizations
+    }
+
+    /// Values for the associated types that `impl_datum` omits but
+    /// whose declarations in the trait have a default. Given:
+    ///
+    /// ```notrust
+    /// trait Iterable { type Iter<'a> = Iter<'a, Self>; }
+    /// impl Iterable for Foo { }
+    /// ```
+    ///
+    /// the impl gets the value `type Iter<'a> = Iter<'a, Foo>`.
+    fn default_associated_ty_values(&self, impl_datum: &ir::ImplDatum) -> Vec<ir::AssociatedTyValue> {
+        let bound = &impl_datum.binders.value;
+        let trait_ref = bound.trait_ref.trait_ref();
+
+        let mut values = vec![];
+        for (&associated_ty_id, datum) in &self.associated_ty_data {
+            let default = match datum.default {
+                Some(ref default) if datum.trait_id == trait_ref.trait_id => default,
+                _ => continue,
+            };
+
+            if bound.associated_ty_values.iter().any(|v| v.associated_ty_id == associated_ty_id) {
+                continue;
+            }
+
+            // An impl that specializes another inherits its values,
+            // rather than taking the trait's default.
+            let inherited = impl_datum.specializes.iter().any(|id| {
+                self.impl_data[id].binders.value.associated_ty_values.iter().any(|v| {
+                    v.associated_ty_id == associated_ty_id
+                })
+            });
+            if inherited {
+                continue;
+            }
+
+            let num_addl = datum.parameter_kinds.len() - trait_ref.parameters.len();
+            let binders: Vec<_> = datum.parameter_kinds[..num_addl].to_vec().anonymize();
+            let parameters = associated_ty_parameters(&binders, trait_ref);
+
+            values.push(ir::AssociatedTyValue {
+                associated_ty_id: associated_ty_id,
+                value: ir::Binders {
+                    binders: binders,
+                    value: ir::AssociatedTyValueBound {
+                        ty: default.subst(&parameters),
+                        where_clauses: vec![],
+                    },
+                },
+                default: true,
+            });
+        }
+        values
+    }
+
+    fn associated_ty_value_clauses(&self,
+                                   atv: &ir::AssociatedTyValue,
+                                   impl_datum: &ir::ImplDatum)
+                                   -> Vec<ir::ProgramClause> {
+        atv.to_program_clauses(impl_datum, &self.associated_ty_data[&atv.associated_ty_id])
+    }
+
+    /// The trait declared as the given lang item, if any.
+    fn lang_item_id(&self, lang_item: ir::LangItem) -> Option<ir::ItemId> {
+        self.lang_items.get(&lang_item).cloned()
+    }
+
+    /// The call trait (`Fn`, `FnMut` or `FnOnce`) for the given
+    /// closure kind, if the program declares one.
+    fn call_trait_id(&self, kind: ir::ClosureKind) -> Option<ir::ItemId> {
+        self.lang_item_id(kind.lang_item())
+    }
+
+    /// The call-trait goals that hold for `self_ty`, a callable of
+    /// the given kind with the given signature: one per call trait
+    /// the kind implements, plus the `FnOnce::Output` normalization.
+    /// Call traits that the program does not declare are skipped.
+    fn call_trait_goals(&self,
+                        kind: ir::ClosureKind,
+                        self_ty: ir::Ty,
+                        arg_tys: &[ir::Ty],
+                        ret_ty: &ir::Ty)
+                        -> Vec<ir::WhereClauseGoal> {
+        let args_ty = ir::Ty::Apply(ir::ApplicationTy {
+            name: ir::TypeName::Tuple(arg_tys.len()),
+            parameters: arg_tys.iter().cloned().map(|ty| ty.cast()).collect(),
+        });
+
+        let mut goals = vec![];
+        for &kind in kind.implemented_kinds() {
+            let trait_id = match self.call_trait_id(kind) {
+                Some(trait_id) => trait_id,
+                None => continue,
+            };
+
+            let trait_ref = ir::TraitRef {
+                trait_id: trait_id,
+                parameters: vec![self_ty.clone().cast(), args_ty.clone().cast()],
+            };
+            goals.push(trait_ref.clone().cast());
+
+            if kind == ir::ClosureKind::FnOnce {
+                if let Some(associated_ty_id) = self.associated_ty_id(trait_id, "Output") {
+                    goals.push(ir::Normalize {
+                        projection: ir::ProjectionTy {
+                            associated_ty_id: associated_ty_id,
+                            parameters: trait_ref.parameters,
+                        },
+                        ty: ret_ty.clone(),
+                    }.cast());
+                }
+            }
+        }
+        goals
+    }
 
-        ir::ProgramEnvironment { trait_data, associated_ty_data, program_clauses }
+    /// The associated type `name` of the trait `trait_id`, if any.
+    fn associated_ty_id(&self, trait_id: ir::ItemId, name: &str) -> Option<ir::ItemId> {
+        self.associated_ty_data.iter()
+                               .find(|&(_, d)| d.trait_id == trait_id && d.name == intern(name))
+                               .map(|(&id, _)| id)
+    }
+
+    /// The trait marked `#[lang(sized)]`, if any.
+    fn sized_trait_id(&self) -> Option<ir::ItemId> {
+        self.lang_item_id(ir::LangItem::Sized)
+    }
+
+    /// True if some impl of the trait `trait_id` (positive or
+    /// negative) has a self type that is an application of the type
+    /// `name`, or a type parameter, as in `impl<T> Send for T`, which
+    /// covers `name` along with every other type.
+    /// The tuple and fn pointer types that get built-in clauses: those
+    /// of up to `BUILTIN_ARITY` elements or arguments, which goals may
+    /// use even if the program does not, and any longer ones the
+    /// program uses.
+    fn arity_builtins(&self) -> Vec<BuiltinType> {
+        let mut names: BTreeSet<_> = (0..BUILTIN_ARITY + 1).map(ir::TypeName::Tuple).collect();
+        names.extend((0..BUILTIN_ARITY + 1).map(ir::TypeName::FnPtr));
+        names.extend(self.builtin_arities.iter().cloned());
+        names.into_iter().map(BuiltinType::of_arity).collect()
+    }
+
+    fn has_explicit_impl(&self, trait_id: ir::ItemId, name: ir::TypeName) -> bool {
+        self.impl_data.values().any(|impl_datum| {
+            let trait_ref = impl_datum.binders.value.trait_ref.trait_ref();
+            trait_ref.trait_id == trait_id && match trait_ref.parameters[0] {
+                ir::ParameterKind::Ty(ir::Ty::Apply(ref apply)) => apply.name == name,
+                ir::ParameterKind::Ty(ir::Ty::Var(_)) => true,
+                _ => false,
+            }
+        })
     }
 }
 
@@ -897,11 +2596,15 @@ impl ir::ImplDatum {
     /// ```notrust
     /// forall<T> { (Vec<T>: Clone) :- (T: Clone) }
     /// ```
+    ///
+    /// For a negative impl like `impl<T> !Send for Rc<T>`, the clause
+    /// has the same shape (`forall<T> { (Rc<T>: Send) }`), but it is
+    /// used to rule out the goal rather than to prove it.
     fn to_program_clause(&self) -> ir::ProgramClause {
         ir::ProgramClause {
             implication: self.binders.map_ref(|bound| {
                 ir::ProgramClauseImplication {
-                    consequence: bound.trait_ref.clone().cast(),
+                    consequence: bound.trait_ref.trait_ref().clone().cast(),
                     conditions: bound.where_clauses.clone().cast(),
                 }
             })
@@ -909,10 +2612,49 @@ impl ir::ImplDatum {
     }
 }
 
+impl ir::AssociatedConstValue {
+    /// Given:
+    ///
+    /// ```notrust
+    /// impl<const N> Len for Array<N> {
+    ///     const LEN = N;
+    /// }
+    /// ```
+    ///
+    /// generate:
+    ///
+    /// ```notrust
+    /// forall<const N> {
+    ///     (Array<N>: Len<const LEN = N>) :- (Array<N>: Len)
+    /// }
+    /// ```
+    fn to_program_clause(&self, impl_datum: &ir::ImplDatum) -> ir::ProgramClause {
+        ir::ProgramClause {
+            implication: impl_datum.binders.map_ref(|bound| {
+                let trait_ref = bound.trait_ref.trait_ref();
+                ir::ProgramClauseImplication {
+                    consequence: ir::NormalizeConst {
+                        projection: ir::ConstProjection {
+                            associated_const_id: self.associated_const_id,
+                            parameters: trait_ref.parameters.clone(),
+                        },
+                        value: self.value,
+                    }.cast(),
+                    conditions: vec![trait_ref.clone().cast()],
+                }
+            })
+        }
+    }
+}
+
 impl ir::AssociatedTyValue {
     /// Given:
     ///
     /// ```notrust
+    /// trait Iterable {
+    ///     type IntoIter<'a> where Self: 'a;
+    /// }
+    ///
     /// impl<T> Iterable for Vec<T> {
     ///     type IntoIter<'a> where T: 'a = Iter<'a, T>;
     /// }
@@ -924,10 +2666,14 @@ impl ir::AssociatedTyValue {
     /// forall<'a, T> {
     ///     (Vec<T>: Iterable<IntoIter<'a> = Iter<'a, T>>) :-
     ///         (Vec<T>: Iterable),  // (1)
+    ///         (Vec<T>: 'a),        // (1)
     ///         (T: 'a)              // (2)
     /// }
     /// ```
-    fn to_program_clauses(&self, impl_datum: &ir::ImplDatum) -> Vec<ir::ProgramClause> {
+    fn to_program_clauses(&self,
+                          impl_datum: &ir::ImplDatum,
+                          associated_ty_datum: &ir::AssociatedTyDatum)
+                          -> Vec<ir::ProgramClause> {
         // Begin with the innermost parameters (`'a`) and then add those from impl (`T`).
         let all_binders: Vec<_> =
             self.value.binders
@@ -939,31 +2685,27 @@ impl ir::AssociatedTyValue {
         // Assemble the full list of conditions for projection to be valid.
         // This comes in two parts, marked as (1) and (2) in example above:
         //
-        // 1. require that the trait is implemented
+        // 1. the where-clauses of the associated type's declaration,
+        //    which begin with the trait being implemented
         // 2. any where-clauses from the `type` declaration in the impl
-        let impl_trait_ref = impl_datum.binders.value.trait_ref.up_shift(self.value.len());
+        let declared_where_clauses: Vec<_> = {
+            let parameters = associated_ty_parameters(&self.value.binders,
+                                                      impl_datum.binders.value.trait_ref.trait_ref());
+            associated_ty_datum.where_clauses
+                               .iter()
+                               .map(|wc| Subst::apply(&parameters, wc))
+                               .collect()
+        };
         let conditions: Vec<ir::Goal> =
-            Some(impl_trait_ref.clone().cast())
+            declared_where_clauses.cast()
             .into_iter()
             .chain(self.value.value.where_clauses.clone().cast())
             .collect();
 
         // The consequence is that the normalization holds.
-        let consequence = {
-            // First add refs to the bound parameters (`'a`, in above example)
-            let parameters = self.value.binders.iter().zip(0..).map(|p| p.to_parameter());
-
-            // Then add the trait-ref parameters (`Vec<T>`, in above example)
-            let parameters = parameters.chain(impl_trait_ref.parameters.clone());
-
-            // Construct normalization predicate
-            ir::Normalize {
-                projection: ir::ProjectionTy {
-                    associated_ty_id: self.associated_ty_id,
-                    parameters: parameters.collect(),
-                },
-                ty: self.value.value.ty.clone()
-            }
+        let consequence = ir::Normalize {
+            projection: self.projection(impl_datum),
+            ty: self.value.value.ty.clone()
         };
 
         let pos_clause = ir::ProgramClause {
@@ -978,6 +2720,60 @@ impl ir::AssociatedTyValue {
 
         vec![pos_clause]
     }
+
+    /// The projection that this value is for, like `<Vec<T> as
+    /// Iterable>::IntoIter<'a>` in the example above, in terms of the
+    /// value's binders followed by those of the impl.
+    fn projection(&self, impl_datum: &ir::ImplDatum) -> ir::ProjectionTy {
+        let impl_trait_ref = impl_datum.binders.value.trait_ref.trait_ref().up_shift(self.value.len());
+
+        // First add refs to the bound parameters (`'a`, in above example)
+        let parameters = self.value.binders.iter().zip(0..).map(|p| p.to_parameter());
+
+        // Then add the trait-ref parameters (`Vec<T>`, in above example)
+        let parameters = parameters.chain(impl_trait_ref.parameters.clone());
+
+        ir::ProjectionTy {
+            associated_ty_id: self.associated_ty_id,
+            parameters: parameters.collect(),
+        }
+    }
+
+    /// The normalizations this value provides, with the normalized
+    /// type left as an extra (innermost) binder:
+    ///
+    /// ```notrust
+    /// forall<'a, T, U> { (Vec<T>: Iterable<IntoIter<'a> = U>) }
+    /// ```
+    fn normalization_pattern(&self, impl_datum: &ir::ImplDatum) -> ir::Binders<ir::Normalize> {
+        let binders: Vec<_> =
+            self.value.binders
+                      .iter()
+                      .cloned()
+                      .chain(impl_datum.binders.binders.iter().cloned())
+                      .chain(Some(ir::ParameterKind::Ty(())))
+                      .collect();
+        let ty = ir::Ty::Var(binders.len() - 1);
+        ir::Binders {
+            binders: binders,
+            value: ir::Normalize { projection: self.projection(impl_datum), ty: ty },
+        }
+    }
+}
+
+/// The parameters to substitute for the binders of an associated
+/// type, given the binders of its own (like the `'a` in `type
+/// IntoIter<'a>`) and the trait-ref it is being projected from.
+/// `trait_ref` is shifted to account for the associated type's own
+/// binders.
+fn associated_ty_parameters(binders: &[ir::ParameterKind<()>],
+                            trait_ref: &ir::TraitRef)
+                            -> Vec<ir::Parameter> {
+    binders.iter()
+           .zip(0..)
+           .map(|p| p.to_parameter())
+           .chain(trait_ref.up_shift(binders.len()).binder_parameters())
+           .collect()
 }
 
 trait ToParameter {
@@ -999,6 +2795,8 @@ impl<'a> ToParameter for (&'a ir::ParameterKind<()>, usize) {
                 ir::ParameterKind::Ty(ir::Ty::Var(index)),
             ir::ParameterKind::Krate(_) =>
                 ir::ParameterKind::Krate(ir::Krate::Var(index)),
+            ir::ParameterKind::Const(_) =>
+                ir::ParameterKind::Const(ir::Const::Var(index)),
         }
     }
 }
@@ -1026,8 +2824,11 @@ impl ir::StructDatum {
         //    for<?T> LocalTo(Foo<?T>, A).
         //    for<?T> WF(Foo<?T>) :- (?T: Eq).
         //
-        // if the struct were fundamental, we might
-        // generate different `LocalTo` clauses
+        // If the struct is fundamental, we also generate, for each
+        // type parameter, a clause making it local wherever that
+        // parameter is:
+        //
+        //    for<?T, ?C> LocalTo(Foo<?T>, ?C) :- LocalTo(?T, ?C).
 
         let local_to = ir::ProgramClause {
             implication: self.binders.map_ref(|bound_datum| {
@@ -1055,64 +2856,945 @@ impl ir::StructDatum {
             })
         };
 
-        vec![local_to, wf]
+        let mut clauses = vec![local_to, wf];
+
+        if self.binders.value.flags.fundamental {
+            // We put the `?C` binder at the end of the list so that
+            // the indices of the struct's own parameters are not
+            // disturbed.
+            let num_binders = self.binders.binders.len();
+            let binders: Vec<_> = self.binders.binders
+                                              .iter()
+                                              .cloned()
+                                              .chain(Some(ir::ParameterKind::Krate(())))
+                                              .collect();
+            let krate = ir::Krate::Var(num_binders);
+            for (index, kind) in self.binders.binders.iter().enumerate() {
+                if let ir::ParameterKind::Ty(()) = *kind {
+                    clauses.push(ir::ProgramClause {
+                        implication: ir::Binders {
+                            binders: binders.clone(),
+                            value: ir::ProgramClauseImplication {
+                                consequence: ir::LocalTo {
+                                    value: self.binders.value.self_ty.clone().cast(),
+                                    krate: krate,
+                                }.cast(),
+                                conditions: vec![
+                                    ir::Goal::Leaf(ir::LocalTo {
+                                        value: ir::Ty::Var(index),
+                                        krate: krate,
+                                    }.cast())
+                                ],
+                            },
+                        },
+                    });
+                }
+            }
+        }
+
+        clauses
     }
-}
 
-impl ir::TraitDatum {
-    fn to_program_clauses(&self) -> Vec<ir::ProgramClause> {
+    fn to_auto_trait_clause(&self, auto_trait_id: ir::ItemId) -> ir::ProgramClause {
         // Given:
         //
-        //    crate A {
-        //        trait Ord<T> where Self: Eq<T> { ... }
-        //    }
-        //
-        // we generate the following clauses:
-        //
-        //    for<?Self, ?T> WF(?Self: Foo<?T>) :-
-        //        // types are well-formed:
-        //        WF(?Self),
-        //        WF(?T),
-        //        // where clauses declared on the trait are met:
-        //        (?Self: Eq<?T>),
-        //
-        //    for<?Self, ?T> ?Self: !Foo<?T> in A :-
-        //        (?Self: Eq<?T>),
+        //    auto trait Send { }
+        //    struct Foo<T> { a: T, b: Vec<T> }
         //
-        // we don't currently generate `LocalTo` clauses, but if we
-        // did it would look something like this (the problem is that
-        // we don't allow quantification over a crate-id like ?C):
+        // we generate the following clause:
         //
-        //    for<?Self, ?T> LocalTo(?Self: Foo<?T>, A).
-        //    for<?Self, ?T, ?C> LocalTo(?Self: Foo<?T>, ?C) :- LocalTo(?Self, ?C).
-        //    for<?Self, ?T, ?C> LocalTo(?Self: Foo<?T>, ?C) :- LocalTo(?T, ?C).
+        //    for<?T> (Foo<?T>: Send) :- (?T: Send), (Vec<?T>: Send).
 
-        let wf = ir::ProgramClause {
-            implication: self.binders.map_ref(|bound| {
+        ir::ProgramClause {
+            implication: self.binders.map_ref(|bound_datum| {
+                let self_ty: ir::Ty = bound_datum.self_ty.clone().cast();
                 ir::ProgramClauseImplication {
-                    consequence: ir::WellFormed::TraitRef(bound.trait_ref.clone()).cast(),
-
-                    conditions: {
-                        let tys = bound.trait_ref.parameters
-                                                 .iter()
-                                                 .filter_map(|pk| pk.as_ref().ty())
-                                                 .map(|ty| ir::WellFormed::Ty(ty.clone()).cast());
-
-                        let where_clauses = bound.where_clauses.iter()
-                                                               .cloned()
-                                                               .map(|wc| wc.cast());
+                    consequence: ir::TraitRef {
+                        trait_id: auto_trait_id,
+                        parameters: vec![self_ty.cast()],
+                    }.cast(),
 
-                        tys.chain(where_clauses).collect()
-                    }
+                    conditions: bound_datum.fields.iter()
+                                                  .map(|field| ir::TraitRef {
+                                                      trait_id: auto_trait_id,
+                                                      parameters: vec![field.ty.clone().cast()],
+                                                  }.cast())
+                                                  .collect(),
                 }
             })
-        };
-
-        vec![wf]
+        }
     }
 }
 
-impl ir::AssociatedTyDatum {
+impl ir::StructDatum {
+    fn to_sized_clause(&self, sized_trait_id: ir::ItemId) -> ir::ProgramClause {
+        // Given:
+        //
+        //    #[lang(sized)] trait Sized { }
+        //    struct Foo<T> where T: ?Sized { a: u32, b: T }
+        //
+        // we generate the following clause:
+        //
+        //    for<?T> (Foo<?T>: Sized) :- (?T: Sized).
+        //
+        // Only the last field of a struct may be unsized, so it alone
+        // decides whether the struct is; a struct without fields is
+        // always sized.
+
+        ir::ProgramClause {
+            implication: self.binders.map_ref(|bound_datum| {
+                let self_ty: ir::Ty = bound_datum.self_ty.clone().cast();
+                ir::ProgramClauseImplication {
+                    consequence: ir::TraitRef {
+                        trait_id: sized_trait_id,
+                        parameters: vec![self_ty.cast()],
+                    }.cast(),
+
+                    conditions: bound_datum.fields.last()
+                                                  .map(|field| ir::TraitRef {
+                                                      trait_id: sized_trait_id,
+                                                      parameters: vec![field.ty.clone().cast()],
+                                                  }.cast())
+                                                  .into_iter()
+                                                  .collect(),
+                }
+            })
+        }
+    }
+}
+
+impl ir::StructDatum {
+    fn to_unsize_clauses(&self) -> Vec<ir::ProgramClause> {
+        // Given:
+        //
+        //    struct Foo<T, U> { a: T, b: Bar<U> }
+        //
+        // we generate the following clause:
+        //
+        //    for<?T, ?U, ?V> Unsize(Foo<?T, ?U>, Foo<?T, ?V>) :- Unsize(Bar<?U>, Bar<?V>).
+        //
+        // There is one such clause for each type parameter that
+        // appears in the last field and in none of the others.
+
+        let bound_datum = &self.binders.value;
+        let (tail, fields) = match bound_datum.fields.split_last() {
+            Some(split) => split,
+            None => return vec![],
+        };
+
+        // The replacement parameter (`?V` above) is bound last.
+        let num_binders = self.binders.len();
+        let binders: Vec<_> = self.binders.binders
+                                          .iter()
+                                          .cloned()
+                                          .chain(Some(ir::ParameterKind::Ty(())))
+                                          .collect();
+
+        let mut clauses = vec![];
+        for (index, kind) in self.binders.binders.iter().enumerate() {
+            if let ir::ParameterKind::Ty(()) = *kind {
+                let parameters: Vec<_> = self.binders.binders.iter().zip(0..).map(|p| {
+                    if p.1 == index {
+                        ir::Ty::Var(num_binders).cast()
+                    } else {
+                        p.to_parameter()
+                    }
+                }).collect();
+
+                let target_tail = Subst::apply(&parameters, &tail.ty);
+                let mentioned_elsewhere = fields.iter().any(|f| {
+                    Subst::apply(&parameters, &f.ty) != f.ty
+                });
+                if target_tail == tail.ty || mentioned_elsewhere {
+                    continue;
+                }
+
+                clauses.push(ir::ProgramClause {
+                    implication: ir::Binders {
+                        binders: binders.clone(),
+                        value: ir::ProgramClauseImplication {
+                            consequence: ir::Unsize {
+                                source: bound_datum.self_ty.clone().cast(),
+                                target: Subst::apply(&parameters, &bound_datum.self_ty).cast(),
+                            }.cast(),
+                            conditions: vec![
+                                ir::Goal::Leaf(ir::Unsize {
+                                    source: tail.ty.clone(),
+                                    target: target_tail,
+                                }.cast())
+                            ],
+                        },
+                    },
+                });
+            }
+        }
+
+        clauses
+    }
+}
+
+impl ir::EnumDatum {
+    fn to_program_clauses(&self) -> Vec<ir::ProgramClause> {
+        // Given:
+        //
+        //    crate A {
+        //        enum Foo<T> where T: Eq { Bar, Baz { value: T } }
+        //    }
+        //
+        // we generate the following clauses, just as for a struct:
+        //
+        //    for<?T> LocalTo(Foo<?T>, A).
+        //    for<?T> WF(Foo<?T>) :- (?T: Eq).
+
+        let local_to = ir::ProgramClause {
+            implication: self.binders.map_ref(|bound_datum| {
+                ir::ProgramClauseImplication {
+                    consequence: ir::LocalTo {
+                        value: bound_datum.self_ty.clone().cast(),
+                        krate: ir::Krate::Id(self.krate_id),
+                    }.cast(),
+
+                    conditions: vec![]
+                }
+            })
+        };
+
+        let wf = ir::ProgramClause {
+            implication: self.binders.map_ref(|bound_datum| {
+                ir::ProgramClauseImplication {
+                    consequence: ir::WellFormed::Ty(bound_datum.self_ty.clone().cast()).cast(),
+
+                    conditions: bound_datum.where_clauses.iter()
+                                                         .cloned()
+                                                         .map(|wc| wc.cast())
+                                                         .collect(),
+                }
+            })
+        };
+
+        vec![local_to, wf]
+    }
+
+    fn to_auto_trait_clause(&self, auto_trait_id: ir::ItemId) -> ir::ProgramClause {
+        // Given:
+        //
+        //    auto trait Send { }
+        //    enum Foo<T> { Bar { a: T }, Baz { b: Vec<T> } }
+        //
+        // we generate the following clause, with a condition for the
+        // fields of every variant:
+        //
+        //    for<?T> (Foo<?T>: Send) :- (?T: Send), (Vec<?T>: Send).
+
+        ir::ProgramClause {
+            implication: self.binders.map_ref(|bound_datum| {
+                let self_ty: ir::Ty = bound_datum.self_ty.clone().cast();
+                ir::ProgramClauseImplication {
+                    consequence: ir::TraitRef {
+                        trait_id: auto_trait_id,
+                        parameters: vec![self_ty.cast()],
+                    }.cast(),
+
+                    conditions: bound_datum.field_tys()
+                                           .into_iter()
+                                           .map(|ty| ir::TraitRef {
+                                               trait_id: auto_trait_id,
+                                               parameters: vec![ty.cast()],
+                                           }.cast())
+                                           .collect(),
+                }
+            })
+        }
+    }
+
+    /// The fields of an enum must all be sized, so the enum always
+    /// is: `forall<T> { Option<T>: Sized }`.
+    fn to_sized_clause(&self, sized_trait_id: ir::ItemId) -> ir::ProgramClause {
+        ir::ProgramClause {
+            implication: self.binders.map_ref(|bound_datum| {
+                let self_ty: ir::Ty = bound_datum.self_ty.clone().cast();
+                ir::ProgramClauseImplication {
+                    consequence: ir::TraitRef {
+                        trait_id: sized_trait_id,
+                        parameters: vec![self_ty.cast()],
+                    }.cast(),
+                    conditions: vec![],
+                }
+            })
+        }
+    }
+}
+
+impl ir::ScalarType {
+    fn to_ty(self) -> ir::Ty {
+        ir::Ty::Apply(ir::ApplicationTy {
+            name: ir::TypeName::Scalar(self),
+            parameters: vec![],
+        })
+    }
+
+    /// Scalars are always well-formed: `WF(i32).`
+    fn to_wf_clause(self) -> ir::ProgramClause {
+        ir::ProgramClause {
+            implication: ir::Binders {
+                binders: vec![],
+                value: ir::ProgramClauseImplication {
+                    consequence: ir::WellFormed::Ty(self.to_ty()).cast(),
+                    conditions: vec![],
+                }
+            }
+        }
+    }
+
+    /// Scalars have no fields, so they implement every auto trait (and
+    /// `Sized`) unconditionally: `i32: Send.`
+    fn to_auto_trait_clause(self, auto_trait_id: ir::ItemId) -> ir::ProgramClause {
+        ir::ProgramClause {
+            implication: ir::Binders {
+                binders: vec![],
+                value: ir::ProgramClauseImplication {
+                    consequence: ir::TraitRef {
+                        trait_id: auto_trait_id,
+                        parameters: vec![self.to_ty().cast()],
+                    }.cast(),
+                    conditions: vec![],
+                }
+            }
+        }
+    }
+}
+
+impl ir::ClosureDatum {
+    fn to_program_clauses(&self, program: &ir::Program) -> Vec<ir::ProgramClause> {
+        // Given:
+        //
+        //    trait FnOnce<Args> { type Output; }
+        //    trait FnMut<Args> where Self: FnOnce<Args> { }
+        //    closure Foo<T>: FnMut(T) -> u32;
+        //
+        // we generate the following clauses:
+        //
+        //    for<?T> WF(Foo<?T>).
+        //    for<?T> Foo<?T>: FnMut<(?T,)>.
+        //    for<?T> Foo<?T>: FnOnce<(?T,)>.
+        //    for<?T> <Foo<?T> as FnOnce<(?T,)>>::Output ==> u32.
+        //
+        // Call traits that the program does not declare are skipped.
+
+        let fact = |consequence: ir::WhereClauseGoal| ir::ProgramClause {
+            implication: self.binders.map_ref(|_| ir::ProgramClauseImplication {
+                consequence: consequence,
+                conditions: vec![],
+            })
+        };
+
+        let bound_datum = &self.binders.value;
+        let self_ty: ir::Ty = bound_datum.self_ty.clone().cast();
+
+        let mut clauses = vec![fact(ir::WellFormed::Ty(self_ty.clone()).cast())];
+        clauses.extend(program.call_trait_goals(bound_datum.kind,
+                                                self_ty,
+                                                &bound_datum.arg_tys,
+                                                &bound_datum.ret_ty)
+                              .into_iter()
+                              .map(fact));
+        clauses
+    }
+
+    /// A closure is always `Sized`, whatever it captures:
+    /// `forall<T> { Foo<T>: Sized }`.
+    fn to_sized_clause(&self, sized_trait_id: ir::ItemId) -> ir::ProgramClause {
+        ir::ProgramClause {
+            implication: self.binders.map_ref(|bound_datum| ir::ProgramClauseImplication {
+                consequence: ir::TraitRef {
+                    trait_id: sized_trait_id,
+                    parameters: vec![bound_datum.self_ty.clone().cast()],
+                }.cast(),
+                conditions: vec![],
+            })
+        }
+    }
+}
+
+impl ir::FnDefDatum {
+    fn to_program_clauses(&self, program: &ir::Program) -> Vec<ir::ProgramClause> {
+        // Given:
+        //
+        //    trait FnOnce<Args> { type Output; }
+        //    trait FnMut<Args> where Self: FnOnce<Args> { }
+        //    trait Fn<Args> where Self: FnMut<Args> { }
+        //    fn foo<T>(T) -> u32 where T: Eq;
+        //
+        // we generate the following clauses:
+        //
+        //    for<?T> WF(foo<?T>) :- (?T: Eq).
+        //    for<?T> foo<?T>: Fn<(?T,)> :- (?T: Eq).
+        //    for<?T> foo<?T>: FnMut<(?T,)> :- (?T: Eq).
+        //    for<?T> foo<?T>: FnOnce<(?T,)> :- (?T: Eq).
+        //    for<?T> <foo<?T> as FnOnce<(?T,)>>::Output ==> u32 :- (?T: Eq).
+        //
+        // A fn item captures nothing, so it implements every call
+        // trait the program declares.
+
+        let rule = |consequence: ir::WhereClauseGoal| ir::ProgramClause {
+            implication: self.binders.map_ref(|bound_datum| ir::ProgramClauseImplication {
+                consequence: consequence,
+                conditions: bound_datum.where_clauses.iter()
+                                                     .cloned()
+                                                     .map(|wc| wc.cast())
+                                                     .collect(),
+            })
+        };
+
+        let bound_datum = &self.binders.value;
+        let self_ty: ir::Ty = bound_datum.self_ty.clone().cast();
+
+        let mut clauses = vec![rule(ir::WellFormed::Ty(self_ty.clone()).cast())];
+        clauses.extend(program.call_trait_goals(ir::ClosureKind::Fn,
+                                                self_ty,
+                                                &bound_datum.arg_tys,
+                                                &bound_datum.ret_ty)
+                              .into_iter()
+                              .map(rule));
+        clauses
+    }
+
+    fn to_auto_trait_clause(&self, auto_trait_id: ir::ItemId) -> ir::ProgramClause {
+        // Given:
+        //
+        //    auto trait Send { }
+        //    fn foo<T>(T);
+        //
+        // we generate the following clause:
+        //
+        //    for<?T> foo<?T>: Send.
+        //
+        // A fn item type has no fields, so this holds unconditionally;
+        // the same clause makes it `Sized`.
+
+        ir::ProgramClause {
+            implication: self.binders.map_ref(|bound_datum| ir::ProgramClauseImplication {
+                consequence: ir::TraitRef {
+                    trait_id: auto_trait_id,
+                    parameters: vec![bound_datum.self_ty.clone().cast()],
+                }.cast(),
+                conditions: vec![],
+            })
+        }
+    }
+}
+
+impl ir::GeneratorDatum {
+    fn to_program_clauses(&self, program: &ir::Program) -> Vec<ir::ProgramClause> {
+        // Given:
+        //
+        //    trait Generator<R> { type Yield; type Return; }
+        //    generator Foo<T>(u32) yield T -> bool;
+        //
+        // we generate the following clauses:
+        //
+        //    for<?T> WF(Foo<?T>).
+        //    for<?T> Foo<?T>: Generator<u32>.
+        //    for<?T> <Foo<?T> as Generator<u32>>::Yield ==> ?T.
+        //    for<?T> <Foo<?T> as Generator<u32>>::Return ==> bool.
+        //
+        // If the program does not declare a `#[lang(generator)]` trait,
+        // only the `WF` clause is generated.
+
+        let fact = |consequence: ir::WhereClauseGoal| ir::ProgramClause {
+            implication: self.binders.map_ref(|_| ir::ProgramClauseImplication {
+                consequence: consequence,
+                conditions: vec![],
+            })
+        };
+
+        let bound_datum = &self.binders.value;
+        let self_ty: ir::Ty = bound_datum.self_ty.clone().cast();
+
+        let mut clauses = vec![fact(ir::WellFormed::Ty(self_ty.clone()).cast())];
+
+        let trait_id = match program.lang_item_id(ir::LangItem::Generator) {
+            Some(trait_id) => trait_id,
+            None => return clauses,
+        };
+
+        let trait_ref = ir::TraitRef {
+            trait_id: trait_id,
+            parameters: vec![self_ty.cast(), bound_datum.resume_ty.clone().cast()],
+        };
+        clauses.push(fact(trait_ref.clone().cast()));
+
+        for &(name, ty) in &[("Yield", &bound_datum.yield_ty), ("Return", &bound_datum.return_ty)] {
+            if let Some(associated_ty_id) = program.associated_ty_id(trait_id, name) {
+                clauses.push(fact(ir::Normalize {
+                    projection: ir::ProjectionTy {
+                        associated_ty_id: associated_ty_id,
+                        parameters: trait_ref.parameters.clone(),
+                    },
+                    ty: ty.clone(),
+                }.cast()));
+            }
+        }
+
+        clauses
+    }
+
+    /// A generator is always `Sized`, like a closure:
+    /// `forall<T> { Foo<T>: Sized }`.
+    fn to_sized_clause(&self, sized_trait_id: ir::ItemId) -> ir::ProgramClause {
+        ir::ProgramClause {
+            implication: self.binders.map_ref(|bound_datum| ir::ProgramClauseImplication {
+                consequence: ir::TraitRef {
+                    trait_id: sized_trait_id,
+                    parameters: vec![bound_datum.self_ty.clone().cast()],
+                }.cast(),
+                conditions: vec![],
+            })
+        }
+    }
+}
+
+impl ir::ExternTyDatum {
+    fn to_program_clauses(&self) -> Vec<ir::ProgramClause> {
+        // Given:
+        //
+        //    crate A {
+        //        extern type Foo;
+        //    }
+        //
+        // we generate the following clauses:
+        //
+        //    LocalTo(Foo, A).
+        //    WF(Foo).
+        //
+        // Nothing else holds for `Foo`: it gets no `Sized` clause and,
+        // having no known fields, no auto trait clauses either.
+
+        let fact = |consequence: ir::WhereClauseGoal| ir::ProgramClause {
+            implication: ir::Binders {
+                binders: vec![],
+                value: ir::ProgramClauseImplication {
+                    consequence: consequence,
+                    conditions: vec![],
+                },
+            }
+        };
+
+        let self_ty: ir::Ty = self.self_ty.clone().cast();
+        vec![
+            fact(ir::LocalTo {
+                value: self_ty.clone().cast(),
+                krate: ir::Krate::Id(self.krate_id),
+            }.cast()),
+            fact(ir::WellFormed::Ty(self_ty).cast()),
+        ]
+    }
+}
+
+impl ir::OpaqueTyDatum {
+    fn to_program_clauses(&self) -> Vec<ir::ProgramClause> {
+        // Given:
+        //
+        //    opaque type Foo<T>: Iterator + Clone;
+        //
+        // we generate the following clauses:
+        //
+        //    for<?T> WF(Foo<?T>).
+        //    for<?T> Foo<?T>: Iterator.
+        //    for<?T> Foo<?T>: Clone.
+        //
+        // The hidden type is never revealed, so these are the only
+        // facts that hold for `Foo`.
+
+        let fact = |consequence: ir::WhereClauseGoal| ir::ProgramClause {
+            implication: self.binders.map_ref(|_| ir::ProgramClauseImplication {
+                consequence: consequence,
+                conditions: vec![],
+            })
+        };
+
+        let bound_datum = &self.binders.value;
+        let self_ty: ir::Ty = bound_datum.self_ty.clone().cast();
+
+        let mut clauses = vec![fact(ir::WellFormed::Ty(self_ty).cast())];
+        clauses.extend(bound_datum.bounds.iter().map(|b| fact(b.clone().cast())));
+        clauses
+    }
+}
+
+/// Like the trait impls of the standard library, the built-in clauses
+/// for tuples and fn pointers cover those of up to this many elements
+/// or arguments.
+const BUILTIN_ARITY: usize = 12;
+
+/// A built-in type constructor that takes a fixed list of parameters,
+/// like `&'a T`, `*const T`, `[T]`, `[T; N]` or `PhantomData<T>`, or
+/// none at all, like `!` or `str`.
+struct BuiltinType {
+    name: ir::TypeName,
+    binders: Vec<ir::ParameterKind<()>>,
+}
+
+impl BuiltinType {
+    fn all() -> Vec<BuiltinType> {
+        use ir::Mutability::{Mut, Not};
+        use ir::ParameterKind::{Const, Lifetime, Ty};
+        let builtin = |name, binders| BuiltinType { name, binders };
+        vec![
+            builtin(ir::TypeName::Ref(Not), vec![Lifetime(()), Ty(())]),
+            builtin(ir::TypeName::Ref(Mut), vec![Lifetime(()), Ty(())]),
+            builtin(ir::TypeName::Raw(Not), vec![Ty(())]),
+            builtin(ir::TypeName::Raw(Mut), vec![Ty(())]),
+            builtin(ir::TypeName::Slice, vec![Ty(())]),
+            builtin(ir::TypeName::Array, vec![Ty(()), Const(())]),
+            builtin(ir::TypeName::Never, vec![]),
+            builtin(ir::TypeName::Str, vec![]),
+            builtin(ir::TypeName::PhantomData, vec![Ty(())]),
+        ]
+    }
+
+    /// The tuple or fn pointer type `name` of a given arity, whose
+    /// parameters are its element types, or its argument types
+    /// followed by its return type.
+    fn of_arity(name: ir::TypeName) -> BuiltinType {
+        match name {
+            ir::TypeName::Tuple(arity) => {
+                BuiltinType { name, binders: vec![ir::ParameterKind::Ty(()); arity] }
+            }
+            ir::TypeName::FnPtr(arity) => {
+                BuiltinType { name, binders: vec![ir::ParameterKind::Ty(()); arity + 1] }
+            }
+            _ => panic!("`{:?}` has no arity", name),
+        }
+    }
+
+    /// The parameters taken by the built-in type `name`; scalars take
+    /// none.
+    fn binders_of(name: ir::TypeName) -> Vec<ir::ParameterKind<()>> {
+        BuiltinType::all()
+            .into_iter()
+            .find(|b| b.name == name)
+            .map(|b| b.binders)
+            .unwrap_or_default()
+    }
+
+    /// The type constructor applied to its bound parameters, e.g.
+    /// `&'?0 ?1`.
+    fn to_ty(&self) -> ir::Ty {
+        ir::Ty::Apply(ir::ApplicationTy {
+            name: self.name,
+            parameters: self.binders.iter().zip(0..).map(|p| p.to_parameter()).collect(),
+        })
+    }
+
+    /// The type parameters (but not the lifetime parameters) of the
+    /// type constructor, e.g. `?1` for `&'?0 ?1`.
+    fn ty_parameters(&self) -> Vec<ir::Ty> {
+        self.binders.iter()
+                    .zip(0..)
+                    .filter_map(|p| match p.to_parameter() {
+                        ir::ParameterKind::Ty(ty) => Some(ty),
+                        _ => None,
+                    })
+                    .collect()
+    }
+
+    /// A built-in type is well-formed if its type parameters are:
+    /// `forall<'a, T> { WF(&'a T) :- WF(T) }`.
+    fn to_wf_clause(&self) -> ir::ProgramClause {
+        ir::ProgramClause {
+            implication: ir::Binders {
+                binders: self.binders.clone(),
+                value: ir::ProgramClauseImplication {
+                    consequence: ir::WellFormed::Ty(self.to_ty()).cast(),
+                    conditions: self.ty_parameters()
+                                    .into_iter()
+                                    .map(|ty| ir::WellFormed::Ty(ty).cast())
+                                    .collect(),
+                }
+            }
+        }
+    }
+
+    /// Every built-in type but slices and `str` is `Sized`, whatever
+    /// its parameters: `forall<'a, T> { &'a T: Sized }`. The exception
+    /// is tuples, which are `Sized` if their elements are:
+    /// `forall<A, B> { (A, B): Sized :- A: Sized, B: Sized }`.
+    fn to_sized_clause(&self, sized_trait_id: ir::ItemId) -> Option<ir::ProgramClause> {
+        let conditions = match self.name {
+            ir::TypeName::Slice | ir::TypeName::Str => return None,
+            ir::TypeName::Tuple(_) => {
+                self.ty_parameters()
+                    .into_iter()
+                    .map(|ty| ir::TraitRef {
+                        trait_id: sized_trait_id,
+                        parameters: vec![ty.cast()],
+                    }.cast())
+                    .collect()
+            }
+            _ => vec![],
+        };
+        Some(ir::ProgramClause {
+            implication: ir::Binders {
+                binders: self.binders.clone(),
+                value: ir::ProgramClauseImplication {
+                    consequence: ir::TraitRef {
+                        trait_id: sized_trait_id,
+                        parameters: vec![self.to_ty().cast()],
+                    }.cast(),
+                    conditions: conditions,
+                }
+            }
+        })
+    }
+
+    /// Arrays unsize to slices: `forall<T, const N> { Unsize([T; N], [T]) }`.
+    fn to_unsize_clause(&self) -> Option<ir::ProgramClause> {
+        if self.name != ir::TypeName::Array {
+            return None;
+        }
+        Some(ir::ProgramClause {
+            implication: ir::Binders {
+                binders: self.binders.clone(),
+                value: ir::ProgramClauseImplication {
+                    consequence: ir::Unsize {
+                        source: self.to_ty(),
+                        target: ir::Ty::Apply(ir::ApplicationTy {
+                            name: ir::TypeName::Slice,
+                            parameters: self.ty_parameters().into_iter().map(|ty| ty.cast()).collect(),
+                        }),
+                    }.cast(),
+                    conditions: vec![],
+                }
+            }
+        })
+    }
+
+    /// Built-in types implement auto traits structurally, as if their
+    /// type parameters were fields: `forall<'a, T> { &'a T: Send :- T: Send }`.
+    /// The exception is fn pointers, which hold no values of their
+    /// argument or return types: `forall<A, R> { fn(A) -> R: Send }`.
+    fn to_auto_trait_clause(&self, auto_trait_id: ir::ItemId) -> ir::ProgramClause {
+        let tys = match self.name {
+            ir::TypeName::FnPtr(_) => vec![],
+            _ => self.ty_parameters(),
+        };
+        ir::ProgramClause {
+            implication: ir::Binders {
+                binders: self.binders.clone(),
+                value: ir::ProgramClauseImplication {
+                    consequence: ir::TraitRef {
+                        trait_id: auto_trait_id,
+                        parameters: vec![self.to_ty().cast()],
+                    }.cast(),
+                    conditions: tys.into_iter()
+                                   .map(|ty| ir::TraitRef {
+                                       trait_id: auto_trait_id,
+                                       parameters: vec![ty.cast()],
+                                   }.cast())
+                                   .collect(),
+                }
+            }
+        }
+    }
+}
+
+impl ir::TraitDatum {
+    fn to_program_clauses(&self) -> Vec<ir::ProgramClause> {
+        // Given:
+        //
+        //    crate A {
+        //        trait Ord<T> where Self: Eq<T> { ... }
+        //    }
+        //
+        // we generate the following clauses:
+        //
+        //    for<?Self, ?T> WF(?Self: Foo<?T>) :-
+        //        // types are well-formed:
+        //        WF(?Self),
+        //        WF(?T),
+        //        // where clauses declared on the trait are met:
+        //        (?Self: Eq<?T>),
+        //
+        //    for<?Self, ?T> ?Self: !Foo<?T> in A :-
+        //        (?Self: Eq<?T>),
+        //
+        // we don't currently generate `LocalTo` clauses, but if we
+        // did it would look something like this (the problem is that
+        // we don't allow quantification over a crate-id like ?C):
+        //
+        //    for<?Self, ?T> LocalTo(?Self: Foo<?T>, A).
+        //    for<?Self, ?T, ?C> LocalTo(?Self: Foo<?T>, ?C) :- LocalTo(?Self, ?C).
+        //    for<?Self, ?T, ?C> LocalTo(?Self: Foo<?T>, ?C) :- LocalTo(?T, ?C).
+
+        let wf = ir::ProgramClause {
+            implication: self.binders.map_ref(|bound| {
+                ir::ProgramClauseImplication {
+                    consequence: ir::WellFormed::TraitRef(bound.trait_ref.clone()).cast(),
+
+                    conditions: {
+                        let tys = bound.trait_ref.parameters
+                                                 .iter()
+                                                 .filter_map(|pk| pk.as_ref().ty())
+                                                 .map(|ty| ir::WellFormed::Ty(ty.clone()).cast());
+
+                        let where_clauses = bound.where_clauses.iter()
+                                                               .cloned()
+                                                               .map(|wc| wc.cast());
+
+                        tys.chain(where_clauses).collect()
+                    }
+                }
+            })
+        };
+
+        let mut clauses = vec![wf];
+
+        // A trait alias additionally gets:
+        //
+        //    for<?Self, ?T> (?Self: Alias<?T>) :- (?Self: A<?T>), (?Self: B).
+        //
+        // The other direction, from `?Self: Alias<?T>` to its bounds,
+        // comes from elaborating the where clauses.
+        if self.binders.value.flags.alias {
+            clauses.push(ir::ProgramClause {
+                implication: self.binders.map_ref(|bound| {
+                    ir::ProgramClauseImplication {
+                        consequence: bound.trait_ref.clone().cast(),
+                        conditions: bound.where_clauses.clone().cast(),
+                    }
+                })
+            });
+        }
+
+        clauses
+    }
+
+    fn to_unsize_clause(&self,
+                        associated_ty_ids: &[ir::ItemId],
+                        sized_trait_id: Option<ir::ItemId>)
+                        -> ir::ProgramClause {
+        // Given:
+        //
+        //    trait Foo<T> { type Item; }
+        //
+        // we generate the following clause, so long as `Foo` is
+        // object safe:
+        //
+        //    for<?T, ?Self, ?Item> Unsize(?Self, dyn Foo<?T, Item = ?Item>) :-
+        //        (?Self: Foo<?T>),
+        //        (<?Self as Foo<?T>>::Item ==> ?Item),
+        //        (?Self: Sized).
+        //
+        // where the `Sized` bound is left out if the program has no
+        // `Sized` lang item.
+
+        let trait_ref = &self.binders.value.trait_ref;
+        let self_ty = trait_ref.parameters[0].clone();
+        let values: Vec<_> = (0..associated_ty_ids.len())
+            .map(|i| ir::Ty::Var(self.binders.len() + i))
+            .collect();
+
+        let mut conditions: Vec<ir::Goal> = vec![trait_ref.clone().cast()];
+        conditions.extend(associated_ty_ids.iter().zip(&values).map(|(&id, value)| {
+            ir::Normalize {
+                projection: ir::ProjectionTy {
+                    associated_ty_id: id,
+                    parameters: trait_ref.parameters.clone(),
+                },
+                ty: value.clone(),
+            }.cast()
+        }));
+        if let Some(sized_trait_id) = sized_trait_id {
+            conditions.push(ir::TraitRef {
+                trait_id: sized_trait_id,
+                parameters: vec![self_ty.clone()],
+            }.cast());
+        }
+
+        ir::ProgramClause {
+            implication: ir::Binders {
+                binders: self.binders
+                             .binders
+                             .iter()
+                             .cloned()
+                             .chain(associated_ty_ids.iter().map(|_| ir::ParameterKind::Ty(())))
+                             .collect(),
+                value: ir::ProgramClauseImplication {
+                    consequence: ir::Unsize {
+                        source: self_ty.ty().unwrap().clone(),
+                        target: dyn_ty(trait_ref.trait_id, &trait_ref.parameters[1..], &values),
+                    }.cast(),
+                    conditions: conditions,
+                }
+            }
+        }
+    }
+
+    fn to_dyn_normalize_clauses(&self, associated_ty_ids: &[ir::ItemId]) -> Vec<ir::ProgramClause> {
+        // Given:
+        //
+        //    trait Foo<T> { type Item; }
+        //
+        // we generate the following clause, so long as `Foo` is
+        // object safe:
+        //
+        //    for<?T, ?Item> <dyn Foo<?T, Item = ?Item> as Foo<?T>>::Item ==> ?Item.
+        //
+        // `Self` is not among the binders here, so the values come
+        // right after the trait's own parameters.
+
+        let trait_id = self.binders.value.trait_ref.trait_id;
+        let num_parameters = self.binders.len() - 1;
+        let parameters: Vec<_> = self.binders.binders[..num_parameters]
+            .iter()
+            .zip(0..)
+            .map(|p| p.to_parameter())
+            .collect();
+        let values: Vec<_> = (0..associated_ty_ids.len())
+            .map(|i| ir::Ty::Var(num_parameters + i))
+            .collect();
+        let mut projection_parameters = vec![dyn_ty(trait_id, &parameters, &values).cast()];
+        projection_parameters.extend(parameters);
+
+        associated_ty_ids.iter().zip(&values).map(|(&id, value)| {
+            ir::ProgramClause {
+                implication: ir::Binders {
+                    binders: self.binders.binders[..num_parameters]
+                                 .iter()
+                                 .cloned()
+                                 .chain(associated_ty_ids.iter().map(|_| ir::ParameterKind::Ty(())))
+                                 .collect(),
+                    value: ir::ProgramClauseImplication {
+                        consequence: ir::Normalize {
+                            projection: ir::ProjectionTy {
+                                associated_ty_id: id,
+                                parameters: projection_parameters.clone(),
+                            },
+                            ty: value.clone(),
+                        }.cast(),
+                        conditions: vec![],
+                    }
+                }
+            }
+        }).collect()
+    }
+}
+
+/// The type `dyn Trait<P.., A = V..>`, given the parameters of the
+/// trait `trait_id` (leaving out `Self`) and the values of its
+/// associated types.
+fn dyn_ty(trait_id: ir::ItemId, parameters: &[ir::Parameter], values: &[ir::Ty]) -> ir::Ty {
+    ir::Ty::Apply(ir::ApplicationTy {
+        name: ir::TypeName::ItemId(trait_id),
+        parameters: parameters.iter()
+                              .cloned()
+                              .chain(values.iter().map(|v| v.clone().cast()))
+                              .collect(),
+    })
+}
+
+impl ir::AssociatedTyDatum {
     fn to_program_clauses(&self, id: ir::ItemId) -> Vec<ir::ProgramClause> {
         /// Given:
         ///
@@ -1208,6 +3890,43 @@ impl ir::AssociatedTyDatum {
             }
         };
 
-        vec![neg_clause]
+        // Given:
+        //
+        //    trait Iterator { type Item where Self: Sized; }
+        //
+        // we generate:
+        //
+        //    forall<Self> {
+        //        WF(<Self as Iterator>::Item) :- (Self: Iterator), (Self: Sized)
+        //    }
+        let wf_clause = {
+            let binders = self.parameter_kinds.anonymize();
+            let trait_ref = match self.where_clauses[0] {
+                ir::WhereClause::Implemented(ref trait_ref) => trait_ref,
+                _ => panic!("associated type where clauses must begin with the trait-ref"),
+            };
+            let num_addl = binders.len() - trait_ref.parameters.len();
+            let parameters = binders[..num_addl]
+                .iter()
+                .zip(0..)
+                .map(|p| p.to_parameter())
+                .chain(trait_ref.parameters.iter().cloned())
+                .collect();
+
+            ir::ProgramClause {
+                implication: ir::Binders {
+                    binders: binders,
+                    value: ir::ProgramClauseImplication {
+                        consequence: ir::WellFormed::Ty(ir::Ty::Projection(ir::ProjectionTy {
+                            associated_ty_id: id,
+                            parameters: parameters,
+                        })).cast(),
+                        conditions: self.where_clauses.clone().cast(),
+                    }
+                }
+            }
+        };
+
+        vec![neg_clause, wf_clause]
     }
 }
@@ -3,6 +3,7 @@
 use chalk_parse;
 use errors::*;
 use ir::*;
+use lalrpop_intern::intern;
 use std::sync::Arc;
 use super::{LowerProgram, LowerGoal};
 
@@ -65,6 +66,28 @@ fn goal_quantifiers() {
     });
 }
 
+#[test]
+fn lifetime_goal_quantifiers() {
+    let program = Arc::new(parse_and_lower("trait Foo { }").unwrap());
+    let goal = parse_and_lower_goal(&program, "forall<T, 'a> { &'a T: Foo }").unwrap();
+    set_current_program(&program, || {
+        assert_eq!(format!("{:?}", goal), "ForAll<type, lifetime> { &'?1 ?0: Foo }");
+    });
+}
+
+#[test]
+fn goal_hypotheses() {
+    let program = Arc::new(parse_and_lower("trait Foo<'a> { } trait Bar<'a> { } trait Baz { }").unwrap());
+    let goal = parse_and_lower_goal(&program, "forall<T> { \
+                                               if (forall<'a> { T: Foo<'a>, T: Bar<'a> }, T: Baz,) { \
+                                               T: Baz } }").unwrap();
+    set_current_program(&program, || {
+        assert_eq!(format!("{:?}", goal),
+                   "ForAll<type> { if ([for<lifetime> ?1: Foo<'?0>, for<lifetime> ?1: Bar<'?0>, ?0: Baz]) \
+                    { ?0: Baz } }");
+    });
+}
+
 #[test]
 fn atc_accounting() {
     let program = Arc::new(parse_and_lower("
@@ -94,10 +117,15 @@ fn atc_accounting() {
                 value: for<lifetime> AssociatedTyValueBound {
                     ty: Iter<'?0, ?1>,
                     where_clauses: []
-                }
+                },
+                default: false
             }
-        ]
-    }
+        ],
+        associated_const_values: []
+    },
+    default: false,
+    is_unsafe: false,
+    specializes: []
 }"#);
         let goal = parse_and_lower_goal(&program, "forall<X> { forall<'a> { forall<Y> { \
                                                    X: Iterable<Iter<'a> = Y> } } }")
@@ -192,3 +220,1117 @@ fn check_parameter_kinds() {
         }
     }
 }
+
+#[test]
+fn auto_trait() {
+    parse_and_lower("auto trait Send { } struct Foo { } impl Send for Foo { }").unwrap();
+
+    lowering_error! {
+        program {
+            auto trait Send<T> { }
+        }
+        error_msg {
+            "auto trait `Send` cannot have parameters"
+        }
+    }
+
+    lowering_error! {
+        program {
+            auto trait Send { type Item; }
+        }
+        error_msg {
+            "auto trait `Send` cannot define associated types"
+        }
+    }
+}
+
+#[test]
+fn negative_impl() {
+    parse_and_lower("auto trait Send { } struct Foo { } impl !Send for Foo { }").unwrap();
+
+    lowering_error! {
+        program {
+            trait Iterator { type Item; }
+            struct Foo { }
+            impl !Iterator for Foo { type Item = Foo; }
+        }
+        error_msg {
+            "negative impls cannot define associated values"
+        }
+    }
+}
+
+#[test]
+fn scalars() {
+    parse_and_lower("trait Foo { } impl Foo for i32 { } impl Foo for bool { }").unwrap();
+
+    lowering_error! {
+        program {
+            trait Foo { }
+            impl Foo for u8<u8> { }
+        }
+        error_msg {
+            "`u8` takes 0 type parameters, not 1"
+        }
+    }
+
+    lowering_error! {
+        program {
+            struct Foo { }
+            impl u32 for Foo { }
+        }
+        error_msg {
+            "expected a trait, found `u32`, which is not a trait"
+        }
+    }
+}
+
+#[test]
+fn tuples() {
+    parse_and_lower("trait Foo { } impl Foo for () { } impl<A, B> Foo for (A, B) { }").unwrap();
+    parse_and_lower("trait Foo { } struct Bar { } impl Foo for (Bar,) { }").unwrap();
+}
+
+#[test]
+fn references() {
+    parse_and_lower("trait Foo { } impl<'a, T> Foo for &'a T { } impl<'a, T> Foo for &'a mut T { }")
+        .unwrap();
+}
+
+#[test]
+fn raw_pointers() {
+    parse_and_lower("trait Foo { } impl<T> Foo for *const T { } impl<T> Foo for *mut T { }").unwrap();
+}
+
+#[test]
+fn slices() {
+    parse_and_lower("trait Foo { } impl<T> Foo for [T] { } impl<'a, T> Foo for &'a [T] { }").unwrap();
+}
+
+#[test]
+fn arrays() {
+    parse_and_lower("trait Foo { } impl<T, const N> Foo for [T; N] { }").unwrap();
+    parse_and_lower("trait Foo { } struct Bar { } impl Foo for [Bar; 3] { }").unwrap();
+    parse_and_lower("struct Bar<const N> { } trait Foo { } impl<T, const N> Foo for [Bar<N>; N] { }")
+        .unwrap();
+
+    lowering_error! {
+        program {
+            trait Foo { }
+            impl<T> Foo for [T; N] { }
+        }
+        error_msg {
+            "invalid const name: \"N\""
+        }
+    }
+
+    lowering_error! {
+        program {
+            struct Bar { }
+            struct Baz<const N> { }
+            trait Foo { }
+            impl Foo for Baz<Bar> { }
+        }
+        error_msg {
+            "incorrect parameter kind: expected const, found type"
+        }
+    }
+
+    let error = parse_and_lower("trait Foo { } impl Foo for [u32; 99999999999999999999] { }").unwrap_err();
+    assert!(error.to_string().contains("const value does not fit in a `u64`"));
+}
+
+#[test]
+fn fn_pointers() {
+    parse_and_lower("trait Foo { } struct Bar { } impl Foo for fn(Bar) -> Bar { }").unwrap();
+    parse_and_lower("trait Foo { } impl<'a, T> Foo for for<'a> fn(&'a T) { }").unwrap();
+    parse_and_lower("trait Foo { } impl<A, B> Foo for fn(A, B) { }").unwrap();
+}
+
+#[test]
+fn closures() {
+    parse_and_lower("closure Foo<T>: FnMut(T) -> u32; closure Bar: Fn();").unwrap();
+
+    lowering_error! {
+        program {
+            closure Foo: FnWhatever(u32);
+        }
+        error_msg {
+            "closure `Foo` has unknown kind `FnWhatever`, expected `Fn`, `FnMut` or `FnOnce`"
+        }
+    }
+
+    lowering_error! {
+        program {
+            closure Foo: Fn(T);
+        }
+        error_msg {
+            "invalid type name `T`"
+        }
+    }
+}
+
+#[test]
+fn fn_defs() {
+    parse_and_lower("trait Eq { } fn foo<T>(T) -> u32 where T: Eq; fn bar();").unwrap();
+
+    lowering_error! {
+        program {
+            fn foo(T);
+        }
+        error_msg {
+            "invalid type name `T`"
+        }
+    }
+}
+
+#[test]
+fn generators() {
+    parse_and_lower("generator Foo<T>(u32) yield T -> bool; generator Bar() yield ();").unwrap();
+
+    lowering_error! {
+        program {
+            generator Foo() yield T;
+        }
+        error_msg {
+            "invalid type name `T`"
+        }
+    }
+}
+
+#[test]
+fn extern_types() {
+    parse_and_lower("extern type Foo; trait Bar { } impl Bar for Foo { }").unwrap();
+
+    lowering_error! {
+        program {
+            extern type Foo;
+            struct Bar { field: Foo<u32> }
+        }
+        error_msg {
+            "`Foo` takes 0 type parameters, not 1"
+        }
+    }
+}
+
+#[test]
+fn object_safety() {
+    let program = parse_and_lower("
+        #[lang(sized)] trait Sized { }
+        trait PartialEq<T> { }
+        trait Iterator { type Item; }
+        trait Streaming { type Item<'a>; }
+
+        trait Safe<T> where Self: Iterator, T: Iterator { }
+        trait Eq where Self: PartialEq<Self> { }
+        trait Clone where Self: Sized { }
+        trait Holds where Self: Iterator<Item = Self> { }
+    ").unwrap();
+
+    let is_object_safe = |name: &str| {
+        program.is_object_safe(program.type_ids[&intern(name)])
+    };
+
+    assert!(is_object_safe("Iterator"));
+    assert!(is_object_safe("Safe"));
+    assert!(!is_object_safe("Streaming"));
+    assert!(!is_object_safe("Eq"));
+    assert!(!is_object_safe("Clone"));
+    assert!(!is_object_safe("Holds"));
+}
+
+#[test]
+fn dyn_trait() {
+    parse_and_lower("trait Foo<T> { } struct Bar { } impl Foo<Bar> for dyn Foo<Bar> { }").unwrap();
+
+    lowering_error! {
+        program {
+            trait PartialEq<T> { }
+            trait Eq where Self: PartialEq<Self> { }
+            struct Foo<T> { }
+            impl PartialEq<Foo<dyn Eq>> for Foo<dyn Eq> { }
+        }
+        error_msg {
+            "the trait `Eq` cannot be made into an object"
+        }
+    }
+
+    lowering_error! {
+        program {
+            struct Foo { }
+            trait Bar { }
+            impl Bar for dyn Foo { }
+        }
+        error_msg {
+            "expected a trait, found `Foo`, which is not a trait"
+        }
+    }
+}
+
+#[test]
+fn dyn_trait_associated_types() {
+    parse_and_lower("
+        trait Iterator { type Item; }
+        trait Convert<T> { type Output; }
+        trait Debug { }
+        struct Foo { }
+        impl Debug for dyn Iterator<Item = Foo> { }
+        impl Debug for dyn Convert<Foo, Output = Foo> { }
+    ").unwrap();
+
+    // The bindings follow the parameters, if any, without a comma of
+    // their own to start.
+    assert!(parse_and_lower("
+        trait Iterator { type Item; }
+        trait Debug { }
+        struct Foo { }
+        impl Debug for dyn Iterator<, Item = Foo> { }
+    ").is_err());
+
+    lowering_error! {
+        program {
+            trait Iterator { type Item; }
+            trait Debug { }
+            impl Debug for dyn Iterator { }
+        }
+        error_msg {
+            "the value of the associated type `Item` in `Iterator` must be specified"
+        }
+    }
+
+    lowering_error! {
+        program {
+            trait Iterator { type Item; }
+            trait Debug { }
+            struct Foo { }
+            impl Debug for dyn Iterator<Item = Foo, Item = Foo> { }
+        }
+        error_msg {
+            "the value of the associated type `Item` in `Iterator` is specified twice"
+        }
+    }
+
+    lowering_error! {
+        program {
+            trait Iterator { type Item; }
+            trait Debug { }
+            struct Foo { }
+            impl Debug for dyn Iterator<Item = Foo, Output = Foo> { }
+        }
+        error_msg {
+            "no associated type `Output` defined in trait"
+        }
+    }
+}
+
+#[test]
+fn opaque_types() {
+    parse_and_lower("trait Iterator { } trait Into<T> { } opaque type Foo<T>: Iterator + Into<T>;").unwrap();
+
+    lowering_error! {
+        program {
+            struct Bar { }
+            opaque type Foo: Bar;
+        }
+        error_msg {
+            "expected a trait, found `Bar`, which is not a trait"
+        }
+    }
+
+    lowering_error! {
+        program {
+            trait Iterator { }
+            trait Clone { }
+            opaque type Foo: Iterator;
+            impl Clone for Foo { }
+        }
+        error_msg {
+            "cannot implement `Clone` for opaque type `Foo`"
+        }
+    }
+}
+
+#[test]
+fn assoc_ty_defaults() {
+    parse_and_lower("trait Foo<T> { type Assoc<'a> = Bar<T, Self>; } struct Bar<A, B> { }").unwrap();
+
+    lowering_error! {
+        program {
+            trait Foo { type Assoc = T; }
+        }
+        error_msg {
+            "invalid type name `T`"
+        }
+    }
+}
+
+#[test]
+fn generic_assoc_tys() {
+    parse_and_lower("
+        trait Family { type Member<T>; type Ref<'a, T>; }
+        struct Vec<T> { }
+        struct Ref<'a, T> { }
+        struct VecFamily { }
+        impl Family for VecFamily {
+            type Member<T> = Vec<T>;
+            type Ref<'a, T> = Ref<'a, Vec<T>>;
+        }
+    ").unwrap();
+
+    lowering_error! {
+        program {
+            trait Family { type Member<T>; }
+            struct Foo { }
+            impl Family for Foo { type Member<'a> = Foo; }
+        }
+        error_msg {
+            "parameters of associated type `Member` do not match its declaration in the trait"
+        }
+    }
+
+    lowering_error! {
+        program {
+            trait Family { type Member<T>; }
+            struct Foo { }
+            impl Family for Foo { type Other = Foo; }
+        }
+        error_msg {
+            "no associated type `Other` defined in trait"
+        }
+    }
+
+    lowering_error! {
+        program {
+            trait Family { type Member<T>; }
+            struct Foo { }
+            impl<T> Family for T where T: Family<Member = Foo> { }
+        }
+        error_msg {
+            "wrong number of parameters for associated type (expected 1, got 0)"
+        }
+    }
+}
+
+#[test]
+fn assoc_consts() {
+    let program = parse_and_lower("
+        trait Len { const LEN; type Item; }
+        struct Array<T, const N> { }
+        impl<T, const N> Len for Array<T, N> { type Item = T; const LEN = N; }
+    ").unwrap();
+    assert!(!program.is_object_safe(program.type_ids[&intern("Len")]));
+
+    lowering_error! {
+        program {
+            trait Len { const LEN; }
+            struct Foo { }
+            impl Len for Foo { const SIZE = 3; }
+        }
+        error_msg {
+            "no associated const `SIZE` defined in trait"
+        }
+    }
+
+    lowering_error! {
+        program {
+            trait Len { const LEN; }
+            struct Foo { }
+            impl Len for Foo { const LEN = N; }
+        }
+        error_msg {
+            "invalid const name: \"N\""
+        }
+    }
+}
+
+#[test]
+fn supertraits() {
+    parse_and_lower("trait PartialEq<T> { } trait Eq: PartialEq<Self> { } trait Ord<T>: Eq + PartialEq<T> { }")
+        .unwrap();
+
+    lowering_error! {
+        program {
+            struct Foo { }
+            trait Bar: Foo { }
+        }
+        error_msg {
+            "expected a trait, found `Foo`, which is not a trait"
+        }
+    }
+
+    lowering_error! {
+        program {
+            trait Bar { }
+            auto trait Send: Bar { }
+        }
+        error_msg {
+            "auto trait `Send` cannot have supertraits"
+        }
+    }
+}
+
+#[test]
+fn assoc_ty_where_clauses() {
+    parse_and_lower("trait Sized { } trait Iterator { type Item where Self: Sized; type Iter<'a> where Self: Sized = Self; }")
+        .unwrap();
+
+    lowering_error! {
+        program {
+            trait Iterator { type Item where T: Iterator; }
+        }
+        error_msg {
+            "invalid type name `T`"
+        }
+    }
+}
+
+#[test]
+fn trait_aliases() {
+    parse_and_lower("trait Clone { } trait Into<T> { } trait CloneInto<T> = Clone + Into<T>;").unwrap();
+
+    lowering_error! {
+        program {
+            trait Clone { }
+            trait Alias = Clone;
+            struct Foo { }
+            impl Alias for Foo { }
+        }
+        error_msg {
+            "cannot implement trait alias `Alias`"
+        }
+    }
+}
+
+#[test]
+fn type_outlives() {
+    parse_and_lower("trait Foo<'a> where Self: 'a { } struct Ref<'a, T> where T: 'a { }").unwrap();
+}
+
+#[test]
+fn lifetime_outlives() {
+    parse_and_lower("trait Foo<'a, 'b> where 'a: 'b { } struct Ref<'a, 'b> where 'b: 'a { }").unwrap();
+}
+
+#[test]
+fn marker_traits() {
+    parse_and_lower("#[marker] trait Marker { } struct Foo { } impl Marker for Foo { } impl<T> Marker for T { }")
+        .unwrap();
+
+    lowering_error! {
+        program {
+            #[marker] trait Marker { type Item; }
+        }
+        error_msg {
+            "marker trait `Marker` cannot have associated items"
+        }
+    }
+}
+
+#[test]
+fn unsafe_traits() {
+    let program = parse_and_lower("unsafe auto trait Send { } trait Clone { } struct Foo { }
+                                   unsafe impl Send for Foo { } impl Clone for Foo { }").unwrap();
+    let send_id = program.type_ids[&intern("Send")];
+    let clone_id = program.type_ids[&intern("Clone")];
+    assert!(program.trait_data[&send_id].binders.value.flags.is_unsafe);
+    assert!(!program.trait_data[&clone_id].binders.value.flags.is_unsafe);
+
+    lowering_error! {
+        program {
+            unsafe trait Send { }
+            struct Foo { }
+            impl Send for Foo { }
+        }
+        error_msg {
+            "implementing the unsafe trait `Send` requires an `unsafe impl`"
+        }
+    }
+
+    lowering_error! {
+        program {
+            trait Clone { }
+            struct Foo { }
+            unsafe impl Clone for Foo { }
+        }
+        error_msg {
+            "implementing the trait `Clone` is not unsafe"
+        }
+    }
+
+    lowering_error! {
+        program {
+            unsafe auto trait Send { }
+            struct Foo { }
+            unsafe impl !Send for Foo { }
+        }
+        error_msg {
+            "negative impls of `Send` cannot be unsafe"
+        }
+    }
+}
+
+#[test]
+fn fundamental_structs() {
+    parse_and_lower("#[fundamental] struct Box<T> { } struct Foo { field: Box<Foo> }").unwrap();
+}
+
+#[test]
+fn specialization() {
+    parse_and_lower("trait Tr { type Item; } struct Foo { } \
+                     impl<T> Tr for T { default type Item = T; } \
+                     impl Tr for Foo { type Item = Foo; }").unwrap();
+
+    lowering_error! {
+        program {
+            trait Tr { type Item; }
+            struct Foo { }
+            impl<T> Tr for T { type Item = T; }
+            impl Tr for Foo { type Item = Foo; }
+        }
+        error_msg {
+            "cannot specialize non-default associated type `Item`"
+        }
+    }
+}
+
+#[test]
+fn negative_goals() {
+    let program = &Arc::new(parse_and_lower("trait Copy { } struct Vec<T> { }").unwrap());
+    set_current_program(&program, || {
+        let goal = parse_and_lower_goal(&program, "forall<T> { not { Vec<T>: Copy } }").unwrap();
+        assert_eq!(format!("{:?}", goal), "ForAll<type> { not { Vec<?0>: Copy } }");
+    });
+}
+
+#[test]
+fn ill_formed_impls() {
+    lowering_error! {
+        program {
+            trait Eq { }
+            trait Ord where Self: Eq { }
+            struct Foo { }
+            impl Ord for Foo { }
+        }
+        error_msg {
+            "impl of trait `Ord` is not well-formed: cannot prove `Foo: Eq`"
+        }
+    }
+
+    lowering_error! {
+        program {
+            trait Eq { }
+            trait Ord where Self: Eq { }
+            struct Vec<T> { }
+            impl<T> Eq for Vec<T> where T: Eq { }
+            impl<T> Ord for Vec<T> { }
+        }
+        error_msg {
+            "impl of trait `Ord` is not well-formed: cannot prove `Vec<?0>: Eq`"
+        }
+    }
+
+    lowering_error! {
+        program {
+            trait Clone { }
+            trait Collection { type Item; }
+            struct Vec<T> { }
+            impl<T> Collection for Vec<T> { type Item where T: Clone = T; }
+        }
+        error_msg {
+            "impl of trait `Collection` is not well-formed: cannot prove `?0: Clone`"
+        }
+    }
+
+    lowering_error! {
+        program {
+            trait Baz { }
+            trait Foo<T> where T: Baz { }
+            struct Bar { }
+            impl Foo<Bar> for Bar { }
+        }
+        error_msg {
+            "impl of trait `Foo` is not well-formed: cannot prove `Bar: Baz`"
+        }
+    }
+
+    parse_and_lower("trait Eq { } trait Ord where Self: Eq { } struct Vec<T> { }
+                     impl<T> Eq for Vec<T> where T: Eq { }
+                     impl<T> Ord for Vec<T> where T: Ord { }
+                     trait Clone { } trait Collection { type Item where Self: Clone; }
+                     impl<T> Collection for Vec<T> { type Item where Vec<T>: Clone = T; }").unwrap();
+}
+
+#[test]
+fn struct_fields() {
+    let program = Arc::new(parse_and_lower("struct Vec<T> { } struct Foo<T> { a: T, b: Vec<T> }").unwrap());
+    set_current_program(&program, || {
+        let foo_id = program.type_ids[&intern("Foo")];
+        let fields = &program.struct_data[&foo_id].binders.value.fields;
+        let fields_text = format!("{:?}", fields);
+        assert_eq!(fields_text, "[Field { name: \"a\", ty: ?0 }, Field { name: \"b\", ty: Vec<?0> }]");
+    });
+
+    lowering_error! {
+        program {
+            struct Foo { a: Foo, a: Foo }
+        }
+        error_msg {
+            "duplicate field `a` in struct `Foo`"
+        }
+    }
+}
+
+#[test]
+fn sized_lang_item() {
+    parse_and_lower("#[lang(sized)] trait Sized { } struct Foo<T> where T: ?Sized { }").unwrap();
+
+    lowering_error! {
+        program {
+            #[lang(sized)] trait Sized { }
+            trait Clone { }
+            struct Foo<T> where T: ?Clone { }
+        }
+        error_msg {
+            "`?Clone` bounds are only allowed for the `Sized` lang item"
+        }
+    }
+
+    lowering_error! {
+        program {
+            #[lang(sized)] trait Sized { }
+            #[lang(sized)] trait AlsoSized { }
+        }
+        error_msg {
+            "duplicate lang item `sized`"
+        }
+    }
+
+    lowering_error! {
+        program {
+            #[lang(copy)] trait Copy { }
+        }
+        error_msg {
+            "unknown lang item `copy`"
+        }
+    }
+}
+
+#[test]
+fn lang_items() {
+    let program = parse_and_lower("
+        #[lang(fn_once)] trait CallOnce<Args> { type Output; }
+        #[lang(fn_mut)] trait CallMut<Args> { }
+        #[lang(fn)] trait Call<Args> { }
+        #[lang(generator)] trait Resume<R> { type Yield; type Return; }
+        trait FnOnce<Args> { }
+    ").unwrap();
+
+    let lang_trait_name = |lang_item| program.type_kinds[&program.lang_items[&lang_item]].name;
+    assert_eq!(lang_trait_name(LangItem::FnOnce), intern("CallOnce"));
+    assert_eq!(lang_trait_name(LangItem::FnMut), intern("CallMut"));
+    assert_eq!(lang_trait_name(LangItem::Fn), intern("Call"));
+    assert_eq!(lang_trait_name(LangItem::Generator), intern("Resume"));
+    assert!(!program.lang_items.contains_key(&LangItem::Sized));
+
+    lowering_error! {
+        program {
+            #[lang(fn)] trait Fn<Args> { }
+            #[lang(fn)] trait AlsoFn<Args> { }
+        }
+        error_msg {
+            "duplicate lang item `fn`"
+        }
+    }
+}
+
+#[test]
+fn enums() {
+    parse_and_lower("enum Option<T> { None, Some { value: T } }").unwrap();
+
+    lowering_error! {
+        program {
+            enum Foo { Bar, Bar }
+        }
+        error_msg {
+            "duplicate variant `Bar` in enum `Foo`"
+        }
+    }
+
+    lowering_error! {
+        program {
+            enum Foo { Bar { a: Foo, a: Foo } }
+        }
+        error_msg {
+            "duplicate field `a` in variant `Foo::Bar`"
+        }
+    }
+}
+
+#[test]
+fn type_aliases() {
+    let program = Arc::new(parse_and_lower("
+            struct HashMap<K, V> { }
+            struct String { }
+            type Map<V> = HashMap<String, V>;
+            type Names = Map<String>;
+            struct Foo { names: Names }
+    ").unwrap());
+    set_current_program(&program, || {
+        let foo_id = program.type_ids[&intern("Foo")];
+        let fields = &program.struct_data[&foo_id].binders.value.fields;
+        assert_eq!(format!("{:?}", fields[0].ty), "HashMap<String, String>");
+
+        let goal = parse_and_lower_goal(&program, "forall<T> { Map<T> = HashMap<String, T> }").unwrap();
+        assert_eq!(format!("{:?}", goal), "ForAll<type> { (HashMap<String, ?0> = HashMap<String, ?0>) }");
+    });
+
+    lowering_error! {
+        program {
+            struct Vec<T> { }
+            type V<T> = Vec<T>;
+            struct Foo { v: V }
+        }
+        error_msg {
+            "`V` takes 1 type parameters, not 0"
+        }
+    }
+
+    lowering_error! {
+        program {
+            type A = B;
+            type B = A;
+        }
+        error_msg {
+            "invalid type name `B`"
+        }
+    }
+}
+
+#[test]
+fn assoc_ty_equality_bounds() {
+    let program = Arc::new(parse_and_lower("
+            trait Iterator { type Item; }
+            trait Foo { }
+            impl<T> Foo for T where T: Iterator<Item = u32> { }
+            struct Wrapper<T> where forall<'a> T: Iterator<Item = &'a u32> { }
+    ").unwrap());
+    set_current_program(&program, || {
+        let impl_datum = program.impl_data.values().next().unwrap();
+        assert_eq!(format!("{:?}", impl_datum.binders.value.where_clauses),
+                   "[?0: Iterator, <?0 as Iterator>::Item ==> u32]");
+
+        let wrapper_id = program.type_ids[&intern("Wrapper")];
+        let where_clauses = &program.struct_data[&wrapper_id].binders.value.where_clauses;
+        assert_eq!(format!("{:?}", where_clauses),
+                   "[for<lifetime> ?1: Iterator, for<lifetime> <?1 as Iterator>::Item ==> &'?0 u32]");
+    });
+}
+
+#[test]
+fn higher_ranked_where_clauses() {
+    let program = Arc::new(parse_and_lower("
+            trait Foo<'a> { }
+            struct Wrapper<T> where forall<'a> T: Foo<'a> { }
+    ").unwrap());
+    set_current_program(&program, || {
+        let wrapper_id = program.type_ids[&intern("Wrapper")];
+        let where_clauses = &program.struct_data[&wrapper_id].binders.value.where_clauses;
+        assert_eq!(format!("{:?}", where_clauses), "[for<lifetime> ?1: Foo<'?0>]");
+
+        let goal = parse_and_lower_goal(&program, "forall<T> { forall<'a> T: Foo<'a> }").unwrap();
+        assert_eq!(format!("{:?}", goal), "ForAll<type> { ForAll<lifetime> { ?1: Foo<'?0> } }");
+    });
+}
+
+#[test]
+fn variances() {
+    let program = Arc::new(parse_and_lower("
+            struct Unit { }
+            struct Box<T> { value: T }
+            struct Cell<T> { value: *mut T }
+            struct Sink<T> { f: fn(T) -> Unit }
+            struct Pair<'a, A, B> { first: &'a A, second: Sink<Sink<B>> }
+            struct Unused<T> { }
+            struct List<T> { head: T, tail: Option<Box<List<T>>> }
+            enum Option<T> { None, Some { value: T } }
+            struct Mixed<T> { a: Box<T>, b: Sink<T> }
+            struct Marker<T> { marker: PhantomData<T> }
+    ").unwrap());
+    let variances = program.variances();
+    let variances_of = |name: &str| format!("{:?}", variances[&program.type_ids[&intern(name)]]);
+    assert_eq!(variances_of("Box"), "[Covariant]");
+    assert_eq!(variances_of("Cell"), "[Invariant]");
+    assert_eq!(variances_of("Sink"), "[Contravariant]");
+    assert_eq!(variances_of("Pair"), "[Covariant, Covariant, Covariant]");
+    assert_eq!(variances_of("Unused"), "[Bivariant]");
+    assert_eq!(variances_of("List"), "[Covariant]");
+    assert_eq!(variances_of("Option"), "[Covariant]");
+    assert_eq!(variances_of("Mixed"), "[Invariant]");
+    assert_eq!(variances_of("Marker"), "[Covariant]");
+}
+
+#[test]
+fn default_type_parameters() {
+    let program = Arc::new(parse_and_lower("
+            struct i32 { }
+            struct Foo<T = i32> { }
+            struct Pair<A, B = A> { }
+            trait Add<Rhs = Self> { }
+            impl Add for i32 { }
+            struct Uses { foo: Foo, pair: Pair<Foo<Foo>> }
+    ").unwrap());
+    set_current_program(&program, || {
+        let uses_id = program.type_ids[&intern("Uses")];
+        let fields = &program.struct_data[&uses_id].binders.value.fields;
+        assert_eq!(format!("{:?}", fields[0].ty), "Foo<i32>");
+        assert_eq!(format!("{:?}", fields[1].ty), "Pair<Foo<Foo<i32>>, Foo<Foo<i32>>>");
+
+        let impl_datum = program.impl_data.values().next().unwrap();
+        assert_eq!(format!("{:?}", impl_datum.binders.value.trait_ref), "i32 as Add<i32>");
+    });
+
+    lowering_error! {
+        program {
+            struct Foo<A = i32, B> { }
+        }
+        error_msg {
+            "type parameters with defaults must come last in `Foo`"
+        }
+    }
+
+    lowering_error! {
+        program {
+            struct Foo<A, B = A> { }
+            struct Bar { foo: Foo }
+        }
+        error_msg {
+            "`Foo` takes 2 type parameters, not 0"
+        }
+    }
+}
+
+#[test]
+fn comments() {
+    let program = Arc::new(parse_and_lower("
+        // A trait, with a comment that mentions `struct` and `/*`.
+        trait Foo { }
+
+        /* A struct; /* block comments nest */ and this is still
+           part of the comment: impl Foo for Bar { } */
+        struct Bar { }
+        struct Baz { } // and a comment to end the line
+    ").unwrap());
+    assert!(program.impl_data.is_empty());
+    assert!(program.type_ids.contains_key(&intern("Baz")));
+
+    let goal = parse_and_lower_goal(&program, "Bar: Foo /* , Baz: Foo */").unwrap();
+    set_current_program(&program, || {
+        assert_eq!(format!("{:?}", goal), "Bar: Foo");
+    });
+
+    assert!(parse_and_lower("struct Bar { } /* unterminated").is_err());
+}
+
+#[test]
+fn error_location() {
+    let text = "
+        trait Serialize { }
+        struct Foo { }
+        impl Seralize for Foo { }
+    ";
+    let error = parse_and_lower(text).unwrap_err();
+    assert_eq!(error.located(text), "error at line 4, column 14: invalid type name `Seralize`");
+
+    let text = "struct Foo<A = i32, B> { }";
+    let error = parse_and_lower(text).unwrap_err();
+    assert_eq!(error.located(text),
+               "error at line 1, column 8: type parameters with defaults must come last in `Foo`");
+
+    let text = "#[auto] trait Send<T> { }";
+    let error = parse_and_lower(text).unwrap_err();
+    assert_eq!(error.located(text), "error at line 1, column 15: auto trait `Send` cannot have parameters");
+
+    // Errors about a where clause point at it...
+    let text = "struct Foo<T> where T: {integer} { }";
+    let error = parse_and_lower(text).unwrap_err();
+    assert_eq!(error.located(text), "error at line 1, column 21: this form of where-clause not allowed here");
+
+    // ...and errors about an impl at the impl.
+    let text = "
+        trait Foo { }
+        struct Bar { }
+        impl Foo for Bar { }
+        impl Foo for Bar { }
+    ";
+    let error = parse_and_lower(text).unwrap_err();
+    assert_eq!(error.located(text), "error at line 5, column 9: overlapping impls of trait `Foo`");
+
+    // Errors within a type point at the type...
+    let text = "struct Foo<'a> { } struct Bar { x: (u32, Foo<u32>) }";
+    let error = parse_and_lower(text).unwrap_err();
+    assert_eq!(error.located(text),
+               "error at line 1, column 42: incorrect parameter kind: expected lifetime, found type");
+
+    // ...and within a trait ref at the trait ref.
+    let text = "trait Foo { } struct Bar { } impl Foo<Bar> for Bar { }";
+    let error = parse_and_lower(text).unwrap_err();
+    assert_eq!(error.located(text), "error at line 1, column 35: wrong number of parameters, expected `1`, got `2`");
+}
+
+#[test]
+fn attributes() {
+    let program = Arc::new(parse_and_lower("
+        #[auto] trait Send { }
+        #[marker] #[lang(sized)] trait Sized { }
+        #[coinductive] #[marker] trait Foo { }
+        #[fundamental] struct Box<T> { }
+    ").unwrap());
+    let send_id = program.type_ids[&intern("Send")];
+    assert!(program.trait_data[&send_id].binders.value.flags.auto);
+    let sized_id = program.type_ids[&intern("Sized")];
+    assert!(program.trait_data[&sized_id].binders.value.flags.sized);
+    let foo_id = program.type_ids[&intern("Foo")];
+    let foo_flags = program.trait_data[&foo_id].binders.value.flags;
+    assert!(foo_flags.marker && foo_flags.coinductive);
+
+    // `#[upstream]` is an attribute like any other, and `upstream` a
+    // name like any other.
+    let program = Arc::new(parse_and_lower("
+        #[marker] #[upstream] trait Foo { }
+        struct upstream { }
+        impl Foo for upstream { }
+    ").unwrap());
+    let foo_id = program.type_ids[&intern("Foo")];
+    assert_eq!(program.type_kinds[&foo_id].krate_id.name, intern("upstream"));
+    assert!(program.trait_data[&foo_id].binders.value.flags.marker);
+    let upstream_id = program.type_ids[&intern("upstream")];
+    assert_eq!(program.type_kinds[&upstream_id].krate_id.name, intern("krate"));
+
+    lowering_error! {
+        program {
+            #[fundamental] trait Foo { }
+        }
+        error_msg {
+            "unknown attribute `#[fundamental]`"
+        }
+    }
+
+    lowering_error! {
+        program {
+            trait Foo { }
+            struct Bar { }
+            #[inline] impl Foo for Bar { }
+        }
+        error_msg {
+            "unknown attribute `#[inline]`"
+        }
+    }
+
+    lowering_error! {
+        program {
+            #[marker(always)] trait Foo { }
+        }
+        error_msg {
+            "attribute `#[marker]` takes 0 arguments, not 1"
+        }
+    }
+}
+
+#[test]
+fn multiple_sources() {
+    let prelude = "
+        #[marker] #[lang(sized)] trait Sized { }
+        trait Clone { }
+    ";
+    let program = Arc::new(chalk_parse::parse_programs(&[prelude, "
+        struct Foo { }
+        impl Clone for Foo { }
+    "]).unwrap().lower().unwrap());
+    assert!(program.type_ids.contains_key(&intern("Sized")));
+
+    let goal = parse_and_lower_goal(&program, "Foo: Clone").unwrap();
+    set_current_program(&program, || {
+        assert_eq!(format!("{:?}", goal), "Foo: Clone");
+    });
+
+    let error = chalk_parse::parse_programs(&[prelude, "struct { }"]).unwrap_err();
+    assert!(error.to_string().starts_with("parse error in source 1"));
+
+    // Errors in lowering point into the source they were found in.
+    let text = "struct Foo { } impl Clon for Foo { }";
+    let error = chalk_parse::parse_programs(&[prelude, text]).unwrap().lower().unwrap_err();
+    assert_eq!(error.located_in(&[prelude, text]),
+               "error in source 1 at line 1, column 21: invalid type name `Clon`");
+}
+
+#[test]
+fn parse_error_recovery() {
+    let error = chalk_parse::parse_program("
+        struct Foo { field: }
+        trait Bar { }
+        impl Bar for Baz Baz { }
+        struct Baz { }
+    ").unwrap_err().to_string();
+    let messages: Vec<_> = error.lines().collect();
+    assert_eq!(messages.len(), 2);
+    assert!(messages[0].starts_with("parse error at line 2, column 29: unexpected token `}`"));
+    assert!(messages[1].starts_with("parse error at line 4, column 26: unexpected token `Baz`"));
+}
+
+#[test]
+fn print_program() {
+    let text = "
+        #[lang(sized)] #[marker] trait Sized { }
+        auto trait Send { }
+        trait Iterator: Sized where Self: Send {
+            type Item = u32;
+            const N;
+        }
+        #[fundamental] struct Own<T> { value: T }
+        enum Option<T> { None, Some { value: T } }
+        default unsafe impl<'a, T> Iterator for &'a mut [T; 3] where T: Iterator<Item = (T,)> {
+            default type Item = fn(T) -> !;
+            const N = 1;
+        }
+        impl !Send for *const () { }
+        crate foo { struct Foo { } }
+        #[upstream] struct Bar { }
+        fn foo<T>(T) -> dyn Iterator<Item = u32> where forall<'b> &'b T: Send;
+        type Alias<T> = <T as Iterator>::Item;
+        trait Both = Send + Sized;
+        trait Add<Rhs = Self> { }
+    ";
+    let printed = chalk_parse::parse_program(text).unwrap().to_string();
+    let lines: Vec<_> = printed.lines().collect();
+    assert_eq!(lines[2],
+               "trait Iterator: Sized where Self: Send { type Item = u32; const N; }");
+    assert_eq!(lines[5],
+               "default unsafe impl<'a, T> Iterator for &'a mut [T; 3] where T: Iterator<Item = (T,)> \
+                { default type Item = fn(T) -> !; const N = 1; }");
+    assert_eq!(lines[7], "crate foo { struct Foo { } }");
+    assert_eq!(lines[8], "#[upstream] struct Bar { }");
+
+    // Printing what was printed changes nothing, and the program means
+    // the same as before.
+    let reparsed = chalk_parse::parse_program(&printed).unwrap();
+    assert_eq!(reparsed.to_string(), printed);
+    reparsed.lower().unwrap();
+}
+
+#[test]
+fn raw_identifiers() {
+    let program = Arc::new(parse_and_lower("
+        trait r#type { }
+        struct r#struct { r#fn: r#struct }
+        impl r#type for r#struct { }
+    ").unwrap());
+    assert!(program.type_ids.contains_key(&intern("struct")));
+
+    let goal = parse_and_lower_goal(&program, "r#struct: r#type").unwrap();
+    set_current_program(&program, || {
+        assert_eq!(format!("{:?}", goal), "struct: type");
+    });
+
+    let printed = chalk_parse::parse_program("struct r#struct { r#fn: r#struct }").unwrap().to_string();
+    assert_eq!(printed, "struct r#struct { r#fn: r#struct }\n");
+}
@@ -0,0 +1,169 @@
+//! Well-formedness checking of impls, done once the program has been
+//! lowered.
+
+use cast::Cast;
+use chalk_parse::ast;
+use errors::*;
+use fold::{Shifter, Subst};
+use ir::*;
+use solve::goal::Prove;
+use solve::solver::Solver;
+use solve::Successful;
+use std::sync::Arc;
+use super::associated_ty_parameters;
+
+/// Checks that every impl is `unsafe` exactly when it must be, that
+/// every positive impl meets the where clauses of the trait it
+/// implements (including its supertraits), and that none of its
+/// associated type values have stricter where clauses than their
+/// declarations in the trait.
+pub fn check_wf(program: &Program) -> Result<()> {
+    let program = Arc::new(program.clone());
+    let env: Arc<ChalkDatabase> = Arc::new(program.environment());
+
+    let mut impl_ids: Vec<_> = program.impl_data.keys().cloned().collect();
+    impl_ids.sort();
+
+    set_current_program(&program, || {
+        for impl_id in impl_ids {
+            let impl_datum = &program.impl_data[&impl_id];
+            let span = program.impl_spans[&impl_id];
+            check_impl_safety(&program, impl_datum, span)?;
+            if !impl_datum.binders.value.trait_ref.is_positive() {
+                continue;
+            }
+            check_impl_header(&program, &env, impl_datum, span)?;
+            for atv in &impl_datum.binders.value.associated_ty_values {
+                check_associated_ty_value(&program, &env, impl_datum, atv, span)?;
+            }
+        }
+        Ok(())
+    })
+}
+
+/// A positive impl must be an `unsafe impl` if and only if its trait
+/// is an `unsafe trait`. Negative impls promise nothing, so they are
+/// never `unsafe`. Errors point at `span`, where the impl was written.
+fn check_impl_safety(program: &Program, impl_datum: &ImplDatum, span: ast::Span) -> Result<()> {
+    let trait_id = impl_datum.binders.value.trait_ref.trait_ref().trait_id;
+    let trait_name = program.type_kinds[&trait_id].name;
+    let trait_is_unsafe = program.trait_data[&trait_id].binders.value.flags.is_unsafe;
+
+    if !impl_datum.binders.value.trait_ref.is_positive() {
+        if impl_datum.is_unsafe {
+            bail!(ErrorKind::UnsafeNegativeImpl(trait_name, span));
+        }
+    } else if trait_is_unsafe && !impl_datum.is_unsafe {
+        bail!(ErrorKind::SafeImplOfUnsafeTrait(trait_name, span));
+    } else if !trait_is_unsafe && impl_datum.is_unsafe {
+        bail!(ErrorKind::UnsafeImplOfSafeTrait(trait_name, span));
+    }
+
+    Ok(())
+}
+
+/// Given:
+///
+/// ```notrust
+/// trait Ord where Self: Eq { }
+/// impl<T> Ord for Vec<T> where T: Ord { }
+/// ```
+///
+/// proves:
+///
+/// ```notrust
+/// forall<T> { if (T: Ord) { Vec<T>: Eq } }
+/// ```
+fn check_impl_header(program: &Program,
+                     env: &Arc<ChalkDatabase>,
+                     impl_datum: &ImplDatum,
+                     span: ast::Span)
+                     -> Result<()> {
+    let trait_ref = impl_datum.binders.value.trait_ref.trait_ref();
+    let trait_datum = &program.trait_data[&trait_ref.trait_id];
+    let parameters = trait_ref.binder_parameters();
+
+    for wc in &trait_datum.binders.value.where_clauses {
+        let obligation = Subst::apply(&parameters, wc);
+        let goal = hypothetically(impl_datum.binders.binders.clone(),
+                                  impl_datum.binders.value.where_clauses.clone(),
+                                  obligation.clone());
+        if !provable(env, goal) {
+            bail!(ErrorKind::IllFormedImpl(program.type_kinds[&trait_ref.trait_id].name,
+                                           format!("{:?}", obligation),
+                                           span));
+        }
+    }
+
+    Ok(())
+}
+
+/// Given:
+///
+/// ```notrust
+/// trait Iterable { type Iter<'a> where Self: 'a; }
+/// impl<T> Iterable for Vec<T> { type Iter<'a> where T: 'a = Iter<'a, T>; }
+/// ```
+///
+/// proves:
+///
+/// ```notrust
+/// forall<'a, T> { if (Vec<T>: Iterable, Vec<T>: 'a) { T: 'a } }
+/// ```
+fn check_associated_ty_value(program: &Program,
+                             env: &Arc<ChalkDatabase>,
+                             impl_datum: &ImplDatum,
+                             atv: &AssociatedTyValue,
+                             span: ast::Span)
+                             -> Result<()> {
+    let associated_ty_datum = &program.associated_ty_data[&atv.associated_ty_id];
+    let trait_ref = impl_datum.binders.value.trait_ref.trait_ref();
+
+    // The value's own binders come first, then those of the impl.
+    let binders: Vec<_> =
+        atv.value.binders
+                 .iter()
+                 .cloned()
+                 .chain(impl_datum.binders.binders.iter().cloned())
+                 .collect();
+
+    let parameters = associated_ty_parameters(&atv.value.binders, trait_ref);
+    let hypotheses: Vec<_> =
+        impl_datum.binders.value.where_clauses
+                                .iter()
+                                .map(|wc| Shifter::up_shift(atv.value.len(), wc))
+                                .chain(associated_ty_datum.where_clauses
+                                                          .iter()
+                                                          .map(|wc| Subst::apply(&parameters, wc)))
+                                .collect();
+
+    for wc in &atv.value.value.where_clauses {
+        let goal = hypothetically(binders.clone(), hypotheses.clone(), wc.clone());
+        if !provable(env, goal) {
+            bail!(ErrorKind::IllFormedImpl(program.type_kinds[&trait_ref.trait_id].name,
+                                           format!("{:?}", wc),
+                                           span));
+        }
+    }
+
+    Ok(())
+}
+
+/// `forall<binders> { if (hypotheses) { obligation } }`
+fn hypothetically(binders: Vec<ParameterKind<()>>,
+                  hypotheses: Vec<WhereClause>,
+                  obligation: WhereClause)
+                  -> Goal {
+    Goal::Quantified(QuantifierKind::ForAll, Binders {
+        binders: binders,
+        value: Box::new(Goal::Implies(hypotheses, Box::new(obligation.cast()))),
+    })
+}
+
+fn provable(env: &Arc<ChalkDatabase>, goal: Goal) -> bool {
+    let mut solver = Solver::new(env, 10);
+    match Prove::new(&mut solver, Box::new(goal)).solve() {
+        Ok(solution) => solution.successful() == Successful::Yes,
+        Err(_) => false,
+    }
+}
@@ -0,0 +1,128 @@
+//! A breadth-first search for proofs.
+//!
+//! The recursive solver follows the first clause that might prove a
+//! goal as deep as it goes before trying the next, and gives up on
+//! any proof deeper than its overflow depth. Here, instead, every
+//! partial proof (a *strand*, as in the SLG solver) is taken one step
+//! further in turn, so the shallowest proofs are found first however
+//! deep the others go, and how deep a proof may be is bounded only by
+//! how many steps we are willing to take overall.
+//!
+//! As in the SLG solver, only `Implemented` goals are searched this
+//! way; the rest are handed to the recursive solver.
+
+use errors::*;
+use fold::Fold;
+use ir::*;
+use solve::Solution;
+use solve::implemented::Implemented;
+use solve::infer::InferenceTable;
+use solve::slg::{answers_solution, clause_strands, Answer, Strand};
+use solve::solver::Solver;
+use solve::subsumption::add_answer;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Instant;
+
+pub struct BreadthFirstSolver {
+    /// Solves the goals that are not searched breadth-first.
+    solver: Solver,
+
+    /// How many steps the search may take; if there are strands left
+    /// after that, the goal is ambiguous.
+    max_steps: usize,
+}
+
+impl BreadthFirstSolver {
+    pub fn new(program: &Arc<ChalkDatabase>, max_steps: usize) -> Self {
+        BreadthFirstSolver {
+            solver: Solver::new(program, max_steps),
+            max_steps,
+        }
+    }
+
+    /// Gives up on solving once `deadline` has passed; see
+    /// `Solver::set_deadline`.
+    pub fn set_deadline(&mut self, deadline: Option<Instant>) {
+        self.solver.set_deadline(deadline);
+    }
+
+    pub fn solve(&mut self, goal: Box<Goal>) -> Result<Solution<Vec<WhereClauseGoal>>> {
+        debug_heading!("BreadthFirstSolver::solve({:?})", goal);
+
+        let mut strand = Strand::new(InferenceTable::new());
+        strand.push_goal(*goal, &Environment::new());
+        let goals: Vec<_> = strand.leaves.iter().map(|leaf| leaf.goal.clone()).collect();
+        let start_goal = strand.infer.canonicalize(&goals).quantified;
+
+        let mut strands = VecDeque::new();
+        strands.push_back(strand);
+        let mut answers = vec![];
+        let mut steps = 0;
+        while let Some(strand) = strands.pop_front() {
+            if steps == self.max_steps {
+                debug!("BreadthFirstSolver::solve: out of steps");
+                strands.push_front(strand);
+                break;
+            }
+            steps += 1;
+            self.solver.check_deadline()?;
+
+            if let Some(answer) = self.step(strand, &goals, &mut strands) {
+                // As in `solve_any`, an answer that holds without
+                // refining the goal settles it, whatever else we
+                // might find.
+                let solution = answer.clone().into_solution();
+                if solution.is_unconditional_for(&start_goal) {
+                    return self.solver.or_timed_out(Ok(solution));
+                }
+                add_answer(&mut answers, answer, Answer::subsumes);
+            }
+        }
+
+        debug!("BreadthFirstSolver::solve: answers={:#?}", answers);
+
+        // The strands we never finished may have led to more answers.
+        if !strands.is_empty() {
+            if answers.is_empty() {
+                return self.solver.or_timed_out(Ok(Solution::maybe(start_goal)));
+            }
+            for answer in &mut answers {
+                answer.ambiguous = true;
+            }
+        }
+        self.solver.or_timed_out(answers_solution(&start_goal, answers))
+    }
+
+    /// Works through the subgoals of `strand` that the recursive
+    /// solver takes care of, up to the next trait goal, which is
+    /// resolved against each clause that might prove it; the strands
+    /// that result are queued up to be taken further later. Returns
+    /// the answer to `goal` if that leaves the strand with nothing
+    /// more to prove.
+    fn step<G>(&mut self, mut strand: Strand, goal: &G, strands: &mut VecDeque<Strand>)
+               -> Option<Answer<G::Result>>
+        where G: Fold
+    {
+        while let Some(leaf) = strand.next_leaf() {
+            if let WhereClauseGoal::Implemented(ref trait_ref) = leaf.goal {
+                let env_goal = strand.infer.canonicalize(&InEnvironment::new(&leaf.environment,
+                                                                             trait_ref.clone()))
+                                           .quantified;
+                if Implemented::blocked_by_negative_impl(&mut self.solver, &env_goal) {
+                    return None;
+                }
+
+                let program = self.solver.program.clone();
+                strands.extend(clause_strands(&*program, &strand, &leaf.environment, trait_ref));
+                return None;
+            }
+
+            if !strand.solve_leaf(&mut self.solver, leaf) {
+                return None;
+            }
+        }
+
+        strand.into_answer(&mut self.solver, goal)
+    }
+}
@@ -0,0 +1,30 @@
+use errors::*;
+use ir::*;
+use solve::Solution;
+use solve::proof_tree::ProofClause;
+use std::fmt;
+
+/// Receives notifications of the steps the solver takes, so that they
+/// can be logged, counted or visualized. Each method does nothing by
+/// default. See `Solver::set_delegate`.
+pub trait SolverDelegate {
+    /// The solver is about to solve `goal`.
+    fn enter_goal(&mut self, _goal: &Canonical<InEnvironment<WhereClauseGoal>>) { }
+
+    /// The solver is done with `goal`, whose result is `result`.
+    fn exit_goal(&mut self,
+                 _goal: &Canonical<InEnvironment<WhereClauseGoal>>,
+                 _result: &Result<Solution<InEnvironment<WhereClauseGoal>>>) { }
+
+    /// The solver is about to try proving the goal it is working on
+    /// with `clause`.
+    fn select_candidate(&mut self, _clause: &ProofClause) { }
+
+    /// `clause` did not prove the goal after all, and the solver has
+    /// undone what it learned from it.
+    fn backtrack(&mut self, _clause: &ProofClause) { }
+
+    /// The solver unified `a` with `b`; `succeeded` is false if the
+    /// two did not unify.
+    fn unify(&mut self, _a: fmt::Arguments, _b: fmt::Arguments, _succeeded: bool) { }
+}
@@ -1,11 +1,13 @@
 use errors::*;
 use fold::Fold;
 use ir::*;
-use solve::Successful;
-use solve::infer::{InferenceTable, UnificationResult, ParameterInferenceVariable};
+use solve::{Solution, Successful};
+use solve::infer::{InferenceTable, InferenceSnapshot, UnificationResult, ParameterInferenceVariable};
 use solve::solver::Solver;
+use solve::subsumption::add_answer;
 use std::collections::HashSet;
 use std::fmt::Debug;
+use std::mem;
 use std::sync::Arc;
 use zip::Zip;
 
@@ -13,15 +15,35 @@ pub struct Fulfill<'s> {
     solver: &'s mut Solver,
     infer: InferenceTable,
     obligations: Vec<InEnvironment<WhereClauseGoal>>,
+
+    /// Goals from `not { ... }`, which are only checked once the
+    /// other obligations have determined all the variables they
+    /// mention.
+    negative_obligations: Vec<InEnvironment<Goal>>,
+    constraints: HashSet<InEnvironment<Constraint>>,
+}
+
+/// The state of a `Fulfill` at some point, which `rollback_to` can
+/// return it to.
+pub struct FulfillSnapshot {
+    infer: InferenceSnapshot,
+    obligations: Vec<InEnvironment<WhereClauseGoal>>,
+    negative_obligations: Vec<InEnvironment<Goal>>,
     constraints: HashSet<InEnvironment<Constraint>>,
 }
 
 impl<'s> Fulfill<'s> {
     pub fn new(solver: &'s mut Solver, infer: InferenceTable) -> Self {
-        Fulfill { solver, infer, obligations: vec![], constraints: HashSet::new() }
+        Fulfill {
+            solver,
+            infer,
+            obligations: vec![],
+            negative_obligations: vec![],
+            constraints: HashSet::new(),
+        }
     }
 
-    pub fn program(&self) -> Arc<ProgramEnvironment> {
+    pub fn program(&self) -> Arc<ChalkDatabase> {
         self.solver.program.clone()
     }
 
@@ -56,7 +78,12 @@ impl<'s> Fulfill<'s> {
     pub fn unify<T>(&mut self, environment: &Arc<Environment>, a: &T, b: &T) -> Result<()>
         where T: ?Sized + Zip + Debug
     {
-        let UnificationResult { goals, constraints } = self.infer.unify(environment, a, b)?;
+        let result = self.infer.unify(environment, a, b);
+        let succeeded = result.is_ok();
+        self.solver.notify(|delegate| {
+            delegate.unify(format_args!("{:?}", a), format_args!("{:?}", b), succeeded)
+        });
+        let UnificationResult { goals, constraints } = result?;
         debug!("unify({:?}, {:?}) succeeded", a, b);
         debug!("unify: goals={:?}", goals);
         debug!("unify: constraints={:?}", constraints);
@@ -65,6 +92,16 @@ impl<'s> Fulfill<'s> {
         Ok(())
     }
 
+    /// Records a region constraint that must hold for the goal to be
+    /// proven; these are not solved, but returned in the solution.
+    /// `'static: 'a` always holds, so it is dropped rather than recorded.
+    pub fn add_constraint(&mut self, environment: &Arc<Environment>, constraint: Constraint) {
+        if let Constraint::LifetimeOutlives(Lifetime::Static, _) = constraint {
+            return;
+        }
+        self.constraints.insert(InEnvironment::new(environment, constraint));
+    }
+
     /// Wraps `InferenceTable::new_parameter_variable`
     pub fn new_parameter_variable(&mut self, ui: ParameterKind<UniverseIndex>)
                                   -> ParameterInferenceVariable {
@@ -91,28 +128,7 @@ impl<'s> Fulfill<'s> {
         debug!("push_goal({:?}, {:?})", goal, environment);
         match goal {
             Goal::Quantified(QuantifierKind::ForAll, subgoal) => {
-                let mut new_environment = environment.clone();
-                let parameters: Vec<_> =
-                    subgoal.binders
-                           .iter()
-                           .map(|pk| {
-                               new_environment = new_environment.new_universe();
-                               match *pk {
-                                   ParameterKind::Lifetime(()) =>
-                                       ParameterKind::Lifetime(Lifetime::ForAll(new_environment.universe)),
-
-                                   ParameterKind::Ty(()) =>
-                                       ParameterKind::Ty(Ty::Apply(ApplicationTy {
-                                           name: TypeName::ForAll(new_environment.universe),
-                                           parameters: vec![]
-                                       })),
-
-                                   ParameterKind::Krate(()) =>
-                                       panic!("unimplemented: for-all binders with crates"),
-                               }
-                           })
-                           .collect();
-                let subgoal = subgoal.value.subst(&parameters);
+                let (new_environment, subgoal) = instantiate_universally(environment, &subgoal);
                 self.push_goal(subgoal, &new_environment);
             }
             Goal::Quantified(QuantifierKind::Exists, subgoal) => {
@@ -129,6 +145,9 @@ impl<'s> Fulfill<'s> {
                 self.push_goal(*subgoal1, environment);
                 self.push_goal(*subgoal2, environment);
             }
+            Goal::Not(subgoal) => {
+                self.negative_obligations.push(InEnvironment::new(environment, *subgoal));
+            }
             Goal::Leaf(wc) => {
                 self.obligations.push(InEnvironment::new(environment, wc));
             }
@@ -139,15 +158,40 @@ impl<'s> Fulfill<'s> {
     /// back a "refined goal" that shows what we learned. This refined
     /// goal combines any lifetime constraints with the final results
     /// of inference. It is produced by this method.
-    pub fn refine_goal<G: Fold>(mut self, goal: G) -> Query<Constrained<G::Result>> {
-        let mut constraints: Vec<_> = self.constraints.into_iter().collect();
+    pub fn refine_goal<G: Fold>(mut self, goal: G) -> Canonical<Constrained<G::Result>> {
+        self.constrained_query(goal)
+    }
+
+    /// Like `refine_goal`, but for a goal whose obligations may not all
+    /// have been solved: those that are left, positive and negative,
+    /// are handed back as the goals the refined goal is conditional on.
+    pub fn conditional_goal<G: Fold>(mut self, goal: G) -> Canonical<Conditional<G::Result>> {
+        let mut constraints: Vec<_> = self.constraints.iter().cloned().collect();
+        constraints.sort();
+        let goals: Vec<_> =
+            self.obligations.iter()
+                            .map(|wc| wc.clone().map(Goal::Leaf))
+                            .chain(self.negative_obligations.iter()
+                                                            .map(|g| g.clone().map(|g| Goal::Not(Box::new(g)))))
+                            .collect();
+        debug!("conditional_goal: constraints = {:?}, goals = {:?}", constraints, goals);
+        let conditional_goal = Conditional {
+            value: goal,
+            constraints: constraints,
+            goals: goals,
+        };
+        self.infer.canonicalize(&conditional_goal).quantified
+    }
+
+    fn constrained_query<G: Fold>(&mut self, goal: G) -> Canonical<Constrained<G::Result>> {
+        let mut constraints: Vec<_> = self.constraints.iter().cloned().collect();
         constraints.sort();
         debug!("refine_goal: constraints = {:?}", constraints);
         let constrained_goal = Constrained {
             value: goal,
             constraints: constraints,
         };
-        self.infer.make_query(&constrained_goal)
+        self.infer.canonicalize(&constrained_goal).quantified
     }
 
     /// Try to solve all of `where_clauses`, which may contain
@@ -198,10 +242,21 @@ impl<'s> Fulfill<'s> {
         // be empty.
         assert!(obligations.is_empty());
 
+        // Now that we know all we're going to learn about the
+        // inference variables, check the negative goals.
+        let mut negative_successful = Successful::Yes;
+        for goal in mem::replace(&mut self.negative_obligations, vec![]) {
+            let successful = self.solve_negative(&goal)?;
+            negative_successful = negative_successful.and(successful);
+            if successful == Successful::Maybe {
+                self.negative_obligations.push(goal);
+            }
+        }
+
         // If we still have ambiguous where-clauses, then we have an
         // ambiguous overall result.
         if self.obligations.is_empty() {
-            Ok(Successful::Yes)
+            Ok(negative_successful)
         } else {
             debug!("still have {} ambiguous obligations: {:#?}",
                    self.obligations.len(), self.obligations);
@@ -209,13 +264,185 @@ impl<'s> Fulfill<'s> {
         }
     }
 
+    /// Defaults the type of each `T: {integer}` or `T: {float}`
+    /// obligation left over by `solve_all` to `i32` or `f64`, as rustc
+    /// does for a numeric literal whose type nothing else decides.
+    /// Only obligations on an inference variable that is still unbound
+    /// are defaulted; one on a projection like `<T as Iterator>::Item`
+    /// stays ambiguous, since the projection may yet normalize to some
+    /// other scalar. Returns true if there were any to default, in
+    /// which case the obligations are worth solving again.
+    pub fn default_numeric_vars(&mut self) -> Result<bool> {
+        let numerics: Vec<_> =
+            self.obligations.iter()
+                            .filter_map(|wc| match wc.goal {
+                                WhereClauseGoal::Numeric(ref numeric) => {
+                                    Some((wc.environment.clone(), numeric.clone()))
+                                }
+                                _ => None,
+                            })
+                            .collect();
+        let numerics: Vec<_> =
+            numerics.into_iter()
+                    .filter(|&(_, ref numeric)| {
+                        match self.infer.canonicalize(&numeric.ty).quantified.value {
+                            Ty::Var(_) => true,
+                            _ => false,
+                        }
+                    })
+                    .collect();
+        for &(ref environment, ref numeric) in &numerics {
+            debug!("default_numeric_vars: defaulting {:?}", numeric);
+            let default = Ty::Apply(ApplicationTy {
+                name: TypeName::Scalar(numeric.kind.default_scalar()),
+                parameters: vec![],
+            });
+            self.unify(environment, &numeric.ty, &default)?;
+        }
+        Ok(!numerics.is_empty())
+    }
+
+    pub fn snapshot(&mut self) -> FulfillSnapshot {
+        FulfillSnapshot {
+            infer: self.infer.snapshot(),
+            obligations: self.obligations.clone(),
+            negative_obligations: self.negative_obligations.clone(),
+            constraints: self.constraints.clone(),
+        }
+    }
+
+    pub fn rollback_to(&mut self, snapshot: FulfillSnapshot) {
+        self.infer.rollback_to(snapshot.infer);
+        self.obligations = snapshot.obligations;
+        self.negative_obligations = snapshot.negative_obligations;
+        self.constraints = snapshot.constraints;
+    }
+
+    /// Like `solve_all`, but rather than settling for an ambiguous
+    /// result, splits on the candidates for the first ambiguous
+    /// obligation and explores each of them in turn. Every distinct
+    /// way of refining `goal` that this finds is pushed onto
+    /// `answers`, until there are `limit` of them. A branch that
+    /// can't be split any further is recorded as an ambiguous answer.
+    pub fn solve_answers<G>(&mut self,
+                            goal: &G,
+                            limit: usize,
+                            answers: &mut Vec<Solution<G::Result>>)
+                            -> Result<()>
+        where G: Fold + Clone, G::Result: Zip + Fold<Result = G::Result> + Clone + Debug + PartialEq
+    {
+        self.solve_answers_at(goal, limit, 0, answers)
+    }
+
+    fn solve_answers_at<G>(&mut self,
+                           goal: &G,
+                           limit: usize,
+                           depth: usize,
+                           answers: &mut Vec<Solution<G::Result>>)
+                           -> Result<()>
+        where G: Fold + Clone, G::Result: Zip + Fold<Result = G::Result> + Clone + Debug + PartialEq
+    {
+        debug_heading!("solve_answers(depth={}, {} answers so far)", depth, answers.len());
+
+        if answers.len() >= limit {
+            return Ok(());
+        }
+
+        let successful = self.solve_all()?;
+        let solution = Solution {
+            successful: successful,
+            refined_goal: self.constrained_query(goal.clone()),
+        };
+
+        let (obligation, candidates) = match self.split(successful, depth)? {
+            Some(split) => split,
+            None => {
+                add_answer(answers, solution, Solution::subsumes);
+                return Ok(());
+            }
+        };
+
+        for candidate in candidates {
+            let snapshot = self.snapshot();
+
+            // A candidate that turns out not to work out only rules
+            // out its own branch.
+            if self.apply_candidate(&obligation, &candidate).is_ok() {
+                let _ = self.solve_answers_at(goal, limit, depth + 1, answers);
+            }
+            self.rollback_to(snapshot);
+        }
+
+        Ok(())
+    }
+
+    /// Like `solve_answers`, but finds the answers one at a time, as
+    /// they are asked for: a caller who only wants the first few need
+    /// not wait for the search to find the rest.
+    pub fn answers<G>(self, goal: G) -> Answers<'s, G>
+        where G: Fold + Clone, G::Result: Zip + Fold<Result = G::Result> + Clone + Debug + PartialEq
+    {
+        Answers {
+            fulfill: self,
+            goal: goal,
+            started: false,
+            frames: vec![],
+            answers: vec![],
+        }
+    }
+
+    /// Given the result of `solve_all`, picks the obligation to split
+    /// on in looking for answers, with its candidates: the first
+    /// obligation left, if the result is ambiguous. Each candidate has
+    /// to tell us something new, or exploring it would get us
+    /// nowhere; if they don't, or the search is already `depth` deep,
+    /// there is no splitting to be done.
+    fn split(&mut self, successful: Successful, depth: usize)
+             -> Result<Option<(InEnvironment<WhereClauseGoal>,
+                               Vec<Solution<InEnvironment<WhereClauseGoal>>>)>> {
+        let obligation = match self.obligations.first() {
+            Some(obligation) => obligation.clone(),
+            None => return Ok(None),
+        };
+        if successful == Successful::Yes || depth >= self.solver.overflow_depth() {
+            return Ok(None);
+        }
+
+        let query = self.infer.canonicalize(&obligation).quantified;
+        let candidates = self.solver.solve_candidates(query.clone())?;
+        let all_refine = candidates.iter().all(|c| {
+            c.refined_goal.binders != query.binders || c.refined_goal.value.value != query.value
+        });
+        if candidates.len() < 2 || !all_refine {
+            return Ok(None);
+        }
+        Ok(Some((obligation, candidates)))
+    }
+
+    /// Commits to `candidate` as the way `obligation` holds.
+    fn apply_candidate(&mut self,
+                       obligation: &InEnvironment<WhereClauseGoal>,
+                       candidate: &Solution<InEnvironment<WhereClauseGoal>>)
+                       -> Result<()> {
+        let Constrained { constraints, value: refined_obligation } =
+            self.infer.instantiate_canonical(&candidate.refined_goal);
+        self.constraints.extend(constraints);
+        self.unify(&obligation.environment, obligation, &refined_obligation)
+    }
+
+    fn solve_negative(&mut self, goal: &InEnvironment<Goal>) -> Result<Successful> {
+        debug!("fulfill::solve_negative(goal={:?})", goal);
+        let canonical = self.infer.canonicalize(goal).quantified;
+        solve_negative(self.solver, &canonical)
+    }
+
     fn solve_one(&mut self,
                  wc: &InEnvironment<WhereClauseGoal>,
                  inference_progress: &mut bool)
                  -> Result<Successful> {
         debug!("fulfill::solve_one(wc={:?})", wc);
 
-        let quantified_wc = self.infer.make_query(&wc);
+        let quantified_wc = self.infer.canonicalize(&wc).quantified;
         let solution = self.solver.solve(quantified_wc.clone())?;
 
         // Regardless of whether the result is ambiguous or not,
@@ -247,8 +474,7 @@ impl<'s> Fulfill<'s> {
 
         if new_type_info || !solution.refined_goal.value.constraints.is_empty() {
             let Constrained { constraints, value: refined_goal } =
-                self.instantiate(solution.refined_goal.binders.iter().cloned(),
-                                 &solution.refined_goal.value);
+                self.infer.instantiate_canonical(&solution.refined_goal);
 
             debug!("fulfill::solve_one: adding constraints {:?}", constraints);
             self.constraints.extend(constraints);
@@ -264,3 +490,177 @@ impl<'s> Fulfill<'s> {
         Ok(solution.successful)
     }
 }
+
+/// The answers to a goal, found as they are asked for; see
+/// `Fulfill::answers`. The search is that of `Fulfill::solve_answers`,
+/// with the obligations split on kept in a stack rather than on the
+/// call stack, so that it can stop after each answer and pick up where
+/// it left off.
+///
+/// As the answers are handed out one at a time, an answer can only be
+/// checked against those found before it: one that a later answer
+/// subsumes is not taken back.
+pub struct Answers<'s, G: Fold> {
+    fulfill: Fulfill<'s>,
+    goal: G,
+
+    /// Whether the goal has been explored as it stands, before
+    /// splitting on any of its obligations.
+    started: bool,
+
+    /// The obligations split on so far, innermost last.
+    frames: Vec<AnswerFrame>,
+
+    /// The answers handed out so far.
+    answers: Vec<Solution<G::Result>>,
+}
+
+struct AnswerFrame {
+    obligation: InEnvironment<WhereClauseGoal>,
+
+    /// The candidates for `obligation` yet to be explored.
+    candidates: ::std::vec::IntoIter<Solution<InEnvironment<WhereClauseGoal>>>,
+
+    /// The state from before the candidate being explored was applied.
+    snapshot: Option<FulfillSnapshot>,
+}
+
+impl<'s, G> Answers<'s, G>
+    where G: Fold + Clone, G::Result: Zip + Fold<Result = G::Result> + Clone + Debug + PartialEq
+{
+    /// Solves what the obligations are now, either returning the
+    /// answer that gives, if it is new, or splitting on one of them.
+    fn explore(&mut self) -> Result<Option<Solution<G::Result>>> {
+        debug_heading!("Answers::explore(depth={}, {} answers so far)",
+                       self.frames.len(), self.answers.len());
+
+        let successful = self.fulfill.solve_all()?;
+        match self.fulfill.split(successful, self.frames.len())? {
+            Some((obligation, candidates)) => {
+                self.frames.push(AnswerFrame {
+                    obligation: obligation,
+                    candidates: candidates.into_iter(),
+                    snapshot: None,
+                });
+                Ok(None)
+            }
+            None => {
+                let solution = Solution {
+                    successful: successful,
+                    refined_goal: self.fulfill.constrained_query(self.goal.clone()),
+                };
+                if self.answers.iter().any(|a| a.subsumes(&solution)) {
+                    return Ok(None);
+                }
+                self.answers.push(solution.clone());
+                Ok(Some(solution))
+            }
+        }
+    }
+}
+
+impl<'s, G> Iterator for Answers<'s, G>
+    where G: Fold + Clone, G::Result: Zip + Fold<Result = G::Result> + Clone + Debug + PartialEq
+{
+    type Item = Result<Solution<G::Result>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            match self.explore() {
+                Ok(Some(answer)) => return Some(Ok(answer)),
+                Ok(None) => (),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+
+        loop {
+            // Move on to the next candidate for the innermost
+            // obligation split on, or, with none left, back out to the
+            // one before it.
+            let next = match self.frames.last_mut() {
+                Some(frame) => {
+                    if let Some(snapshot) = frame.snapshot.take() {
+                        self.fulfill.rollback_to(snapshot);
+                    }
+                    frame.candidates.next().map(|c| (frame.obligation.clone(), c))
+                }
+                None => return None,
+            };
+            let (obligation, candidate) = match next {
+                Some(next) => next,
+                None => {
+                    self.frames.pop();
+                    continue;
+                }
+            };
+
+            let snapshot = self.fulfill.snapshot();
+            self.frames.last_mut().unwrap().snapshot = Some(snapshot);
+
+            // As in `solve_answers`, a candidate that does not work
+            // out only rules out its own branch.
+            if self.fulfill.apply_candidate(&obligation, &candidate).is_ok() {
+                if let Ok(Some(answer)) = self.explore() {
+                    return Some(Ok(answer));
+                }
+            }
+        }
+    }
+}
+
+/// Solves `not { goal }` by negation as failure: it holds if `goal` is
+/// unprovable. If `goal` still mentions inference variables, it
+/// *flounders*: `not { ?T: Copy }` holds for some choices of `?T` and
+/// not others, so failing to prove `?T: Copy` tells us nothing, and
+/// the result is ambiguous. (Variables that `goal` binds itself, as in
+/// `not { exists<T> { T: Copy } }`, are no obstacle.)
+pub fn solve_negative(solver: &mut Solver, goal: &Canonical<InEnvironment<Goal>>) -> Result<Successful> {
+    if !goal.binders.is_empty() {
+        debug!("solve_negative: `{:?}` flounders", goal.value);
+        return Ok(Successful::Maybe);
+    }
+
+    let InEnvironment { ref environment, ref goal } = goal.value;
+    let mut fulfill = Fulfill::new(solver, InferenceTable::new());
+    fulfill.push_goal(goal.clone(), environment);
+    match fulfill.solve_all() {
+        Ok(Successful::Yes) => bail!("`{:?}` is provable", goal),
+        Ok(Successful::Maybe) => Ok(Successful::Maybe),
+        Err(_) => Ok(Successful::Yes),
+    }
+}
+
+/// Replaces the variables bound by the `forall` goal `subgoal` with
+/// placeholders, each in a new universe; returns the environment with
+/// those universes, together with the instantiated goal.
+pub fn instantiate_universally(environment: &Arc<Environment>,
+                               subgoal: &Binders<Box<Goal>>)
+                               -> (Arc<Environment>, Goal) {
+    let mut new_environment = environment.clone();
+    let parameters: Vec<_> =
+        subgoal.binders
+               .iter()
+               .map(|pk| {
+                   new_environment = new_environment.new_universe();
+                   match *pk {
+                       ParameterKind::Lifetime(()) =>
+                           ParameterKind::Lifetime(Lifetime::ForAll(new_environment.universe)),
+
+                       ParameterKind::Ty(()) =>
+                           ParameterKind::Ty(Ty::Apply(ApplicationTy {
+                               name: TypeName::ForAll(new_environment.universe),
+                               parameters: vec![]
+                           })),
+
+                       ParameterKind::Krate(()) =>
+                           panic!("unimplemented: for-all binders with crates"),
+
+                       ParameterKind::Const(()) =>
+                           ParameterKind::Const(Const::ForAll(new_environment.universe)),
+                   }
+               })
+               .collect();
+    let subgoal = subgoal.value.subst(&parameters);
+    (new_environment, subgoal)
+}
@@ -1,9 +1,9 @@
 use errors::*;
 use ir::*;
-use solve::fulfill::Fulfill;
+use solve::fulfill::{Answers, Fulfill};
 use solve::infer::{InferenceTable};
 use solve::solver::Solver;
-use solve::Solution;
+use solve::{Solution, Successful};
 
 pub struct Prove<'s> {
     fulfill: Fulfill<'s>,
@@ -22,8 +22,46 @@ impl<'s> Prove<'s> {
         prove
     }
 
+    /// Enumerates the distinct answers to the goal, up to `limit` of
+    /// them: where `solve` would find `exists<T> { T: Foo }` ambiguous,
+    /// this yields one answer for each type that implements `Foo`.
+    pub fn solve_answers(mut self, limit: usize) -> Result<Vec<Solution<Vec<WhereClauseGoal>>>> {
+        let goals: Vec<_> = self.goals.iter().map(|g| g.goal.clone()).collect();
+        let mut answers = vec![];
+        self.fulfill.solve_answers(&goals, limit, &mut answers)?;
+        Ok(answers)
+    }
+
+    /// Like `solve_answers`, but yields the answers one at a time, as
+    /// they are found, with no limit on how many: the caller stops
+    /// when it has seen enough.
+    pub fn answers(self) -> Answers<'s, Vec<WhereClauseGoal>> {
+        let goals: Vec<_> = self.goals.into_iter().map(|g| g.goal).collect();
+        self.fulfill.answers(goals)
+    }
+
+    /// Like `solve`, but rather than calling the goal ambiguous when
+    /// some of its subgoals cannot be decided, says what the goal is
+    /// refined to provided that those subgoals hold: `exists<T> {
+    /// Vec<T>: Sized, T: Clone }`, given `impl<T> Sized for Vec<T>`
+    /// and a few impls of `Clone`, is provable subject to the goal
+    /// `?0: Clone`. With no goals left, the goal holds outright, as
+    /// when `solve` says `Yes`.
+    pub fn solve_conditional(mut self) -> Result<Canonical<Conditional<Vec<WhereClauseGoal>>>> {
+        self.fulfill.solve_all()?;
+        let goals: Vec<_> = self.goals.into_iter().map(|g| g.goal).collect();
+        Ok(self.fulfill.conditional_goal(goals))
+    }
+
+    /// Proves the goal. If that is ambiguous with the type of some
+    /// numeric literal still unknown, as is `exists<T> { T: {integer},
+    /// T: Foo }` given impls of `Foo` for `i32` and `u8`, the type is
+    /// defaulted and the goal tried again.
     pub fn solve(mut self) -> Result<Solution<Vec<WhereClauseGoal>>> {
-        let successful = self.fulfill.solve_all()?;
+        let mut successful = self.fulfill.solve_all()?;
+        if successful == Successful::Maybe && self.fulfill.default_numeric_vars()? {
+            successful = self.fulfill.solve_all()?;
+        }
         let goals: Vec<_> = self.goals.into_iter().map(|g| g.goal).collect();
         let refined_goal = self.fulfill.refine_goal(goals);
         Ok(Solution {
@@ -0,0 +1,244 @@
+use errors::*;
+use fold::{Fold, Subst};
+use ir::*;
+use solve::Solution;
+use std::collections::HashMap;
+use zip::{Zip, Zipper};
+
+/// Computes the guidance for an ambiguous goal: the most specific
+/// refinement of `start_goal` that every one of the `candidates` is
+/// an instance of. For example, if one candidate refines `?0: Foo` to
+/// `Vec<u32>: Foo` and another to `Vec<i32>: Foo`, then whichever of
+/// them applies, `?0` must be `Vec<?1>` for some `?1`; the guidance
+/// is thus `Vec<?1>: Foo`.
+///
+/// If the candidates agree on nothing, the guidance is just
+/// `start_goal` itself. Region constraints differ from candidate to
+/// candidate, so the guidance never carries any.
+pub fn guidance<R>(start_goal: &Canonical<R>, candidates: &[Solution<R>]) -> Canonical<Constrained<R>>
+    where R: Zip + Fold<Result = R> + Clone
+{
+    let substitutions: Option<Vec<_>> = candidates.iter().map(|candidate| {
+        substitution(start_goal, &candidate.refined_goal.value.value)
+    }).collect();
+
+    let substitutions = match substitutions {
+        Some(substitutions) => substitutions,
+        None => {
+            return start_goal.clone().map(|g| Constrained { value: g, constraints: vec![] });
+        }
+    };
+
+    let mut generalizer = Generalizer { binders: vec![], vars: HashMap::new() };
+    let parameters: Vec<_> = start_goal.binders.iter().enumerate().map(|(index, &kind)| {
+        let values: Option<Vec<_>> = substitutions.iter().map(|s| s[index].clone()).collect();
+        match values {
+            Some(values) => generalizer.generalize(kind, &values),
+            None => generalizer.new_var(kind, None),
+        }
+    }).collect();
+
+    Canonical {
+        value: Constrained {
+            value: Subst::apply(&parameters, &start_goal.value),
+            constraints: vec![],
+        },
+        binders: generalizer.binders,
+    }
+}
+
+/// Determines the value that `refined_goal` gives each variable of
+/// `start_goal`, or `None` if `refined_goal` is not an instance of
+/// `start_goal` that we can make sense of.
+fn substitution<R: Zip>(start_goal: &Canonical<R>, refined_goal: &R) -> Option<Vec<Option<Parameter>>> {
+    let mut collector = SubstitutionCollector {
+        values: vec![None; start_goal.binders.len()],
+    };
+    match Zip::zip_with(&mut collector, &start_goal.value, refined_goal) {
+        Ok(()) => Some(collector.values),
+        Err(_) => None,
+    }
+}
+
+struct SubstitutionCollector {
+    values: Vec<Option<Parameter>>,
+}
+
+impl SubstitutionCollector {
+    fn record(&mut self, index: usize, value: Parameter) -> Result<()> {
+        if index >= self.values.len() {
+            bail!("variable `?{}` is not from the goal", index);
+        }
+        match self.values[index] {
+            Some(ref previous) if *previous != value => {
+                bail!("`?{}` is both `{:?}` and `{:?}`", index, previous, value)
+            }
+            _ => { }
+        }
+        self.values[index] = Some(value);
+        Ok(())
+    }
+}
+
+impl Zipper for SubstitutionCollector {
+    fn zip_tys(&mut self, a: &Ty, b: &Ty) -> Result<()> {
+        match (a, b) {
+            (&Ty::Var(index), _) => self.record(index, ParameterKind::Ty(b.clone())),
+            (&Ty::Apply(ref a), &Ty::Apply(ref b)) => Zip::zip_with(self, a, b),
+            (&Ty::Projection(ref a), &Ty::Projection(ref b)) => Zip::zip_with(self, a, b),
+
+            // Variables under a binder would need to be shifted; so
+            // long as the two types are identical, there is nothing
+            // to learn from them anyway.
+            _ if a == b => Ok(()),
+            _ => bail!("`{:?}` is not an instance of `{:?}`", b, a),
+        }
+    }
+
+    fn zip_lifetimes(&mut self, a: &Lifetime, b: &Lifetime) -> Result<()> {
+        match *a {
+            Lifetime::Var(index) => self.record(index, ParameterKind::Lifetime(*b)),
+            _ if a == b => Ok(()),
+            _ => bail!("`{:?}` is not an instance of `{:?}`", b, a),
+        }
+    }
+
+    fn zip_krates(&mut self, a: &Krate, b: &Krate) -> Result<()> {
+        match *a {
+            Krate::Var(index) => self.record(index, ParameterKind::Krate(*b)),
+            _ if a == b => Ok(()),
+            _ => bail!("`{:?}` is not an instance of `{:?}`", b, a),
+        }
+    }
+
+    fn zip_consts(&mut self, a: &Const, b: &Const) -> Result<()> {
+        match *a {
+            Const::Var(index) => self.record(index, ParameterKind::Const(*b)),
+            _ if a == b => Ok(()),
+            _ => bail!("`{:?}` is not an instance of `{:?}`", b, a),
+        }
+    }
+}
+
+/// Anti-unifies the values that the candidates give a variable: the
+/// parts of the values that all candidates agree on are kept, and
+/// each place where they differ becomes a fresh variable.
+struct Generalizer {
+    /// The kinds and universes of the fresh variables.
+    binders: Vec<ParameterKind<UniverseIndex>>,
+
+    /// The fresh variable created for each combination of differing
+    /// values, so that a combination that occurs twice is
+    /// generalized to the same variable both times.
+    vars: HashMap<Vec<Parameter>, usize>,
+}
+
+impl Generalizer {
+    fn new_var(&mut self, kind: ParameterKind<UniverseIndex>, key: Option<Vec<Parameter>>) -> Parameter {
+        if let Some(&index) = key.as_ref().and_then(|key| self.vars.get(key)) {
+            return var_of_kind(kind, index);
+        }
+
+        let index = self.binders.len();
+        self.binders.push(kind);
+        if let Some(key) = key {
+            self.vars.insert(key, index);
+        }
+        var_of_kind(kind, index)
+    }
+
+    fn generalize(&mut self, kind: ParameterKind<UniverseIndex>, values: &[Parameter]) -> Parameter {
+        let universe = match kind {
+            ParameterKind::Ty(ui) |
+            ParameterKind::Lifetime(ui) |
+            ParameterKind::Krate(ui) |
+            ParameterKind::Const(ui) => ui,
+        };
+
+        if let Some(tys) = values.iter().map(|v| v.as_ref().ty().cloned()).collect::<Option<Vec<_>>>() {
+            return ParameterKind::Ty(self.generalize_tys(universe, &tys));
+        }
+
+        // Lifetimes, crates and consts have no structure of their own,
+        // so the candidates either agree on them outright or not at all.
+        let first = &values[0];
+        let mentions_var = values.iter().any(|v| match *v {
+            ParameterKind::Lifetime(Lifetime::Var(_)) |
+            ParameterKind::Krate(Krate::Var(_)) |
+            ParameterKind::Const(Const::Var(_)) => true,
+            _ => false,
+        });
+        if !mentions_var && values.iter().all(|v| v == first) {
+            first.clone()
+        } else {
+            self.new_var(kind, Some(values.to_vec()))
+        }
+    }
+
+    fn generalize_tys(&mut self, universe: UniverseIndex, tys: &[Ty]) -> Ty {
+        let first = &tys[0];
+        match *first {
+            Ty::Apply(ref a) => {
+                let applications: Option<Vec<_>> = tys.iter().map(|ty| match *ty {
+                    Ty::Apply(ref b) if b.name == a.name && b.parameters.len() == a.parameters.len() => Some(b),
+                    _ => None,
+                }).collect();
+                if let Some(applications) = applications {
+                    return Ty::Apply(ApplicationTy {
+                        name: a.name,
+                        parameters: self.generalize_parameters(universe, &applications, |b| &b.parameters),
+                    });
+                }
+            }
+            Ty::Projection(ref a) => {
+                let projections: Option<Vec<_>> = tys.iter().map(|ty| match *ty {
+                    Ty::Projection(ref b) if b.associated_ty_id == a.associated_ty_id => Some(b),
+                    _ => None,
+                }).collect();
+                if let Some(projections) = projections {
+                    return Ty::Projection(ProjectionTy {
+                        associated_ty_id: a.associated_ty_id,
+                        parameters: self.generalize_parameters(universe, &projections, |b| &b.parameters),
+                    });
+                }
+            }
+            Ty::Var(_) | Ty::ForAll(_) => { }
+        }
+
+        let key = tys.iter().cloned().map(ParameterKind::Ty).collect();
+        match self.new_var(ParameterKind::Ty(universe), Some(key)) {
+            ParameterKind::Ty(ty) => ty,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generalizes the parameters of several applications (or
+    /// projections) with the same name, position by position.
+    fn generalize_parameters<T, F>(&mut self,
+                                   universe: UniverseIndex,
+                                   items: &[&T],
+                                   parameters: F)
+                                   -> Vec<Parameter>
+        where F: Fn(&T) -> &Vec<Parameter>
+    {
+        (0..parameters(items[0]).len()).map(|index| {
+            let values: Vec<_> = items.iter().map(|item| parameters(*item)[index].clone()).collect();
+            let kind = match values[0] {
+                ParameterKind::Ty(_) => ParameterKind::Ty(universe),
+                ParameterKind::Lifetime(_) => ParameterKind::Lifetime(universe),
+                ParameterKind::Krate(_) => ParameterKind::Krate(universe),
+                ParameterKind::Const(_) => ParameterKind::Const(universe),
+            };
+            self.generalize(kind, &values)
+        }).collect()
+    }
+}
+
+fn var_of_kind(kind: ParameterKind<UniverseIndex>, index: usize) -> Parameter {
+    match kind {
+        ParameterKind::Ty(_) => ParameterKind::Ty(Ty::Var(index)),
+        ParameterKind::Lifetime(_) => ParameterKind::Lifetime(Lifetime::Var(index)),
+        ParameterKind::Krate(_) => ParameterKind::Krate(Krate::Var(index)),
+        ParameterKind::Const(_) => ParameterKind::Const(Const::Var(index)),
+    }
+}
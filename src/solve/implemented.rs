@@ -1,12 +1,13 @@
 use errors::*;
 use ir::*;
 use solve::match_any::MatchAny;
+use solve::match_program_clause::MatchProgramClause;
 use solve::solver::Solver;
 use solve::Solution;
 
 pub struct Implemented<'s> {
     solver: &'s mut Solver,
-    env_goal: Query<InEnvironment<TraitRef>>,
+    env_goal: Canonical<InEnvironment<TraitRef>>,
 }
 
 enum Technique {
@@ -15,7 +16,7 @@ enum Technique {
 }
 
 impl<'s> Implemented<'s> {
-    pub fn new(solver: &'s mut Solver, env_goal: Query<InEnvironment<TraitRef>>) -> Self {
+    pub fn new(solver: &'s mut Solver, env_goal: Canonical<InEnvironment<TraitRef>>) -> Self {
         Implemented {
             solver: solver,
             env_goal: env_goal,
@@ -24,7 +25,14 @@ impl<'s> Implemented<'s> {
 
     pub fn solve(self) -> Result<Solution<InEnvironment<TraitRef>>> {
         let Implemented { solver, env_goal } = self;
-        MatchAny::new(solver, &env_goal).solve().chain_err(|| {
+        let result = if Self::blocked_by_negative_impl(solver, &env_goal) {
+            Err("a negative impl applies".into())
+        } else {
+            let trait_id = env_goal.value.goal.trait_id;
+            let marker = solver.program.trait_datum(trait_id).map_or(false, |datum| datum.binders.value.flags.marker);
+            MatchAny::new(solver, &env_goal).allow_overlap(marker).solve()
+        };
+        result.chain_err(|| {
             format!("`{:?}{:?}` is not implemented for `{:?}` in environment `{:?}`",
                     env_goal.value.goal.trait_id,
                     debug::Angle(&env_goal.value.goal.parameters[1..]),
@@ -32,4 +40,20 @@ impl<'s> Implemented<'s> {
                     env_goal.value.environment)
         })
     }
+
+    /// A negative impl like `impl !Send for Foo` blocks the goal `Foo:
+    /// Send` if it applies without us having to learn anything new
+    /// about the goal. If the negative impl only *might* apply (e.g.,
+    /// the goal is `?T: Send`), we fall back to the usual search.
+    pub fn blocked_by_negative_impl(solver: &mut Solver,
+                                env_goal: &Canonical<InEnvironment<TraitRef>>)
+                                -> bool {
+        let program = solver.program.clone();
+        program.negative_impl_clauses(env_goal.value.goal.trait_id).into_iter().any(|clause| {
+            match MatchProgramClause::new(solver, env_goal, clause).solve() {
+                Ok(solution) => solution.is_unconditional_for(env_goal),
+                Err(_) => false,
+            }
+        })
+    }
 }
@@ -1,52 +1,81 @@
 use errors::*;
 use fold::{Fold, Folder, Shifter};
 use ir::*;
+use std::fmt::Debug;
 
 use super::{InferenceTable, TyInferenceVariable, KrateInferenceVariable, LifetimeInferenceVariable,
-            ParameterInferenceVariable};
+            ConstInferenceVariable, ParameterInferenceVariable};
 use super::var::InferenceValue;
 
 impl InferenceTable {
     /// Given a value `value` with variables in it, replaces those
     /// variables with their instantiated values; any variables not
-    /// yet instantiated are replaces with a small integer index 0..N
+    /// yet instantiated are replaced with a small integer index 0..N
     /// in order of appearance. The result is a canonicalized
-    /// representation of `value`.
+    /// representation of `value`, which no longer mentions this
+    /// table's variables: it can be cached, compared with other
+    /// canonical values, and solved in an inference table of its own.
     ///
     /// Example:
     ///
     ///    ?22: Foo<?23>
     ///
-    /// would be quantified to
+    /// would be canonicalized to
     ///
-    ///    Quantified { value: `?0: Foo<?1>`, binders: [ui(?22), ui(?23)] }
+    ///    Canonical { value: `?0: Foo<?1>`, binders: [ui(?22), ui(?23)] }
     ///
     /// where `ui(?22)` and `ui(?23)` are the universe indices of
-    /// `?22` and `?23` respectively.
-    pub fn make_query<T>(&mut self, value: &T) -> Query<T::Result>
+    /// `?22` and `?23` respectively. The free variables `?22` and
+    /// `?23` are returned too, so that an answer to the canonical
+    /// value can be related back to this table.
+    pub fn canonicalize<T>(&mut self, value: &T) -> Canonicalized<T::Result>
         where T: Fold
     {
-        debug!("make_query({:#?})", value);
-        let mut q = Querifier {
+        debug!("canonicalize({:#?})", value);
+        let mut q = Canonicalizer {
             table: self,
             free_vars: Vec::new(),
         };
         let r = value.fold_with(&mut q, 0).unwrap();
-        Query {
-            value: r,
-            binders: q.into_binders(),
+        let free_vars = q.free_vars.clone();
+        Canonicalized {
+            quantified: Canonical {
+                value: r,
+                binders: q.into_binders(),
+            },
+            free_vars: free_vars,
         }
     }
+
+    /// Instantiates the canonical variables of `canonical` with fresh
+    /// inference variables from this table, of the same kinds and in
+    /// the same universes.
+    pub fn instantiate_canonical<T>(&mut self, canonical: &Canonical<T>) -> T::Result
+        where T: Fold + Debug
+    {
+        self.instantiate(canonical.binders.iter().cloned(), &canonical.value)
+    }
 }
 
-struct Querifier<'q> {
+/// The result of canonicalizing a value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Canonicalized<T> {
+    /// The canonical form of the value.
+    pub quantified: Canonical<T>,
+
+    /// The inference variable that each canonical variable stands for:
+    /// canonical variable `?i` is `free_vars[i]`.
+    pub free_vars: Vec<ParameterInferenceVariable>,
+}
+
+struct Canonicalizer<'q> {
     table: &'q mut InferenceTable,
     free_vars: Vec<ParameterInferenceVariable>,
 }
 
-impl<'q> Querifier<'q> {
+impl<'q> Canonicalizer<'q> {
     fn into_binders(self) -> Vec<ParameterKind<UniverseIndex>> {
-        let Querifier { table, free_vars } = self;
+        let Canonicalizer { table, free_vars } = self;
         free_vars.into_iter()
             .map(|p_v| match p_v {
                      ParameterKind::Ty(v) => {
@@ -70,6 +99,13 @@ impl<'q> Querifier<'q> {
                              InferenceValue::Bound(_) => panic!("free var now bound"),
                          }
                      }
+
+                     ParameterKind::Const(n) => {
+                         match table.const_unify.probe_value(n) {
+                             InferenceValue::Unbound(ui) => ParameterKind::Const(ui),
+                             InferenceValue::Bound(_) => panic!("free var now bound"),
+                         }
+                     }
                  })
             .collect()
     }
@@ -86,7 +122,7 @@ impl<'q> Querifier<'q> {
     }
 }
 
-impl<'q> Folder for Querifier<'q> {
+impl<'q> Folder for Canonicalizer<'q> {
     fn fold_free_var(&mut self, depth: usize, binders: usize) -> Result<Ty> {
         let var = TyInferenceVariable::from_depth(depth);
         match self.table.probe_var(var) {
@@ -142,4 +178,19 @@ impl<'q> Folder for Querifier<'q> {
             }
         }
     }
+
+    fn fold_free_const_var(&mut self, depth: usize, binders: usize) -> Result<Const> {
+        let var = ConstInferenceVariable::from_depth(depth);
+        match self.table.probe_const_var(var) {
+            Some(n) => {
+                let mut folder = (self, Shifter::new(binders));
+                n.fold_with(&mut folder, 0)
+            }
+            None => {
+                let free_var = ParameterKind::Const(self.table.const_unify.find(var));
+                let position = self.add(free_var) + binders;
+                Ok(ConstInferenceVariable::from_depth(position).to_const())
+            }
+        }
+    }
 }
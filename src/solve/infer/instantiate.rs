@@ -53,4 +53,12 @@ impl Folder for Instantiator {
             Ok(Krate::Var(depth + binders - self.vars.len())) // see comment above
         }
     }
+
+    fn fold_free_const_var(&mut self, depth: usize, binders: usize) -> Result<Const> {
+        if depth < self.vars.len() {
+            Ok(self.vars[depth].as_ref().constant().unwrap().to_const().up_shift(binders))
+        } else {
+            Ok(Const::Var(depth + binders - self.vars.len())) // see comment above
+        }
+    }
 }
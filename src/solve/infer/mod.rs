@@ -2,31 +2,46 @@ use ena::unify as ena;
 use errors::*;
 use ir::*;
 
+mod canonicalize;
 mod instantiate;
-mod query;
 mod unify;
+mod universe;
 mod var;
 #[cfg(test)] mod test;
 
+pub use self::canonicalize::Canonicalized;
 pub use self::unify::UnificationResult;
+pub use self::universe::UniverseMap;
 use self::var::*;
 
+/// The inference variables of each kind are kept in a union-find
+/// table (from `ena`), rather than as a map of substitutions: unifying
+/// two variables merges their sets, so that however long a chain of
+/// variables gets, finding the value of any of them takes close to
+/// constant time, and a snapshot is just a position in the table's
+/// undo log.
 #[derive(Clone)]
 pub struct InferenceTable {
     ty_unify: ena::UnificationTable<TyInferenceVariable>,
     krate_unify: ena::UnificationTable<KrateInferenceVariable>,
     lifetime_unify: ena::UnificationTable<LifetimeInferenceVariable>,
+    const_unify: ena::UnificationTable<ConstInferenceVariable>,
 }
 
 pub struct InferenceSnapshot {
     ty_unify_snapshot: ena::Snapshot<TyInferenceVariable>,
 
     krate_unify_snapshot: ena::Snapshot<KrateInferenceVariable>,
+
+    lifetime_unify_snapshot: ena::Snapshot<LifetimeInferenceVariable>,
+
+    const_unify_snapshot: ena::Snapshot<ConstInferenceVariable>,
 }
 
 pub type ParameterInferenceVariable = ParameterKind<TyInferenceVariable,
                                                     LifetimeInferenceVariable,
-                                                    KrateInferenceVariable>;
+                                                    KrateInferenceVariable,
+                                                    ConstInferenceVariable>;
 
 impl InferenceTable {
     pub fn new() -> Self {
@@ -34,6 +49,7 @@ impl InferenceTable {
             ty_unify: ena::UnificationTable::new(),
             krate_unify: ena::UnificationTable::new(),
             lifetime_unify: ena::UnificationTable::new(),
+            const_unify: ena::UnificationTable::new(),
         }
     }
 
@@ -57,34 +73,59 @@ impl InferenceTable {
         self.krate_unify.new_key(InferenceValue::Unbound(ui))
     }
 
+    pub fn new_const_variable(&mut self, ui: UniverseIndex) -> ConstInferenceVariable {
+        self.const_unify.new_key(InferenceValue::Unbound(ui))
+    }
+
     pub fn new_parameter_variable(&mut self, ui: ParameterKind<UniverseIndex>)
                                   -> ParameterInferenceVariable {
         match ui {
             ParameterKind::Ty(ui) => ParameterKind::Ty(self.new_variable(ui)),
             ParameterKind::Lifetime(ui) => ParameterKind::Lifetime(self.new_lifetime_variable(ui)),
             ParameterKind::Krate(ui) => ParameterKind::Krate(self.new_krate_variable(ui)),
+            ParameterKind::Const(ui) => ParameterKind::Const(self.new_const_variable(ui)),
         }
     }
 
+    /// Starts a speculative stretch of inference: the variables made
+    /// and bound from now on can be undone with `rollback_to`, or kept
+    /// with `commit`. Snapshots nest, and must be ended innermost first;
+    /// taking one is cheap, so that trying a candidate need not mean
+    /// cloning the whole table.
     pub fn snapshot(&mut self) -> InferenceSnapshot {
         let ty_unify_snapshot = self.ty_unify.snapshot();
         let krate_unify_snapshot = self.krate_unify.snapshot();
+        let lifetime_unify_snapshot = self.lifetime_unify.snapshot();
+        let const_unify_snapshot = self.const_unify.snapshot();
         InferenceSnapshot {
             ty_unify_snapshot,
             krate_unify_snapshot,
+            lifetime_unify_snapshot,
+            const_unify_snapshot,
         }
     }
 
+    /// Undoes everything done since `snapshot` was taken.
     pub fn rollback_to(&mut self, snapshot: InferenceSnapshot) {
         self.ty_unify.rollback_to(snapshot.ty_unify_snapshot);
         self.krate_unify.rollback_to(snapshot.krate_unify_snapshot);
+        self.lifetime_unify.rollback_to(snapshot.lifetime_unify_snapshot);
+        self.const_unify.rollback_to(snapshot.const_unify_snapshot);
     }
 
-    fn commit(&mut self, snapshot: InferenceSnapshot) {
+    /// Keeps everything done since `snapshot` was taken. If it is
+    /// nested in another snapshot, rolling back to that one still
+    /// undoes it.
+    pub fn commit(&mut self, snapshot: InferenceSnapshot) {
         self.ty_unify.commit(snapshot.ty_unify_snapshot);
+        self.krate_unify.commit(snapshot.krate_unify_snapshot);
+        self.lifetime_unify.commit(snapshot.lifetime_unify_snapshot);
+        self.const_unify.commit(snapshot.const_unify_snapshot);
     }
 
-    fn commit_if_ok<F, R>(&mut self, op: F) -> Result<R>
+    /// Runs `op`, keeping what it did to the table if it succeeds and
+    /// undoing it if not.
+    pub fn commit_if_ok<F, R>(&mut self, op: F) -> Result<R>
         where F: FnOnce(&mut Self) -> Result<R>
     {
         let snapshot = self.snapshot();
@@ -101,6 +142,17 @@ impl InferenceTable {
         }
     }
 
+    /// Runs `op` and undoes whatever it did to the table, for finding
+    /// out whether something would unify without committing to it.
+    pub fn probe<F, R>(&mut self, op: F) -> R
+        where F: FnOnce(&mut Self) -> R
+    {
+        let snapshot = self.snapshot();
+        let result = op(self);
+        self.rollback_to(snapshot);
+        result
+    }
+
     fn normalize_shallow(&mut self, leaf: &Ty) -> Option<Ty> {
         leaf.inference_var()
             .and_then(|var| {
@@ -118,10 +170,17 @@ impl InferenceTable {
         }
     }
 
+    fn normalize_const(&mut self, leaf: &Const) -> Option<Const> {
+        match *leaf {
+            Const::Var(v) => self.probe_const_var(ConstInferenceVariable::from_depth(v)),
+            Const::ForAll(_) | Const::Value(_) => None,
+        }
+    }
+
     fn normalize_lifetime(&mut self, leaf: &Lifetime) -> Option<Lifetime> {
         match *leaf {
             Lifetime::Var(v) => self.probe_lifetime_var(LifetimeInferenceVariable::from_depth(v)),
-            Lifetime::ForAll(_) => None,
+            Lifetime::ForAll(_) | Lifetime::Static => None,
         }
     }
 
@@ -139,6 +198,13 @@ impl InferenceTable {
         }
     }
 
+    fn probe_const_var(&mut self, var: ConstInferenceVariable) -> Option<Const> {
+        match self.const_unify.probe_value(var) {
+            InferenceValue::Unbound(_) => None,
+            InferenceValue::Bound(val) => Some(val.clone()),
+        }
+    }
+
     fn probe_lifetime_var(&mut self, var: LifetimeInferenceVariable) -> Option<Lifetime> {
         match self.lifetime_unify.probe_value(var) {
             InferenceValue::Unbound(_) => None,
@@ -84,6 +84,11 @@ impl<'q> Folder for Normalizer<'q> {
         assert_eq!(binders, 0);
         Ok(KrateInferenceVariable::from_depth(depth).to_krate())
     }
+
+    fn fold_free_const_var(&mut self, depth: usize, binders: usize) -> Result<Const> {
+        assert_eq!(binders, 0);
+        Ok(ConstInferenceVariable::from_depth(depth).to_const())
+    }
 }
 
 #[test]
@@ -178,22 +183,36 @@ fn universe_promote_bad() {
 #[test]
 fn projection_eq() {
     // exists(A -> A = Item0<<A as Item1>::foo>)
-    //                       ^^^^^^^^^^^^ Can A repeat here? For now,
-    //                       we say no, but it's an interesting question.
+    //                       ^^^^^^^^^^^^ A may repeat here: the
+    //                       projection is generalized to a fresh
+    //                       variable, which it must normalize to.
     let mut table = InferenceTable::new();
     let environment0 = Environment::new();
     let a = table.new_variable(environment0.universe).to_ty();
 
-    // expect an error ("cycle during unification")
-    table.unify(&environment0,
-               &a,
-               &ty!(apply (item 0) (projection (item 1) (expr a))))
-        .unwrap_err();
+    let result = table.unify(&environment0,
+                             &a,
+                             &ty!(apply (item 0) (projection (item 1) (expr a))))
+                      .unwrap();
+    let b = match table.normalize(&a) {
+        Ty::Apply(ApplicationTy { ref parameters, .. }) => parameters[0].clone().ty().unwrap(),
+        ty => panic!("`{:?}` was not generalized", ty),
+    };
+    assert!(b.inference_var().is_some());
+    assert_eq!(result.goals.len(), 1);
+    match result.goals[0].goal {
+        WhereClauseGoal::Normalize(ref normalize) => {
+            assert_eq!(normalize.projection.associated_ty_id, ItemId { index: 1 });
+            assert_eq!(normalize.ty, b);
+        }
+        ref goal => panic!("unexpected goal `{:?}`", goal),
+    }
 }
 
 const U0: UniverseIndex = UniverseIndex { counter: 0 };
 const U1: UniverseIndex = UniverseIndex { counter: 1 };
 const U2: UniverseIndex = UniverseIndex { counter: 2 };
+const U3: UniverseIndex = UniverseIndex { counter: 3 };
 
 #[test]
 fn quantify_simple() {
@@ -204,8 +223,8 @@ fn quantify_simple() {
     ]);
 
     assert_eq!(
-        table.make_query(&ty!(apply (item 0) (var 2) (var 1) (var 0))),
-        Query {
+        table.canonicalize(&ty!(apply (item 0) (var 2) (var 1) (var 0))).quantified,
+        Canonical {
             value: ty!(apply (item 0) (var 0) (var 1) (var 2)),
             binders: vec![ParameterKind::Ty(U2), ParameterKind::Ty(U1), ParameterKind::Ty(U0)],
         });
@@ -230,9 +249,105 @@ fn quantify_bound() {
         .unwrap();
 
     assert_eq!(
-        table.make_query(&ty!(apply (item 0) (expr v2b) (expr v2a) (expr v1) (expr v0))),
-        Query {
+        table.canonicalize(&ty!(apply (item 0) (expr v2b) (expr v2a) (expr v1) (expr v0))).quantified,
+        Canonical {
             value: ty!(apply (item 0) (apply (item 1) (var 0) (var 1)) (var 2) (var 0) (var 1)),
             binders: vec![ParameterKind::Ty(U1), ParameterKind::Ty(U0), ParameterKind::Ty(U2)],
         });
 }
+
+#[test]
+fn canonicalize_round_trip() {
+    let mut table = InferenceTable::new_with_vars(&[
+        ParameterKind::Ty(U0),
+        ParameterKind::Ty(U1),
+    ]);
+
+    let canonicalized = table.canonicalize(&ty!(apply (item 0) (var 1) (var 0) (var 1)));
+    assert_eq!(
+        canonicalized.free_vars,
+        vec![ParameterKind::Ty(TyInferenceVariable::from_depth(1)),
+             ParameterKind::Ty(TyInferenceVariable::from_depth(0))]);
+
+    // Moving the value into another table and canonicalizing it there
+    // gets us back where we started.
+    let mut other = InferenceTable::new();
+    let ty = other.instantiate_canonical(&canonicalized.quantified);
+    assert_eq!(other.canonicalize(&ty).quantified, canonicalized.quantified);
+}
+
+#[test]
+fn long_variable_chains() {
+    // exists(A0, ..., An -> A0 = A1, ..., An-1 = An, An = foo)
+    let mut table = InferenceTable::new();
+    let environment0 = Environment::new();
+    let vars: Vec<_> = (0..10_000).map(|_| table.new_variable(environment0.universe).to_ty()).collect();
+    let lifetimes: Vec<_> =
+        (0..10_000).map(|_| table.new_lifetime_variable(environment0.universe)).collect();
+    for pair in vars.windows(2) {
+        table.unify(&environment0, &pair[0], &pair[1]).unwrap();
+    }
+    for pair in lifetimes.windows(2) {
+        table.unify(&environment0, &pair[0].to_lifetime(), &pair[1].to_lifetime()).unwrap();
+    }
+
+    let snapshot = table.snapshot();
+    table.unify(&environment0, &vars[vars.len() - 1], &ty!(apply (item 0))).unwrap();
+    assert!(vars.iter().all(|var| table.normalize(var) == ty!(apply (item 0))));
+
+    table.rollback_to(snapshot);
+    assert!(table.probe_var(vars[0].inference_var().unwrap()).is_none());
+    assert!(table.lifetime_unify.unioned(lifetimes[0], lifetimes[lifetimes.len() - 1]));
+}
+
+#[test]
+fn snapshots() {
+    let mut table = InferenceTable::new();
+    let environment0 = Environment::new();
+    let a = table.new_variable(environment0.universe).to_ty();
+    let b = table.new_variable(environment0.universe).to_ty();
+
+    // What `probe` does is always undone.
+    let unified = table.probe(|table| table.unify(&environment0, &a, &ty!(apply (item 0))).is_ok());
+    assert!(unified);
+    assert_eq!(table.normalize(&a), a);
+
+    // A failed `commit_if_ok` is undone, even the parts that worked.
+    table.commit_if_ok(|table| {
+        table.unify(&environment0, &a, &ty!(apply (item 0)))?;
+        table.unify(&environment0, &a, &ty!(apply (item 1)))
+    }).unwrap_err();
+    assert_eq!(table.normalize(&a), a);
+
+    // Committing an inner snapshot keeps its bindings only until the
+    // outer one is rolled back.
+    let outer = table.snapshot();
+    let inner = table.snapshot();
+    table.unify(&environment0, &a, &ty!(apply (item 0) (expr b))).unwrap();
+    table.commit(inner);
+    assert_eq!(table.normalize(&a), ty!(apply (item 0) (expr b)));
+    table.rollback_to(outer);
+    assert_eq!(table.normalize(&a), a);
+}
+
+#[test]
+fn universe_compression() {
+    // A value in U0 and U3 compresses to one in U0 and U1, and back.
+    let canonical = Canonical {
+        value: ty!(apply (item 0) (apply (skol 3)) (var 0)),
+        binders: vec![ParameterKind::Ty(U3)],
+    };
+    let (compressed, universes) = UniverseMap::compress(&canonical);
+    assert_eq!(
+        compressed,
+        Canonical {
+            value: ty!(apply (item 0) (apply (skol 1)) (var 0)),
+            binders: vec![ParameterKind::Ty(U1)],
+        });
+    assert_eq!(universes.decompress(&compressed), canonical);
+
+    // Universes made after compressing, past the last one, come after
+    // it in the original numbering as well.
+    let answer = Canonical { value: ty!(apply (skol 2)), binders: vec![] };
+    assert_eq!(universes.decompress(&answer).value, ty!(apply (skol 4)));
+}
@@ -165,6 +165,9 @@ impl<'t> Unifier<'t> {
         Ok(())
     }
 
+    /// Rather than normalizing `proj` here, leaves a goal to normalize
+    /// it to `ty`, which the solver can put off until it knows enough
+    /// about the projection to pick an impl.
     fn unify_projection_ty(&mut self, proj: &ProjectionTy, ty: &Ty) -> Result<()> {
         Ok(self.goals.push(InEnvironment::new(self.environment,
                                               Normalize {
@@ -252,6 +255,50 @@ impl<'t> Unifier<'t> {
         }
     }
 
+    fn unify_const_const(&mut self, a: &Const, b: &Const) -> Result<()> {
+        if let Some(n_a) = self.table.normalize_const(a) {
+            return self.unify_const_const(&n_a, b);
+        } else if let Some(n_b) = self.table.normalize_const(b) {
+            return self.unify_const_const(a, &n_b);
+        }
+
+        debug_heading!("unify_const_const({:?}, {:?})", a, b);
+
+        match (*a, *b) {
+            (Const::Var(depth_a), Const::Var(depth_b)) => {
+                let var_a = ConstInferenceVariable::from_depth(depth_a);
+                let var_b = ConstInferenceVariable::from_depth(depth_b);
+                self.table.const_unify.unify_var_var(var_a, var_b).unwrap();
+                Ok(())
+            }
+
+            (Const::Var(depth), value) |
+            (value, Const::Var(depth)) => {
+                let var = ConstInferenceVariable::from_depth(depth);
+                let var_ui = match self.table.const_unify.probe_value(var) {
+                    InferenceValue::Unbound(ui) => ui,
+                    InferenceValue::Bound(_) => panic!("bound var survived normalization"),
+                };
+                if let Const::ForAll(ui) = value {
+                    if !var_ui.can_see(ui) {
+                        bail!(ErrorKind::PlaceholderLeak(format!("{:?}", value), var_ui))
+                    }
+                }
+                self.table.const_unify.unify_var_value(var, InferenceValue::Bound(value)).unwrap();
+                Ok(())
+            }
+
+            (Const::ForAll(_), _) |
+            (Const::Value(_), _) => {
+                if a == b {
+                    Ok(())
+                } else {
+                    bail!("cannot unify `{:?}` with `{:?}`", a, b)
+                }
+            }
+        }
+    }
+
     fn unify_lifetime_lifetime(&mut self, a: &Lifetime, b: &Lifetime) -> Result<()> {
         if let Some(n_a) = self.table.normalize_lifetime(a) {
             return self.unify_lifetime_lifetime(&n_a, b);
@@ -287,7 +334,20 @@ impl<'t> Unifier<'t> {
                 }
             }
 
-            (&Lifetime::ForAll(_), &Lifetime::ForAll(_)) => {
+            // `'static` lives in the root universe, so any variable
+            // can be bound to it.
+            (&Lifetime::Var(depth), &Lifetime::Static) |
+            (&Lifetime::Static, &Lifetime::Var(depth)) => {
+                let var = LifetimeInferenceVariable::from_depth(depth);
+                self.table.lifetime_unify.unify_var_value(var, InferenceValue::Bound(Lifetime::Static))
+                                         .unwrap();
+                Ok(())
+            }
+
+            (&Lifetime::ForAll(_), &Lifetime::ForAll(_)) |
+            (&Lifetime::ForAll(_), &Lifetime::Static) |
+            (&Lifetime::Static, &Lifetime::ForAll(_)) |
+            (&Lifetime::Static, &Lifetime::Static) => {
                 if a != b {
                     Ok(self.push_lifetime_eq_constraint(*a, *b))
                 } else {
@@ -314,6 +374,10 @@ impl<'t> Zipper for Unifier<'t> {
     fn zip_krates(&mut self, a: &Krate, b: &Krate) -> Result<()> {
         self.unify_krate_krate(a, b)
     }
+
+    fn zip_consts(&mut self, a: &Const, b: &Const) -> Result<()> {
+        self.unify_const_const(a, b)
+    }
 }
 
 impl ApplicationTy {
@@ -326,7 +390,17 @@ impl TypeName {
     fn universe_index(&self) -> UniverseIndex {
         match *self {
             TypeName::ItemId(_) |
-            TypeName::AssociatedType(_) => UniverseIndex::root(),
+            TypeName::AssociatedType(_) |
+            TypeName::Scalar(_) |
+            TypeName::Tuple(_) |
+            TypeName::Ref(_) |
+            TypeName::Raw(_) |
+            TypeName::Slice |
+            TypeName::Str |
+            TypeName::Array |
+            TypeName::FnPtr(_) |
+            TypeName::Never |
+            TypeName::PhantomData => UniverseIndex::root(),
             TypeName::ForAll(universe) => {
                 assert!(universe.counter > 0);
                 universe
@@ -354,7 +428,7 @@ impl<'u, 't> OccursCheck<'u, 't> {
 
     fn check_apply(&mut self, apply: &ApplicationTy) -> Result<ApplicationTy> {
         let ApplicationTy { name, ref parameters } = *apply;
-        self.universe_check(name.universe_index())?;
+        self.universe_check(name.universe_index(), &name)?;
         let parameters = parameters.iter()
                                    .map(|p| self.check_parameter(p))
                                    .collect::<Result<Vec<_>>>()?;
@@ -374,6 +448,40 @@ impl<'u, 't> OccursCheck<'u, 't> {
             ParameterKind::Ty(ref t) => Ok(ParameterKind::Ty(self.check_ty(t)?)),
             ParameterKind::Lifetime(ref lt) => Ok(ParameterKind::Lifetime(self.check_lifetime(lt)?)),
             ParameterKind::Krate(_) => panic!("krate used as parameter to a type"),
+            ParameterKind::Const(ref n) => Ok(ParameterKind::Const(self.check_const(n)?)),
+        }
+    }
+
+    fn check_const(&mut self, n: &Const) -> Result<Const> {
+        match *n {
+            Const::Var(depth) => {
+                if depth < self.binders {
+                    return Ok(Const::Var(depth));
+                }
+
+                let v = ConstInferenceVariable::from_depth(depth - self.binders);
+                match self.unifier.table.const_unify.probe_value(v) {
+                    InferenceValue::Unbound(ui) => {
+                        if self.universe_index < ui {
+                            // As with type variables, promote `?N` to
+                            // the universe of the variable being bound.
+                            self.unifier
+                                .table
+                                .const_unify
+                                .unify_var_value(v, InferenceValue::Unbound(self.universe_index))
+                                .unwrap();
+                        }
+                        Ok(Const::Var(depth))
+                    }
+
+                    InferenceValue::Bound(n) => Ok(n.up_shift(self.binders)),
+                }
+            }
+            Const::ForAll(ui) => {
+                self.universe_check(ui, n)?;
+                Ok(Const::ForAll(ui))
+            }
+            Const::Value(value) => Ok(Const::Value(value)),
         }
     }
 
@@ -435,6 +543,7 @@ impl<'u, 't> OccursCheck<'u, 't> {
                     Ok(Lifetime::ForAll(ui))
                 }
             }
+            Lifetime::Static => Ok(Lifetime::Static),
         }
     }
 
@@ -477,10 +586,29 @@ impl<'u, 't> OccursCheck<'u, 't> {
                 Ok(Ty::Var(depth))
             }
 
+            Ty::Projection(ref projection) if self.binders == 0 => {
+                // Generalize, as rustc does: rather than binding the
+                // variable to a type with the projection in it, which
+                // would commit it to that projection's unnormalized
+                // form, stand a fresh variable in for the projection
+                // and leave a goal to normalize the projection to it.
+                // This also lets `exists(A -> A = Item0<<A as
+                // Item1>::foo>)` through, where the occurs check would
+                // otherwise reject it.
+                let var = self.unifier.table.new_variable(self.universe_index).to_ty();
+                let environment = self.unifier.environment;
+                self.unifier.goals.push(InEnvironment::new(environment,
+                                                           Normalize {
+                                                               projection: projection.clone(),
+                                                               ty: var.clone(),
+                                                           }
+                                                           .cast()));
+                Ok(var)
+            }
+
             Ty::Projection(ProjectionTy { associated_ty_id, ref parameters }) => {
-                // FIXME(#6) -- this rejects constraints like
-                // `exists(A -> A = Item0<<A as Item1>::foo>)`, which
-                // is probably too conservative.
+                // Under binders, the projection may name the bound
+                // lifetimes, so it cannot be pulled out into a goal.
                 let parameters =
                     parameters.iter()
                               .map(|p| self.check_parameter(p))
@@ -490,16 +618,19 @@ impl<'u, 't> OccursCheck<'u, 't> {
         }
     }
 
+    /// The leak check: fails if `placeholder`, which lives in
+    /// `application_universe_index`, cannot be named from the universe
+    /// of the variable being bound, as in `exists<T> { forall<U> { T =
+    /// U } }`.
     fn universe_check(&mut self,
-                      application_universe_index: UniverseIndex)
+                      application_universe_index: UniverseIndex,
+                      placeholder: &Debug)
                       -> Result<()> {
         debug!("universe_check({:?}, {:?})",
                self.universe_index,
                application_universe_index);
         if self.universe_index < application_universe_index {
-            bail!("incompatible universes(universe_index={:?}, application_universe_index={:?})",
-                  self.universe_index,
-                  application_universe_index)
+            bail!(ErrorKind::PlaceholderLeak(format!("{:?}", placeholder), self.universe_index))
         } else {
             Ok(())
         }
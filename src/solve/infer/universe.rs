@@ -0,0 +1,95 @@
+//! Compressing the universes of canonical values.
+//!
+//! A goal like `!3: Foo` in an environment of universe U3 only cares
+//! about how its universes are ordered, not which they are: solving it
+//! is no different from solving `!1: Foo` in U1. Before a canonical
+//! goal is solved (and cached), its universes are renumbered to the
+//! fewest consecutive ones that keep that order, so that goals that
+//! come up in different contexts share a cache entry; the solution is
+//! numbered back again afterwards.
+
+use errors::*;
+use fold::{Fold, Folder};
+use ir::*;
+
+/// The universes a canonical value was compressed from, in order:
+/// universe `Ui` of the compressed value is `universes[i]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UniverseMap {
+    universes: Vec<UniverseIndex>,
+}
+
+impl UniverseMap {
+    /// Compresses the universes of `canonical`, returning the result
+    /// together with the map to take it (or an answer to it) back.
+    pub fn compress<T: Fold>(canonical: &Canonical<T>) -> (Canonical<T::Result>, UniverseMap) {
+        let mut universes = vec![UniverseIndex::root()];
+        canonical.value.fold_with(&mut UniverseFolder { op: |ui| { universes.push(ui); ui } }, 0).unwrap();
+        for binder in &canonical.binders {
+            binder.clone().map(|ui| universes.push(ui));
+        }
+        universes.sort();
+        universes.dedup();
+
+        let map = UniverseMap { universes };
+        let compressed = Canonical {
+            value: canonical.value.fold_with(&mut UniverseFolder { op: |ui| map.compress_universe(ui) }, 0).unwrap(),
+            binders: canonical.binders.iter().map(|b| b.clone().map(|ui| map.compress_universe(ui))).collect(),
+        };
+        (compressed, map)
+    }
+
+    /// Takes `canonical`, in terms of the compressed universes, back to
+    /// the original ones.
+    pub fn decompress<T: Fold>(&self, canonical: &Canonical<T>) -> Canonical<T::Result> {
+        Canonical {
+            value: canonical.value.fold_with(&mut UniverseFolder { op: |ui| self.decompress_universe(ui) }, 0).unwrap(),
+            binders: canonical.binders.iter().map(|b| b.clone().map(|ui| self.decompress_universe(ui))).collect(),
+        }
+    }
+
+    fn compress_universe(&self, universe: UniverseIndex) -> UniverseIndex {
+        let counter = self.universes.binary_search(&universe).expect("universe not in map");
+        UniverseIndex { counter }
+    }
+
+    fn decompress_universe(&self, universe: UniverseIndex) -> UniverseIndex {
+        // Solving may create universes beyond the ones compressed, one
+        // after another past the last; they follow that one's original
+        // in the same way.
+        let last = self.universes.len() - 1;
+        if universe.counter <= last {
+            self.universes[universe.counter]
+        } else {
+            UniverseIndex { counter: self.universes[last].counter + (universe.counter - last) }
+        }
+    }
+}
+
+/// A folder that changes only the universes of a value, by `op`;
+/// variables are left as they are.
+struct UniverseFolder<F> {
+    op: F,
+}
+
+impl<F: FnMut(UniverseIndex) -> UniverseIndex> Folder for UniverseFolder<F> {
+    fn fold_free_var(&mut self, depth: usize, binders: usize) -> Result<Ty> {
+        Ok(Ty::Var(depth + binders))
+    }
+
+    fn fold_free_lifetime_var(&mut self, depth: usize, binders: usize) -> Result<Lifetime> {
+        Ok(Lifetime::Var(depth + binders))
+    }
+
+    fn fold_free_krate_var(&mut self, depth: usize, binders: usize) -> Result<Krate> {
+        Ok(Krate::Var(depth + binders))
+    }
+
+    fn fold_free_const_var(&mut self, depth: usize, binders: usize) -> Result<Const> {
+        Ok(Const::Var(depth + binders))
+    }
+
+    fn fold_universe(&mut self, universe: UniverseIndex) -> UniverseIndex {
+        (self.op)(universe)
+    }
+}
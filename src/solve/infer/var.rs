@@ -76,6 +76,38 @@ impl UnifyKey for KrateInferenceVariable {
     }
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ConstInferenceVariable {
+    index: u32,
+}
+
+impl ConstInferenceVariable {
+    pub fn from_depth(depth: usize) -> ConstInferenceVariable {
+        assert!(depth < u32::MAX as usize);
+        ConstInferenceVariable { index: depth as u32 }
+    }
+
+    pub fn to_const(&self) -> Const {
+        Const::Var(self.index as usize)
+    }
+}
+
+impl UnifyKey for ConstInferenceVariable {
+    type Value = InferenceValue<Const>;
+
+    fn index(&self) -> u32 {
+        self.index
+    }
+
+    fn from_index(u: u32) -> Self {
+        ConstInferenceVariable { index: u }
+    }
+
+    fn tag() -> &'static str {
+        "ConstInferenceVariable"
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct LifetimeInferenceVariable {
     index: u32,
@@ -166,3 +198,9 @@ impl fmt::Debug for KrateInferenceVariable {
     }
 }
 
+impl fmt::Debug for ConstInferenceVariable {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(fmt, "const ?{}", self.index)
+    }
+}
+
@@ -4,52 +4,211 @@ use fold::Fold;
 use ir::*;
 use solve::match_clause::MatchClause;
 use solve::match_program_clause::MatchProgramClause;
+use solve::proof_tree::ProofClause;
 use solve::solver::Solver;
 use solve::Solution;
+use solve::subsumption::add_answer;
 use std::hash::Hash;
+use zip::Zip;
 
 pub struct MatchAny<'s, G: 's> {
     solver: &'s mut Solver,
-    env_goal: &'s Query<InEnvironment<G>>,
+    env_goal: &'s Canonical<InEnvironment<G>>,
+    allow_overlap: bool,
 }
 
-#[derive(Debug)]
+/// How many candidates a goal must have before, with the `parallel`
+/// feature, they are explored on the thread pool; for fewer, forking
+/// the solver costs more than it saves.
+#[cfg(feature = "parallel")]
+const PARALLEL_CANDIDATES: usize = 16;
+
+#[derive(Clone, Debug)]
 enum Technique<'t> {
     WithClause(WhereClause),
     WithProgramClause(&'t ProgramClause),
+    WithDefaultClause(&'t DefaultProgramClause),
 }
 
 impl<'s, G> MatchAny<'s, G>
-    where G: Cast<WhereClause> + Cast<WhereClauseGoal> + Clone + Hash + Eq + Fold<Result = G>
+    where G: Cast<WhereClause> + Cast<WhereClauseGoal> + Clone + Hash + Eq + Fold<Result = G> + Zip,
+          G: Send + Sync
 {
-    pub fn new(solver: &'s mut Solver, env_goal: &'s Query<InEnvironment<G>>) -> Self {
+    pub fn new(solver: &'s mut Solver, env_goal: &'s Canonical<InEnvironment<G>>) -> Self {
         MatchAny {
             solver: solver,
             env_goal: env_goal,
+            allow_overlap: false,
         }
     }
 
+    /// Treat candidates that agree on the refined goal as a single
+    /// candidate, rather than as an ambiguity. This is used for
+    /// marker traits, whose impls may overlap.
+    pub fn allow_overlap(mut self, allow_overlap: bool) -> Self {
+        self.allow_overlap = allow_overlap;
+        self
+    }
+
     pub fn solve(self) -> Result<Solution<InEnvironment<G>>> {
-        let MatchAny { solver, env_goal } = self;
+        let MatchAny { solver, env_goal, allow_overlap } = self;
         let program = solver.program.clone();
+        let (hypotheses, techniques) = Self::techniques(&*program, env_goal, solver.sort_candidates());
 
-        let environment = &env_goal.value.environment;
-        let techniques =
-            environment.elaborated_clauses(&program)
-                       .map(Technique::WithClause)
-                       .chain(program.program_clauses.iter().map(Technique::WithProgramClause));
-
-        let result = solver.solve_any(techniques, &env_goal, |solver, technique| {
-            match technique {
-                Technique::WithClause(clause) => {
-                    MatchClause::new(solver, &env_goal, &clause).solve()
-                }
-                Technique::WithProgramClause(program_clause) => {
-                    MatchProgramClause::new(solver, &env_goal, program_clause).solve()
+        // As in rustc, a where-clause in the environment that proves
+        // the goal wins out over the impls (and other clauses of the
+        // program) that might also prove it, even if the impls would
+        // prove it some other way; the goal is only ambiguous if the
+        // where-clauses disagree among themselves.
+        if !hypotheses.is_empty() {
+            let result = solver.solve_any(hypotheses, &env_goal, allow_overlap, |solver, technique| {
+                Self::apply(solver, env_goal, technique)
+            });
+            if result.is_ok() {
+                return result;
+            }
+        }
+
+        if let Some(results) = Self::apply_parallel(solver, env_goal, &techniques) {
+            return solver.solve_any(results, &env_goal, allow_overlap, |_, result| result);
+        }
+        solver.solve_any(techniques, &env_goal, allow_overlap, |solver, technique| {
+            Self::apply(solver, env_goal, technique)
+        })
+    }
+
+    /// The distinct solutions yielded by each technique that applies,
+    /// in order; unlike `solve`, these are not collapsed into a single
+    /// (possibly ambiguous) solution.
+    pub fn candidates(self) -> Vec<Solution<InEnvironment<G>>> {
+        let MatchAny { solver, env_goal, .. } = self;
+        let program = solver.program.clone();
+        let (hypotheses, techniques) = Self::techniques(&*program, env_goal, solver.sort_candidates());
+        let mut candidates = vec![];
+        for technique_set in vec![hypotheses, techniques] {
+            for technique in technique_set {
+                if let Ok(solution) = Self::apply(solver, env_goal, technique) {
+                    add_answer(&mut candidates, solution, Solution::subsumes);
                 }
             }
-        });
+            // As in `solve`, the where-clauses come first.
+            if !candidates.is_empty() {
+                break;
+            }
+        }
+        candidates
+    }
+
+    /// The ways of proving the goal: first from the where-clauses of
+    /// the environment, then from the clauses of the program. With
+    /// `sort`, each is sorted by how it prints.
+    fn techniques<'t>(program: &'t ChalkDatabase,
+                      env_goal: &Canonical<InEnvironment<G>>,
+                      sort: bool)
+                      -> (Vec<Technique<'t>>, Vec<Technique<'t>>) {
+        let environment = &env_goal.value.environment;
+        let goal: WhereClauseGoal = env_goal.value.goal.clone().cast();
+        let mut hypotheses: Vec<_> = environment.elaborated_clauses(program)
+                                                .map(Technique::WithClause)
+                                                .collect();
+        let mut clauses: Vec<_> = program.program_clauses_for(&goal)
+                                         .into_iter()
+                                         .map(Technique::WithProgramClause)
+                                         .chain(program.default_program_clauses_for(&goal)
+                                                       .into_iter()
+                                                       .map(Technique::WithDefaultClause))
+                                         .collect();
+        if sort {
+            hypotheses.sort_by_key(|technique| format!("{:?}", technique));
+            clauses.sort_by_key(|technique| format!("{:?}", technique));
+        }
+        (hypotheses, clauses)
+    }
+
+    /// Applies each of `techniques` on its own fork of the solver,
+    /// on the thread pool, if there are enough of them to make that
+    /// worthwhile. The results are those that applying them in turn
+    /// would give, up to the first that settles the goal outright;
+    /// the forks that produced them are joined back into `solver`.
+    #[cfg(feature = "parallel")]
+    fn apply_parallel(solver: &mut Solver,
+                      env_goal: &Canonical<InEnvironment<G>>,
+                      techniques: &[Technique])
+                      -> Option<Vec<Result<Solution<InEnvironment<G>>>>> {
+        use rayon::prelude::*;
+
+        if techniques.len() < PARALLEL_CANDIDATES || !solver.can_fork() {
+            return None;
+        }
+
+        // The workers print items, in debug output and in errors, by
+        // way of the current program, which is thread-local; so each
+        // worker sets it to this thread's before it starts.
+        let program = with_current_program(|program| program.cloned());
+        let forks = solver.forks(techniques.len());
+        let outcomes: Vec<_> = forks.into_par_iter()
+                                    .zip(techniques.par_iter())
+                                    .map(|(fork, technique)| {
+                                        let run = || {
+                                            fork.run(|solver| Self::apply(solver, env_goal, technique.clone()))
+                                        };
+                                        match program {
+                                            Some(ref program) => set_current_program(program, run),
+                                            None => run(),
+                                        }
+                                    })
+                                    .collect();
+
+        // Solving in turn would have stopped at the first solution
+        // that holds unconditionally, so the later ones are dropped,
+        // along with anything their forks learned.
+        let mut results = vec![];
+        for (fork, result) in outcomes {
+            let last = match result {
+                Ok(ref solution) => solution.is_unconditional_for(env_goal),
+                Err(_) => false,
+            };
+            solver.join(fork);
+            results.push(result);
+            if last {
+                break;
+            }
+        }
+        solver.unfork();
+        Some(results)
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    fn apply_parallel(_solver: &mut Solver,
+                      _env_goal: &Canonical<InEnvironment<G>>,
+                      _techniques: &[Technique])
+                      -> Option<Vec<Result<Solution<InEnvironment<G>>>>> {
+        None
+    }
 
-        result
+    fn apply(solver: &mut Solver,
+             env_goal: &Canonical<InEnvironment<G>>,
+             technique: Technique)
+             -> Result<Solution<InEnvironment<G>>> {
+        match technique {
+            Technique::WithClause(clause) => {
+                solver.with_candidate(|| ProofClause::Hypothesis(clause.clone()), |solver| {
+                    MatchClause::new(solver, env_goal, &clause).solve()
+                })
+            }
+            Technique::WithProgramClause(program_clause) => {
+                solver.with_candidate(|| ProofClause::Program(program_clause.clone()), |solver| {
+                    MatchProgramClause::new(solver, env_goal, program_clause).solve()
+                })
+            }
+            Technique::WithDefaultClause(default_clause) => {
+                if default_clause.may_be_specialized(&env_goal.value.goal.clone().cast()) {
+                    bail!("a more specialized impl may apply")
+                }
+                solver.with_candidate(|| ProofClause::Program(default_clause.clause.clone()), |solver| {
+                    MatchProgramClause::new(solver, env_goal, &default_clause.clause).solve()
+                })
+            }
+        }
     }
 }
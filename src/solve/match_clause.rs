@@ -19,7 +19,7 @@ impl<'s, G> MatchClause<'s, G>
     where G: Clone + Cast<WhereClause> + Fold<Result = G>
 {
     pub fn new(solver: &'s mut Solver,
-               q: &'s Query<InEnvironment<G>>,
+               q: &'s Canonical<InEnvironment<G>>,
                clause: &'s WhereClause)
                -> Self {
         let InEnvironment { ref environment, ref goal } = q.value;
@@ -31,7 +31,17 @@ impl<'s, G> MatchClause<'s, G>
 
     pub fn solve(mut self) -> Result<Solution<InEnvironment<G>>> {
         let environment = self.environment.clone();
-        self.fulfill.unify(&environment, &self.goal.clone().cast(), &self.clause)?;
+
+        // A higher-ranked hypothesis `forall<'a> T: Foo<'a>` may be used
+        // at any choice of `'a`, so its binders become inference variables.
+        let mut clause = self.clause.clone();
+        while let WhereClause::ForAll(binders) = clause {
+            clause = *self.fulfill.instantiate_in(environment.universe,
+                                                  binders.binders.iter().cloned(),
+                                                  &binders.value);
+        }
+
+        self.fulfill.unify(&environment, &self.goal.clone().cast(), &clause)?;
         let successful = self.fulfill.solve_all()?;
         let refined_goal = self.fulfill.refine_goal(InEnvironment::new(&environment, &self.goal));
         Ok(Solution {
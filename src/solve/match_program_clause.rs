@@ -19,7 +19,7 @@ impl<'s, G> MatchProgramClause<'s, G>
     where G: Clone + Cast<WhereClauseGoal> + Fold<Result = G>
 {
     pub fn new(solver: &'s mut Solver,
-               q: &'s Query<InEnvironment<G>>,
+               q: &'s Canonical<InEnvironment<G>>,
                program_clause: &'s ProgramClause)
                -> Self {
         let InEnvironment { ref environment, ref goal } = q.value;
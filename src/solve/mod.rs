@@ -1,5 +1,17 @@
+use errors::*;
 use ir::*;
+use solve::breadth_first::BreadthFirstSolver;
+use solve::goal::Prove;
+use solve::slg::SlgSolver;
+use solve::solver::Solver;
+use fold::Fold;
+use zip::Zip;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+pub mod breadth_first;
+pub mod delegate;
 pub mod fulfill;
 pub mod infer;
 pub mod implemented;
@@ -9,19 +21,210 @@ pub mod match_program_clause;
 pub mod normalize;
 pub mod normalize_application;
 pub mod not_unify;
+pub mod numeric;
+pub mod outlives;
+pub mod proof_tree;
 pub mod goal;
+pub mod guidance;
+pub mod slg;
 pub mod solver;
+pub mod specialization;
+pub mod subsumption;
+pub mod subtype;
+pub mod suggest;
+pub mod truncate;
 pub mod unify;
+pub mod why_not;
 
 #[cfg(test)] mod test;
 
+/// Which of the solvers to prove a goal with.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SolverChoice {
+    /// The recursive solver, which searches depth-first and treats
+    /// goals nested more than `overflow_depth` deep as ambiguous.
+    Recursive { overflow_depth: usize },
+
+    /// The recursive solver, run with an overflow depth of 0, then 1,
+    /// and so on up to `max_depth`, until a run finds that the goal
+    /// holds outright or never overflows. Goals with shallow proofs
+    /// are solved without searching deep for others.
+    IterativeDeepening { max_depth: usize },
+
+    /// The breadth-first solver, which takes every partial proof one
+    /// step further in turn, for at most `max_steps` steps overall.
+    BreadthFirst { max_steps: usize },
+
+    /// The SLG solver, which builds up tables of answers; `max_size`
+    /// bounds both the number of tables and the answers in each.
+    Slg { max_size: usize },
+}
+
+/// Settings for solving a single goal, in place of those of the
+/// `SolverChoice` it is solved with, so that a caller can tighten the
+/// limits for one query without setting up a solver of its own. A
+/// field left `None` keeps what the solver would otherwise use.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SolveOptions {
+    /// How deep goals may be nested: the `overflow_depth` of the
+    /// recursive solver, or the `max_depth` of iterative deepening.
+    pub max_depth: Option<usize>,
+
+    /// The `max_size` of the SLG solver.
+    pub max_size: Option<usize>,
+
+    /// The `max_steps` of the breadth-first solver.
+    pub max_steps: Option<usize>,
+
+    /// How many answers `SolverChoice::solve_goal_answers` looks for
+    /// at most; `DEFAULT_ENUMERATE_LIMIT` if `None`.
+    pub enumerate_limit: Option<usize>,
+
+    /// How long to solve for before giving up with
+    /// `ErrorKind::TimedOut`; there is no time limit if `None`.
+    pub timeout: Option<Duration>,
+}
+
+/// How many answers to a goal are enumerated, unless `SolveOptions`
+/// says otherwise.
+pub const DEFAULT_ENUMERATE_LIMIT: usize = 10;
+
+impl SolverChoice {
+    pub fn solve_goal(self,
+                      program: &Arc<ChalkDatabase>,
+                      goal: Box<Goal>)
+                      -> Result<Solution<Vec<WhereClauseGoal>>> {
+        self.solve_goal_until(program, goal, None)
+    }
+
+    /// Like `solve_goal`, but gives up with `ErrorKind::TimedOut` if
+    /// the goal is not solved within `timeout`.
+    pub fn solve_goal_with_timeout(self,
+                                   program: &Arc<ChalkDatabase>,
+                                   goal: Box<Goal>,
+                                   timeout: Duration)
+                                   -> Result<Solution<Vec<WhereClauseGoal>>> {
+        self.solve_goal_until(program, goal, Some(Instant::now() + timeout))
+    }
+
+    /// This choice of solver, with the limits that `options` sets in
+    /// place of its own.
+    pub fn with_options(self, options: &SolveOptions) -> SolverChoice {
+        match self {
+            SolverChoice::Recursive { overflow_depth } => {
+                SolverChoice::Recursive { overflow_depth: options.max_depth.unwrap_or(overflow_depth) }
+            }
+            SolverChoice::IterativeDeepening { max_depth } => {
+                SolverChoice::IterativeDeepening { max_depth: options.max_depth.unwrap_or(max_depth) }
+            }
+            SolverChoice::BreadthFirst { max_steps } => {
+                SolverChoice::BreadthFirst { max_steps: options.max_steps.unwrap_or(max_steps) }
+            }
+            SolverChoice::Slg { max_size } => {
+                SolverChoice::Slg { max_size: options.max_size.unwrap_or(max_size) }
+            }
+        }
+    }
+
+    /// Like `solve_goal`, but with the settings of `options`.
+    pub fn solve_goal_with_options(self,
+                                   program: &Arc<ChalkDatabase>,
+                                   goal: Box<Goal>,
+                                   options: &SolveOptions)
+                                   -> Result<Solution<Vec<WhereClauseGoal>>> {
+        let deadline = options.timeout.map(|timeout| Instant::now() + timeout);
+        self.with_options(options).solve_goal_until(program, goal, deadline)
+    }
+
+    /// Enumerates the distinct answers to `goal`, up to the
+    /// `enumerate_limit` of `options` (see `Prove::solve_answers`).
+    /// Only the recursive solver splits a goal into answers like
+    /// this, searching as deep as the solver would; the others give
+    /// the one solution `solve_goal` would.
+    pub fn solve_goal_answers(self,
+                              program: &Arc<ChalkDatabase>,
+                              goal: Box<Goal>,
+                              options: &SolveOptions)
+                              -> Result<Vec<Solution<Vec<WhereClauseGoal>>>> {
+        let deadline = options.timeout.map(|timeout| Instant::now() + timeout);
+        let limit = options.enumerate_limit.unwrap_or(DEFAULT_ENUMERATE_LIMIT);
+        match self.with_options(options) {
+            SolverChoice::Recursive { overflow_depth } |
+            SolverChoice::IterativeDeepening { max_depth: overflow_depth } => {
+                let goal = Box::new(goal.simplify());
+                let mut solver = Solver::new(program, overflow_depth);
+                solver.set_deadline(deadline);
+                let result = Prove::new(&mut solver, goal).solve_answers(limit);
+                solver.or_timed_out(result)
+            }
+            choice => Ok(vec![choice.solve_goal_until(program, goal, deadline)?]),
+        }
+    }
+
+    fn solve_goal_until(self,
+                        program: &Arc<ChalkDatabase>,
+                        goal: Box<Goal>,
+                        deadline: Option<Instant>)
+                        -> Result<Solution<Vec<WhereClauseGoal>>> {
+        let goal = Box::new(goal.simplify());
+        match self {
+            SolverChoice::Recursive { overflow_depth } => {
+                let mut solver = Solver::new(program, overflow_depth);
+                solver.set_deadline(deadline);
+                let result = Prove::new(&mut solver, goal).solve();
+                solver.or_timed_out(result)
+            }
+            SolverChoice::IterativeDeepening { max_depth } => {
+                let mut overflow_depth = 0;
+                loop {
+                    let mut solver = Solver::new(program, overflow_depth);
+                    solver.set_deadline(deadline);
+                    let result = Prove::new(&mut solver, goal.clone()).solve();
+                    let result = solver.or_timed_out(result);
+                    let settled = match result {
+                        Ok(ref solution) => solution.successful() == Successful::Yes,
+                        Err(_) => solver.timed_out(),
+                    };
+                    if settled || solver.overflow().is_none() || overflow_depth >= max_depth {
+                        return result;
+                    }
+                    overflow_depth += 1;
+                }
+            }
+            SolverChoice::BreadthFirst { max_steps } => {
+                let mut solver = BreadthFirstSolver::new(program, max_steps);
+                solver.set_deadline(deadline);
+                solver.solve(goal)
+            }
+            SolverChoice::Slg { max_size } => {
+                let mut solver = SlgSolver::new(program, max_size);
+                solver.set_deadline(deadline);
+                solver.solve(goal)
+            }
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Solution<G> {
     successful: Successful,
-    refined_goal: Query<Constrained<G>>,
+
+    /// What we learned about the goal. For an ambiguous solution,
+    /// this is guidance: what every way of proving the goal agrees
+    /// on, which is sound to act on but may not be the end of it.
+    refined_goal: Canonical<Constrained<G>>,
 }
 
 impl<G> Solution<G> {
+    /// The solution that says nothing at all about `goal`: it may or
+    /// may not hold.
+    pub fn maybe(goal: Canonical<G>) -> Self {
+        Solution {
+            successful: Successful::Maybe,
+            refined_goal: goal.map(|g| Constrained { value: g, constraints: vec![] }),
+        }
+    }
+
     pub fn map<OP, H>(self, op: OP) -> Solution<H>
         where OP: FnOnce(G) -> H
     {
@@ -30,6 +233,31 @@ impl<G> Solution<G> {
             refined_goal: self.refined_goal.map(|c| c.map(op)),
         }
     }
+
+    pub fn successful(&self) -> Successful {
+        self.successful
+    }
+
+    /// True if this solution proves `goal` outright: it is successful
+    /// and it neither refines `goal` nor carries any constraints.
+    pub fn is_unconditional_for(&self, goal: &Canonical<G>) -> bool
+        where G: PartialEq
+    {
+        self.successful == Successful::Yes &&
+            self.refined_goal.binders == goal.binders &&
+            self.refined_goal.value.value == goal.value &&
+            self.refined_goal.value.constraints.is_empty()
+    }
+
+    /// True if this solution makes `other` redundant: it refines the
+    /// goal no further, and is no less certain. See
+    /// `subsumption::subsumes`.
+    pub fn subsumes(&self, other: &Self) -> bool
+        where G: Zip + Fold<Result = G> + Clone + Debug + PartialEq
+    {
+        (self.successful == Successful::Yes || other.successful == Successful::Maybe) &&
+            subsumption::subsumes(&self.refined_goal, &other.refined_goal)
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
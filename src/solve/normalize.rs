@@ -7,7 +7,7 @@ use solve::Solution;
 
 pub struct SolveNormalize<'s> {
     solver: &'s mut Solver,
-    env_goal: Query<InEnvironment<Normalize>>,
+    env_goal: Canonical<InEnvironment<Normalize>>,
 }
 
 enum Technique {
@@ -16,7 +16,7 @@ enum Technique {
 }
 
 impl<'s> SolveNormalize<'s> {
-    pub fn new(solver: &'s mut Solver, env_goal: Query<InEnvironment<Normalize>>) -> Self {
+    pub fn new(solver: &'s mut Solver, env_goal: Canonical<InEnvironment<Normalize>>) -> Self {
         SolveNormalize {
             solver: solver,
             env_goal: env_goal,
@@ -27,11 +27,33 @@ impl<'s> SolveNormalize<'s> {
         let SolveNormalize { solver, env_goal } = self;
         match MatchAny::new(solver, &env_goal).solve() {
             Ok(v) => Ok(v),
+            Err(_) if Self::underconstrained(&*solver.program, &env_goal.value.goal) => {
+                // Until we know more about the trait the projection
+                // belongs to, some impl may yet apply, and settling on
+                // the fallback now would rule it out. Leave the goal
+                // for later; the fulfillment context that set it will
+                // try it again once its other obligations have refined
+                // the variables in it.
+                Ok(Solution::maybe(env_goal))
+            }
             Err(_) => {
                 // If we can't find anything better, the fallback is to
-                // normalize into an application of `Iterator::Item`.
+                // normalize into an application of `Iterator::Item`,
+                // provided the trait bound holds.
                 NormalizeApplication::new(solver, env_goal).solve()
             }
         }
     }
+
+    /// True if the self type of the trait that `goal` projects out of
+    /// is an inference variable that has yet to be resolved.
+    fn underconstrained(program: &ChalkDatabase, goal: &Normalize) -> bool {
+        match program.split_projection(&goal.projection) {
+            Some((_, trait_params, _)) => match trait_params[0].as_ref().ty() {
+                Some(&Ty::Var(_)) => true,
+                _ => false,
+            },
+            None => false,
+        }
+    }
 }
@@ -14,7 +14,7 @@ pub struct NormalizeApplication<'s> {
 
 impl<'s> NormalizeApplication<'s> {
     pub fn new(solver: &'s mut Solver,
-               q: Query<InEnvironment<Normalize>>)
+               q: Canonical<InEnvironment<Normalize>>)
                -> Self {
         let InEnvironment { environment, goal } = q.value;
         let infer = InferenceTable::new_with_vars(&q.binders);
@@ -40,6 +40,23 @@ impl<'s> NormalizeApplication<'s> {
         // application type we just built (`apply_ty`).
         self.fulfill.unify(&environment, &self.goal.ty, &apply_ty)?;
 
+        // The application stands for whatever the impl of the trait
+        // normalizes the projection to, so there must be one: that is,
+        // the trait bound has to hold, most likely by a where-clause in
+        // the environment, as in generic code that has `T: Iterator`.
+        let trait_ref = {
+            let program = self.fulfill.program();
+            let (associated_ty_data, trait_params, _) = match program.split_projection(&self.goal.projection) {
+                Some(split) => split,
+                None => bail!("no associated type `{:?}`", self.goal.projection.associated_ty_id),
+            };
+            TraitRef {
+                trait_id: associated_ty_data.trait_id,
+                parameters: trait_params.to_owned(),
+            }
+        };
+        self.fulfill.push_goal(Goal::Leaf(WhereClauseGoal::Implemented(trait_ref)), &environment);
+
         // Now try to prove any resulting where-clauses one by one. If
         // all of them can be successfully proved, then we know that
         // this unification succeeded.
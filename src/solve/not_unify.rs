@@ -17,16 +17,16 @@ pub struct SolveNotUnify<'s> {
 enum State {
     Unprovable,
     Ambiguous,
-    IfGoalsMet(Vec<Query<InEnvironment<WhereClauseGoal>>>),
+    IfGoalsMet(Vec<Canonical<InEnvironment<WhereClauseGoal>>>),
     NotUnifiable,
 }
 use self::State::*;
 
 impl<'s> SolveNotUnify<'s> {
     pub fn new(solver: &'s mut Solver,
-               env_goal: Query<InEnvironment<Not<Unify<Ty>>>>)
+               env_goal: Canonical<InEnvironment<Not<Unify<Ty>>>>)
                -> Self {
-        let Query { value: InEnvironment { environment, goal }, binders } = env_goal;
+        let Canonical { value: InEnvironment { environment, goal }, binders } = env_goal;
         SolveNotUnify { solver, binders, environment, goal, state: Unprovable }
     }
 
@@ -34,7 +34,7 @@ impl<'s> SolveNotUnify<'s> {
         let successful = self.solve_tys()?;
         Ok(Solution {
             successful: successful,
-            refined_goal: Query {
+            refined_goal: Canonical {
                 binders: self.binders,
                 value: Constrained {
                     value: InEnvironment {
@@ -87,7 +87,7 @@ impl<'s> SolveNotUnify<'s> {
         }
     }
 
-    fn if_goal_met(&mut self, goal: Query<InEnvironment<WhereClauseGoal>>) {
+    fn if_goal_met(&mut self, goal: Canonical<InEnvironment<WhereClauseGoal>>) {
         debug!("not_unifiable");
         match self.state {
             Unprovable | Ambiguous => self.state = IfGoalsMet(vec![goal]),
@@ -118,16 +118,6 @@ impl<'s> SolveNotUnify<'s> {
 
             (&Ty::Apply(ref apply1), &Ty::Apply(ref apply2)) => {
                 match (apply1.name, apply2.name) {
-                    (TypeName::ItemId(n1), TypeName::ItemId(n2)) => {
-                        if n1 != n2 {
-                            self.not_unifiable();
-                        } else {
-                            for (param1, param2) in apply1.parameters.iter().zip(&apply2.parameters) {
-                                self.parameters(param1, param2);
-                            }
-                        }
-                    }
-
                     (_, TypeName::ForAll(_)) |
                     (_, TypeName::AssociatedType(_)) |
                     (TypeName::ForAll(_), _) |
@@ -136,6 +126,16 @@ impl<'s> SolveNotUnify<'s> {
                         // these values are, so we can't say that they are disjoint
                         self.unprovable();
                     }
+
+                    (n1, n2) => {
+                        if n1 != n2 {
+                            self.not_unifiable();
+                        } else {
+                            for (param1, param2) in apply1.parameters.iter().zip(&apply2.parameters) {
+                                self.parameters(param1, param2);
+                            }
+                        }
+                    }
                 }
             }
 
@@ -181,7 +181,7 @@ impl<'s> SolveNotUnify<'s> {
     }
 
     fn projection_ty(&mut self, proj: &ProjectionTy, ty: &Ty) {
-        let goal = Query {
+        let goal = Canonical {
             binders: self.binders.clone(),
             value: InEnvironment::new(&self.environment,
                                       Not {
@@ -200,15 +200,25 @@ impl<'s> SolveNotUnify<'s> {
         unimplemented!()
     }
 
+    fn consts(&mut self, n1: &Const, n2: &Const) {
+        match (*n1, *n2) {
+            (Const::Value(v1), Const::Value(v2)) if v1 != v2 => self.not_unifiable(),
+            (Const::Var(_), _) | (_, Const::Var(_)) => self.ambiguous(),
+            _ => self.unprovable(),
+        }
+    }
+
     fn parameters(&mut self, p1: &Parameter, p2: &Parameter) {
         use ir::ParameterKind::*;
         match (p1, p2) {
             (&Ty(ref t1), &Ty(ref t2)) => self.tys(t1, t2),
             (&Lifetime(_), &Lifetime(_)) => self.unprovable(),
             (&Krate(_), &Krate(_)) => panic!("krate in type"),
+            (&Const(ref n1), &Const(ref n2)) => self.consts(n1, n2),
             (&Ty(_), _) |
             (&Lifetime(_), _) |
-            (&Krate(_), _) => {
+            (&Krate(_), _) |
+            (&Const(_), _) => {
                 panic!("mismatched kinds")
             }
         }
@@ -0,0 +1,45 @@
+use errors::*;
+use ir::*;
+use solve::infer::InferenceTable;
+use solve::fulfill::Fulfill;
+use solve::solver::Solver;
+use solve::{Solution, Successful};
+use std::sync::Arc;
+
+/// Solves `T: {integer}` and `T: {float}`. These hold for the scalar
+/// types of the right kind, and are ambiguous so long as `T` is not
+/// known; it is up to whoever asked to default `T` if nothing else
+/// decides it (see `Fulfill::default_numeric_vars`). A projection
+/// like `<T as Iterator>::Item` is not known either, since it may yet
+/// normalize to a scalar.
+pub struct SolveNumeric<'s> {
+    fulfill: Fulfill<'s>,
+    environment: Arc<Environment>,
+    goal: Numeric,
+}
+
+impl<'s> SolveNumeric<'s> {
+    pub fn new(solver: &'s mut Solver, env_goal: Canonical<InEnvironment<Numeric>>) -> Self {
+        let Canonical { binders, value: InEnvironment { environment, goal } } = env_goal;
+        let infer = InferenceTable::new_with_vars(&binders);
+        let fulfill = Fulfill::new(solver, infer);
+        SolveNumeric { fulfill, environment, goal }
+    }
+
+    pub fn solve(self) -> Result<Solution<InEnvironment<Numeric>>> {
+        let successful = match self.goal.ty {
+            Ty::Var(_) | Ty::Projection(_) => Successful::Maybe,
+            Ty::Apply(ApplicationTy { name: TypeName::Scalar(scalar), .. })
+                if scalar.numeric_kind() == Some(self.goal.kind) => Successful::Yes,
+            ref ty => match self.goal.kind {
+                NumericKind::Integer => bail!("`{:?}` is not an integer type", ty),
+                NumericKind::Float => bail!("`{:?}` is not a float type", ty),
+            },
+        };
+        let refined_goal = self.fulfill.refine_goal(InEnvironment::new(&self.environment, &self.goal));
+        Ok(Solution {
+            successful: successful,
+            refined_goal: refined_goal,
+        })
+    }
+}
@@ -0,0 +1,56 @@
+use errors::*;
+use fold::Fold;
+use ir::*;
+use solve::infer::InferenceTable;
+use solve::fulfill::Fulfill;
+use solve::solver::Solver;
+use solve::Solution;
+use std::sync::Arc;
+
+/// A goal of the form `T: 'a` or `'a: 'b`.
+pub trait Outlives: Sized + Fold<Result = Self> {
+    /// The region constraint that proving this goal imposes.
+    fn to_constraint(&self) -> Constraint;
+}
+
+impl Outlives for TypeOutlives {
+    fn to_constraint(&self) -> Constraint {
+        Constraint::TypeOutlives(self.ty.clone(), self.lifetime)
+    }
+}
+
+impl Outlives for LifetimeOutlives {
+    fn to_constraint(&self) -> Constraint {
+        Constraint::LifetimeOutlives(self.a, self.b)
+    }
+}
+
+/// Solves outlives goals. Like rustc, we do not check region
+/// relationships while solving; the goal always holds, and the
+/// relation is passed back as a constraint in the solution.
+pub struct SolveOutlives<'s, G> {
+    fulfill: Fulfill<'s>,
+    environment: Arc<Environment>,
+    goal: G,
+}
+
+impl<'s, G: Outlives> SolveOutlives<'s, G> {
+    pub fn new(solver: &'s mut Solver, env_goal: Canonical<InEnvironment<G>>) -> Self {
+        let Canonical { binders, value: InEnvironment { environment, goal } } = env_goal;
+        let infer = InferenceTable::new_with_vars(&binders);
+        let fulfill = Fulfill::new(solver, infer);
+        SolveOutlives { fulfill, environment, goal }
+    }
+
+    pub fn solve(mut self) -> Result<Solution<InEnvironment<G>>> {
+        let environment = self.environment.clone();
+        let constraint = self.goal.to_constraint();
+        self.fulfill.add_constraint(&environment, constraint);
+        let successful = self.fulfill.solve_all()?;
+        let refined_goal = self.fulfill.refine_goal(InEnvironment::new(&environment, &self.goal));
+        Ok(Solution {
+            successful: successful,
+            refined_goal: refined_goal,
+        })
+    }
+}
@@ -0,0 +1,63 @@
+use ir::*;
+use solve::solver::CachedResult;
+
+/// A record of how the solver went about a goal: which clauses turned
+/// out to apply to it, and how the subgoals of each were solved in
+/// turn. See `Solver::record_proof_trees`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofTree {
+    pub goal: Canonical<InEnvironment<WhereClauseGoal>>,
+
+    /// The solution found for `goal`, or the message of the error if
+    /// it failed. The refined goal of the solution shows what the
+    /// proof substituted for the variables of `goal`.
+    pub result: CachedResult,
+
+    pub kind: ProofKind,
+
+    /// The clauses that apply to `goal`, each with the proofs of the
+    /// subgoals it led to.
+    pub candidates: Vec<ProofCandidate>,
+
+    /// The clauses that were tried for `goal` but did not apply, each
+    /// with the proofs of the subgoals solved before it was given up
+    /// on. See `WhyNot`.
+    pub rejected: Vec<ProofCandidate>,
+
+    /// The subgoals solved on the way to `goal` that are not due to
+    /// any one clause, such as the normalizations that unifying two
+    /// types can call for.
+    pub subproofs: Vec<ProofTree>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProofKind {
+    /// The goal was solved by searching for clauses that prove it.
+    Search,
+
+    /// The goal was solved before, and its result remembered.
+    Cached,
+
+    /// The goal was already being solved further up the tree; its
+    /// result is the one assumed for the cycle.
+    Cycle,
+
+    /// The goal was nested too deep to solve, and so is ambiguous.
+    Overflow,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofCandidate {
+    pub clause: ProofClause,
+    pub subproofs: Vec<ProofTree>,
+}
+
+/// A clause that a goal can be proven with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProofClause {
+    /// A where-clause from the environment.
+    Hypothesis(WhereClause),
+
+    /// A clause of the program, such as the one for an impl.
+    Program(ProgramClause),
+}
@@ -0,0 +1,554 @@
+//! An alternative to the recursive solver, based on SLG resolution.
+//!
+//! The recursive solver proves a trait goal by searching depth-first
+//! through the clauses that might prove it, which can send it down an
+//! infinite path even when there are perfectly good answers elsewhere.
+//! Here, instead, each distinct (canonical) trait goal we come across
+//! gets a *table* of the answers found for it so far. Solving a goal
+//! consumes the answers in the tables of its subgoals, and the tables
+//! are extended round by round until none of them changes: every
+//! answer reachable by a finite proof is eventually found, whatever
+//! order the clauses come in.
+//!
+//! Built up from nothing, the tables would only ever hold answers
+//! with finite proofs. As in the recursive solver, though, a cycle of
+//! coinductive goals (those of auto traits, say) holds: the table for
+//! such a goal starts out with the goal itself as a provisional
+//! answer. If, once the tables are complete, that answer has not been
+//! derived again, it was never justified; the goal is then marked as
+//! refuted, and the tables, some of which may have used the answer,
+//! are built again without it.
+//!
+//! Only `Implemented` goals are tabled. The remaining goals --
+//! unification, normalization, region constraints and the like --
+//! always have a single result, and are handed to the recursive
+//! solver, as are negated goals.
+
+use cast::Cast;
+use errors::*;
+use fold::Fold;
+use ir::*;
+use solve::{Solution, Successful};
+use solve::fulfill::{instantiate_universally, solve_negative};
+use solve::guidance::guidance;
+use solve::implemented::Implemented;
+use solve::infer::{InferenceTable, UnificationResult};
+use solve::solver::Solver;
+use solve::subsumption::{self, add_answer};
+use solve::truncate::truncate;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::mem;
+use std::sync::Arc;
+use std::time::Instant;
+use zip::Zip;
+
+type CanonicalGoal = Canonical<InEnvironment<WhereClauseGoal>>;
+
+pub struct SlgSolver {
+    /// Solves the goals that are not tabled.
+    solver: Solver,
+
+    /// How many tables there may be, and how many answers each table
+    /// may hold. A goal that would need more is ambiguous. This is
+    /// also the largest a type in a tabled goal may be before the goal
+    /// is truncated (see `truncate`).
+    max_size: usize,
+
+    tables: Vec<Table>,
+    table_indices: HashMap<CanonicalGoal, usize>,
+
+    /// The coinductive goals whose provisional answer turned out not to
+    /// hold, and whose tables so start out empty.
+    refuted: HashSet<CanonicalGoal>,
+}
+
+struct Table {
+    goal: CanonicalGoal,
+    answers: Vec<Answer<InEnvironment<WhereClauseGoal>>>,
+
+    /// Set once `goal` has had more answers than fit in the table, so
+    /// that there may be more answers than `answers`.
+    truncated: bool,
+
+    /// Set while `goal` is coinductive and the first of `answers` is
+    /// only assumed, without having been derived yet.
+    provisional: bool,
+}
+
+/// One way of proving a goal: the goal refined by the proof, and
+/// whether the proof relied on anything ambiguous.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct Answer<G> {
+    pub(super) refined_goal: Canonical<Constrained<G>>,
+    pub(super) ambiguous: bool,
+}
+
+/// A partial proof: the subgoals that remain to be proven, in the
+/// inference context of the proof.
+#[derive(Clone)]
+pub(super) struct Strand {
+    pub(super) infer: InferenceTable,
+    pub(super) leaves: Vec<InEnvironment<WhereClauseGoal>>,
+
+    /// Subgoals whose result was ambiguous, to be tried again once
+    /// the rest of the proof has told us more about them.
+    deferred: Vec<InEnvironment<WhereClauseGoal>>,
+    negatives: Vec<InEnvironment<Goal>>,
+    constraints: Vec<InEnvironment<Constraint>>,
+    ambiguous: bool,
+
+    /// Set when a subgoal is proven, so that the deferred subgoals are
+    /// worth another try.
+    progress: bool,
+}
+
+impl SlgSolver {
+    /// Creates a solver that allows for `max_size` tables of at most
+    /// `max_size` answers each.
+    pub fn new(program: &Arc<ChalkDatabase>, max_size: usize) -> Self {
+        SlgSolver {
+            solver: Solver::new(program, max_size),
+            max_size,
+            tables: vec![],
+            table_indices: HashMap::new(),
+            refuted: HashSet::new(),
+        }
+    }
+
+    /// Gives up on solving once `deadline` has passed; see
+    /// `Solver::set_deadline`.
+    pub fn set_deadline(&mut self, deadline: Option<Instant>) {
+        self.solver.set_deadline(deadline);
+    }
+
+    pub fn solve(&mut self, goal: Box<Goal>) -> Result<Solution<Vec<WhereClauseGoal>>> {
+        debug_heading!("SlgSolver::solve({:?})", goal);
+
+        let mut strand = Strand::new(InferenceTable::new());
+        strand.push_goal(*goal, &Environment::new());
+        let goals: Vec<_> = strand.leaves.iter().map(|leaf| leaf.goal.clone()).collect();
+        let start_goal = strand.infer.canonicalize(&goals).quantified;
+
+        // Any table that a run over the goal creates is filled in
+        // before the goal is run again; once a run needs no new
+        // tables, the answers it found are all there are.
+        let mut answers;
+        loop {
+            let tables = self.tables.len();
+            answers = vec![];
+            self.run_strand(strand.clone(), &goals, &mut answers)?;
+            if self.tables.len() == tables {
+                break;
+            }
+            self.complete_tables()?;
+        }
+
+        debug!("SlgSolver::solve: answers={:#?}", answers);
+
+        self.solver.or_timed_out(answers_solution(&start_goal, answers))
+    }
+
+    /// Extends the tables, round by round, until none of them gains a
+    /// new answer. If any provisional answer is then still unfounded,
+    /// the tables are thrown away instead, to be built again without
+    /// it.
+    fn complete_tables(&mut self) -> Result<()> {
+        loop {
+            let mut changed = false;
+            let mut index = 0;
+            while index < self.tables.len() {
+                changed |= self.extend_table(index)?;
+                index += 1;
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        let refuted: Vec<_> = self.tables
+                                  .iter()
+                                  .filter(|table| table.provisional)
+                                  .map(|table| table.goal.clone())
+                                  .collect();
+        if !refuted.is_empty() {
+            debug!("complete_tables: refuted {:?}", refuted);
+            self.refuted.extend(refuted);
+            self.tables.clear();
+            self.table_indices.clear();
+        }
+        Ok(())
+    }
+
+    /// Finds the answers to the goal of the table at `index`, given
+    /// the answers in the tables so far, and adds any that are new.
+    /// Returns true if the table changed.
+    fn extend_table(&mut self, index: usize) -> Result<bool> {
+        let goal = self.tables[index].goal.clone();
+        debug_heading!("extend_table({:?})", goal);
+
+        let mut answers = vec![];
+        self.derive_answers(&goal, &mut answers)?;
+
+        let max_size = self.max_size;
+        let table = &mut self.tables[index];
+        if table.provisional && answers.iter().any(|a| a.subsumes(&Answer::assumed(&goal))) {
+            debug!("extend_table: provisional answer confirmed");
+            table.provisional = false;
+        }
+        let mut changed = false;
+        for answer in answers {
+            if table.answers.iter().any(|a| a.subsumes(&answer)) {
+                continue;
+            }
+            let len = table.answers.len();
+            table.answers.retain(|a| !answer.subsumes(a));
+            changed |= table.answers.len() != len;
+            if table.answers.len() < max_size {
+                debug!("extend_table: new answer {:?}", answer);
+                table.answers.push(answer);
+                changed = true;
+            } else if !table.truncated {
+                debug!("extend_table: too many answers");
+                table.truncated = true;
+                changed = true;
+            }
+        }
+        Ok(changed)
+    }
+
+    /// Resolves the trait goal `goal` against each clause that might
+    /// prove it, pushing the answers onto `answers`.
+    fn derive_answers(&mut self,
+                      goal: &CanonicalGoal,
+                      answers: &mut Vec<Answer<InEnvironment<WhereClauseGoal>>>)
+                      -> Result<()> {
+        let InEnvironment { ref environment, goal: ref wc } = goal.value;
+        let trait_ref = match *wc {
+            WhereClauseGoal::Implemented(ref trait_ref) => trait_ref.clone(),
+            _ => panic!("only trait goals are tabled, not `{:?}`", wc),
+        };
+
+        let env_goal = goal.clone().map(|g| InEnvironment::new(&g.environment, trait_ref.clone()));
+        if Implemented::blocked_by_negative_impl(&mut self.solver, &env_goal) {
+            return Ok(());
+        }
+
+        let strand = Strand::new(InferenceTable::new_with_vars(&goal.binders));
+        let program = self.solver.program.clone();
+        for strand in clause_strands(&*program, &strand, environment, &trait_ref) {
+            self.run_strand(strand, &goal.value, answers)?;
+        }
+
+        Ok(())
+    }
+
+    /// The index of the table for `goal`, creating it if need be; or
+    /// `None` if there is no room for one.
+    fn table_index(&mut self, goal: CanonicalGoal) -> Option<usize> {
+        if let Some(&index) = self.table_indices.get(&goal) {
+            return Some(index);
+        }
+        if self.tables.len() >= self.max_size {
+            return None;
+        }
+        let index = self.tables.len();
+        let provisional = self.solver.is_coinductive(&goal) && !self.refuted.contains(&goal);
+        let answers = if provisional { vec![Answer::assumed(&goal)] } else { vec![] };
+        self.table_indices.insert(goal.clone(), index);
+        self.tables.push(Table { goal, answers, truncated: false, provisional });
+        Some(index)
+    }
+
+    /// Proves the remaining subgoals of `strand` in every way the
+    /// tables allow, pushing an answer to `goal` onto `answers` for
+    /// each proof.
+    fn run_strand<G>(&mut self, mut strand: Strand, goal: &G, answers: &mut Vec<Answer<G::Result>>)
+                     -> Result<()>
+        where G: Fold, G::Result: Zip + Fold<Result = G::Result> + Clone + Debug + PartialEq
+    {
+        self.solver.check_deadline()?;
+
+        while let Some(leaf) = strand.next_leaf() {
+            if let WhereClauseGoal::Implemented(_) = leaf.goal {
+                let mut canonical = strand.infer.canonicalize(&leaf).quantified;
+                if let Some(truncated) = truncate(&canonical, self.max_size) {
+                    debug!("run_strand: truncated {:?} to {:?}", canonical, truncated);
+                    canonical = truncated;
+                    strand.ambiguous = true;
+                }
+                let index = match self.table_index(canonical) {
+                    Some(index) => index,
+                    None => {
+                        strand.ambiguous = true;
+                        continue;
+                    }
+                };
+
+                // Each answer in the table is a different way of
+                // proving the leaf, and so a separate strand.
+                let table_answers = self.tables[index].answers.clone();
+                if self.tables[index].truncated {
+                    strand.ambiguous = true;
+                }
+                for answer in table_answers {
+                    let mut strand = strand.clone();
+                    if strand.apply_answer(&leaf, &answer).is_ok() {
+                        strand.ambiguous |= answer.ambiguous;
+                        self.run_strand(strand, goal, answers)?;
+                    }
+                }
+                return Ok(());
+            }
+
+            if !strand.solve_leaf(&mut self.solver, leaf) {
+                return Ok(());
+            }
+        }
+
+        if let Some(answer) = strand.into_answer(&mut self.solver, goal) {
+            debug!("run_strand: answer {:?}", answer);
+            add_answer(answers, answer, Answer::subsumes);
+        }
+        Ok(())
+    }
+}
+
+/// The solution given by the `answers` found to `start_goal`: an error
+/// if there are none, and ambiguous if there is more than one or the
+/// one is itself ambiguous.
+pub(super) fn answers_solution<G>(start_goal: &Canonical<G>,
+                                  mut answers: Vec<Answer<G>>)
+                                  -> Result<Solution<G>>
+    where G: Zip + Fold<Result = G> + Clone + Debug
+{
+    if answers.is_empty() {
+        bail!("no way to prove `{:?}`", start_goal.value);
+    }
+
+    if answers.len() == 1 && !answers[0].ambiguous {
+        let answer = answers.pop().unwrap();
+        return Ok(Solution { successful: Successful::Yes, refined_goal: answer.refined_goal });
+    }
+
+    let candidates: Vec<_> = answers.into_iter().map(|answer| answer.into_solution()).collect();
+    Ok(Solution {
+        successful: Successful::Maybe,
+        refined_goal: guidance(start_goal, &candidates),
+    })
+}
+
+/// Copies of `strand` in which the trait goal `trait_ref` has been
+/// resolved against each clause that might prove it: the hypotheses
+/// of `environment`, and the clauses of the program. The conditions
+/// of a program clause become leaves of its strand.
+pub(super) fn clause_strands(program: &ChalkDatabase,
+                             strand: &Strand,
+                             environment: &Arc<Environment>,
+                             trait_ref: &TraitRef)
+                             -> Vec<Strand> {
+    let mut strands = vec![];
+
+    for clause in environment.elaborated_clauses(program) {
+        let mut strand = strand.clone();
+
+        // As in `MatchClause`, the binders of a higher-ranked
+        // hypothesis may be chosen freely.
+        let mut clause = clause;
+        while let WhereClause::ForAll(binders) = clause {
+            clause = *strand.infer.instantiate(
+                binders.binders.iter().map(|pk| pk.map(|_| environment.universe)),
+                &binders.value);
+        }
+
+        let trait_clause: WhereClause = trait_ref.clone().cast();
+        if strand.unify(environment, &trait_clause, &clause).is_ok() {
+            strands.push(strand);
+        }
+    }
+
+    // As in `MatchAny`, the where-clauses that prove the goal win out
+    // over the impls.
+    if !strands.is_empty() {
+        return strands;
+    }
+
+    let trait_goal: WhereClauseGoal = trait_ref.clone().cast();
+    let program_clauses = program.program_clauses_for(&trait_goal).into_iter().chain(
+        program.default_program_clauses_for(&trait_goal)
+               .into_iter()
+               .filter(|default_clause| !default_clause.may_be_specialized(&trait_goal))
+               .map(|default_clause| &default_clause.clause));
+    for program_clause in program_clauses {
+        let mut strand = strand.clone();
+        let implication = strand.infer.instantiate(
+            program_clause.implication.binders.iter().map(|pk| pk.map(|_| environment.universe)),
+            &program_clause.implication.value);
+        if strand.unify(environment, &trait_goal, &implication.consequence).is_ok() {
+            for condition in implication.conditions {
+                strand.push_goal(condition, environment);
+            }
+            strands.push(strand);
+        }
+    }
+
+    strands
+}
+
+impl<G: Clone> Answer<G> {
+    /// The answer that `goal` holds as it is, without refinement.
+    fn assumed(goal: &Canonical<G>) -> Self {
+        Answer {
+            refined_goal: goal.clone().map(|value| Constrained { value, constraints: vec![] }),
+            ambiguous: false,
+        }
+    }
+}
+
+impl<G> Answer<G> {
+    pub(super) fn into_solution(self) -> Solution<G> {
+        Solution {
+            successful: if self.ambiguous { Successful::Maybe } else { Successful::Yes },
+            refined_goal: self.refined_goal,
+        }
+    }
+
+    /// True if this answer makes `other` redundant, as with
+    /// `Solution::subsumes`.
+    pub(super) fn subsumes(&self, other: &Self) -> bool
+        where G: Zip + Fold<Result = G> + Clone + Debug + PartialEq
+    {
+        (!self.ambiguous || other.ambiguous) &&
+            subsumption::subsumes(&self.refined_goal, &other.refined_goal)
+    }
+}
+
+impl Strand {
+    pub(super) fn new(infer: InferenceTable) -> Self {
+        Strand {
+            infer,
+            leaves: vec![],
+            deferred: vec![],
+            negatives: vec![],
+            constraints: vec![],
+            ambiguous: false,
+            progress: false,
+        }
+    }
+
+    /// Breaks `goal` down into the subgoals that make it up; this is
+    /// the counterpart of `Fulfill::push_goal`.
+    pub(super) fn push_goal(&mut self, goal: Goal, environment: &Arc<Environment>) {
+        match goal {
+            Goal::Quantified(QuantifierKind::ForAll, subgoal) => {
+                let (new_environment, subgoal) = instantiate_universally(environment, &subgoal);
+                self.push_goal(subgoal, &new_environment);
+            }
+            Goal::Quantified(QuantifierKind::Exists, subgoal) => {
+                let subgoal = self.infer.instantiate(
+                    subgoal.binders.iter().map(|pk| pk.map(|_| environment.universe)),
+                    &subgoal.value);
+                self.push_goal(*subgoal, environment);
+            }
+            Goal::Implies(wc, subgoal) => {
+                let new_environment = &environment.add_clauses(wc);
+                self.push_goal(*subgoal, new_environment);
+            }
+            Goal::And(subgoal1, subgoal2) => {
+                self.push_goal(*subgoal1, environment);
+                self.push_goal(*subgoal2, environment);
+            }
+            Goal::Not(subgoal) => {
+                self.negatives.push(InEnvironment::new(environment, *subgoal));
+            }
+            Goal::Leaf(wc) => {
+                self.leaves.push(InEnvironment::new(environment, wc));
+            }
+        }
+    }
+
+    fn unify<T>(&mut self, environment: &Arc<Environment>, a: &T, b: &T) -> Result<()>
+        where T: ?Sized + Zip + Debug
+    {
+        let UnificationResult { goals, constraints } = self.infer.unify(environment, a, b)?;
+        self.constraints.extend(constraints);
+        self.leaves.extend(goals);
+        Ok(())
+    }
+
+    /// Proves `leaf` by way of `answer`, an answer to (the canonical
+    /// form of) `leaf`. It is up to the caller to deal with an
+    /// ambiguous answer.
+    fn apply_answer(&mut self,
+                    leaf: &InEnvironment<WhereClauseGoal>,
+                    answer: &Answer<InEnvironment<WhereClauseGoal>>)
+                    -> Result<()> {
+        let Constrained { value, constraints } = self.infer.instantiate_canonical(&answer.refined_goal);
+        self.constraints.extend(constraints);
+        self.unify(&leaf.environment, leaf, &value)?;
+        if !answer.ambiguous {
+            self.progress = true;
+        }
+        Ok(())
+    }
+
+    /// Takes the next subgoal to prove, going back to the deferred
+    /// ones once the others have been dealt with, so long as that
+    /// taught us something.
+    pub(super) fn next_leaf(&mut self) -> Option<InEnvironment<WhereClauseGoal>> {
+        if self.leaves.is_empty() && self.progress && !self.deferred.is_empty() {
+            self.leaves = mem::replace(&mut self.deferred, vec![]);
+            self.progress = false;
+        }
+        self.leaves.pop()
+    }
+
+    /// Proves the untabled `leaf` with the recursive solver. Returns
+    /// false if it cannot hold, and so neither can the strand.
+    pub(super) fn solve_leaf(&mut self, solver: &mut Solver, leaf: InEnvironment<WhereClauseGoal>) -> bool {
+        let canonical = self.infer.canonicalize(&leaf).quantified;
+        let solution = match solver.solve(canonical) {
+            Ok(solution) => solution,
+            Err(_) => return false,
+        };
+        let answer = Answer {
+            ambiguous: solution.successful == Successful::Maybe,
+            refined_goal: solution.refined_goal,
+        };
+        if self.apply_answer(&leaf, &answer).is_err() {
+            return false;
+        }
+        if answer.ambiguous {
+            self.deferred.push(leaf);
+        }
+        true
+    }
+
+    /// Once there are no subgoals left, checks the negated goals and
+    /// makes the answer to `goal` that the strand proves, if it still
+    /// holds.
+    pub(super) fn into_answer<G>(mut self, solver: &mut Solver, goal: &G) -> Option<Answer<G::Result>>
+        where G: Fold
+    {
+        if !self.deferred.is_empty() {
+            self.ambiguous = true;
+        }
+
+        for negative in mem::replace(&mut self.negatives, vec![]) {
+            let canonical = self.infer.canonicalize(&negative).quantified;
+            match solve_negative(solver, &canonical) {
+                Ok(Successful::Yes) => { }
+                Ok(Successful::Maybe) => self.ambiguous = true,
+                Err(_) => return None,
+            }
+        }
+
+        let mut constraints = mem::replace(&mut self.constraints, vec![]);
+        constraints.sort();
+        constraints.dedup();
+        Some(Answer {
+            refined_goal: self.infer.canonicalize(&Constrained { value: goal, constraints }).quantified,
+            ambiguous: self.ambiguous,
+        })
+    }
+}
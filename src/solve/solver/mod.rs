@@ -1,114 +1,944 @@
 use cast::Cast;
 use errors::*;
+use fold::Fold;
+use solve::delegate::SolverDelegate;
+use solve::fulfill::Answers;
+use solve::goal::Prove;
+use solve::guidance::guidance;
+use solve::match_any::MatchAny;
 use solve::match_program_clause::MatchProgramClause;
 use solve::normalize::SolveNormalize;
 use solve::not_unify::SolveNotUnify;
+use solve::numeric::SolveNumeric;
+use solve::outlives::SolveOutlives;
+use solve::proof_tree::{ProofCandidate, ProofClause, ProofKind, ProofTree};
 use solve::implemented::Implemented;
+use solve::infer::UniverseMap;
+use solve::subsumption::add_answer;
+use solve::subtype::SolveSubtype;
+use solve::truncate::truncate;
 use solve::unify::SolveUnify;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::mem;
 use std::sync::Arc;
+use std::time::Instant;
+use zip::Zip;
 
 use super::*;
 
 pub struct Solver {
-    pub(super) program: Arc<ProgramEnvironment>,
+    pub(super) program: Arc<ChalkDatabase>,
     overflow_depth: usize,
-    stack: Vec<Query<InEnvironment<WhereClauseGoal>>>,
+
+    /// How large the types in a trait goal may grow before the goal is
+    /// truncated; see `set_max_size`.
+    max_size: usize,
+
+    stack: Vec<StackEntry>,
+
+    /// The answers assumed so far for the goals on the stack that
+    /// were found to depend on themselves; `None` means assumed not
+    /// to hold.
+    provisional: HashMap<Canonical<InEnvironment<WhereClauseGoal>>,
+                         Option<Solution<InEnvironment<WhereClauseGoal>>>>,
+
+    /// The stack of goals at the point where we first nested deeper
+    /// than `overflow_depth`, ending with the goal we gave up on.
+    overflow: Option<Vec<Canonical<InEnvironment<WhereClauseGoal>>>>,
+
+    /// The results of the goals solved so far, each with the message of
+    /// the error if it failed. Results that rest on the answers assumed
+    /// for a cycle, which may yet change, are left out.
+    cache: HashMap<Canonical<InEnvironment<WhereClauseGoal>>, CachedResult>,
+
+    /// For each cached result, the items whose clauses were consulted in
+    /// reaching it (see `WhereClauseGoal::item_id`); editing any other
+    /// item leaves the result as it is.
+    dependencies: HashMap<Canonical<InEnvironment<WhereClauseGoal>>, HashSet<ItemId>>,
+
+    /// While the solver is forked, or in a solver made from a fork, what
+    /// `provisional`, `cache` and `dependencies` held when it was
+    /// forked, shared with the forks rather than copied into each of
+    /// them; those then hold only what was added since.
+    snapshot: Option<Arc<Snapshot>>,
+
+    /// While recording proof trees, the proofs still under way,
+    /// innermost last.
+    proof_frames: Option<Vec<ProofFrame>>,
+
+    /// The finished proof trees of the goals solved at the top level.
+    proof_trees: Vec<ProofTree>,
+
+    delegate: Option<Box<SolverDelegate>>,
+
+    /// When to give up on solving; see `set_deadline`.
+    deadline: Option<Instant>,
+
+    /// Set once the deadline has passed.
+    timed_out: bool,
+
+    /// Whether to explore the candidates for each goal in a stable
+    /// order; see `set_sort_candidates`.
+    sort_candidates: bool,
+}
+
+/// How large the types in a trait goal may grow before a solver
+/// truncates it, unless told otherwise with `Solver::set_max_size`.
+pub const DEFAULT_MAX_SIZE: usize = 64;
+
+pub type CachedResult = ::std::result::Result<Solution<InEnvironment<WhereClauseGoal>>, String>;
+
+fn cached_result(result: &Result<Solution<InEnvironment<WhereClauseGoal>>>) -> CachedResult {
+    match *result {
+        Ok(ref solution) => Ok(solution.clone()),
+        Err(ref error) => Err(error.to_string()),
+    }
+}
+
+#[derive(Clone)]
+struct StackEntry {
+    goal: Canonical<InEnvironment<WhereClauseGoal>>,
+
+    /// Set when `goal` is reached again while solving it.
+    cycle: bool,
+
+    /// The lowest position on the stack of a goal whose assumed result
+    /// the result of `goal` relies on. If that is below `goal` itself,
+    /// the result is provisional, and not cached.
+    depends_on: Option<usize>,
+
+    /// The items whose clauses were consulted in solving `goal`.
+    items: HashSet<ItemId>,
+}
+
+/// What a solver had assumed and cached when it was forked; see
+/// `Solver::forks`.
+struct Snapshot {
+    provisional: HashMap<Canonical<InEnvironment<WhereClauseGoal>>,
+                         Option<Solution<InEnvironment<WhereClauseGoal>>>>,
+    cache: HashMap<Canonical<InEnvironment<WhereClauseGoal>>, CachedResult>,
+    dependencies: HashMap<Canonical<InEnvironment<WhereClauseGoal>>, HashSet<ItemId>>,
+
+    /// The snapshot of the solver that the forked solver was itself
+    /// made from, if it was made from a fork.
+    parent: Option<Arc<Snapshot>>,
+}
+
+impl Snapshot {
+    fn provisional(&self,
+                   goal: &Canonical<InEnvironment<WhereClauseGoal>>)
+                   -> Option<&Option<Solution<InEnvironment<WhereClauseGoal>>>> {
+        match self.provisional.get(goal) {
+            Some(answer) => Some(answer),
+            None => self.parent.as_ref().and_then(|parent| parent.provisional(goal)),
+        }
+    }
+
+    fn cached(&self,
+              goal: &Canonical<InEnvironment<WhereClauseGoal>>)
+              -> Option<(&CachedResult, &HashSet<ItemId>)> {
+        match self.cache.get(goal) {
+            Some(result) => Some((result, &self.dependencies[goal])),
+            None => self.parent.as_ref().and_then(|parent| parent.cached(goal)),
+        }
+    }
+}
+
+/// The state a solver needs to go on solving goals on another thread,
+/// as split off by `Solver::forks`.
+pub struct Fork {
+    program: Arc<ChalkDatabase>,
+    overflow_depth: usize,
+    max_size: usize,
+    stack: Vec<StackEntry>,
+    provisional: HashMap<Canonical<InEnvironment<WhereClauseGoal>>,
+                         Option<Solution<InEnvironment<WhereClauseGoal>>>>,
+    overflow: Option<Vec<Canonical<InEnvironment<WhereClauseGoal>>>>,
+    cache: HashMap<Canonical<InEnvironment<WhereClauseGoal>>, CachedResult>,
+    dependencies: HashMap<Canonical<InEnvironment<WhereClauseGoal>>, HashSet<ItemId>>,
+    snapshot: Option<Arc<Snapshot>>,
+    deadline: Option<Instant>,
+    timed_out: bool,
+    sort_candidates: bool,
+}
+
+impl Fork {
+    /// Runs `op` with a solver made from the fork, returning the fork
+    /// as `op` left it.
+    pub fn run<F, T>(self, op: F) -> (Fork, T)
+        where F: FnOnce(&mut Solver) -> T
+    {
+        let Fork {
+            program, overflow_depth, max_size, stack, provisional, overflow, cache, dependencies,
+            snapshot, deadline, timed_out, sort_candidates,
+        } = self;
+        let mut solver = Solver {
+            program,
+            overflow_depth,
+            max_size,
+            stack,
+            provisional,
+            overflow,
+            cache,
+            dependencies,
+            snapshot,
+            proof_frames: None,
+            proof_trees: vec![],
+            delegate: None,
+            deadline,
+            timed_out,
+            sort_candidates,
+        };
+        let result = op(&mut solver);
+        let Solver {
+            program, overflow_depth, max_size, stack, provisional, overflow, cache, dependencies,
+            snapshot, deadline, timed_out, sort_candidates, ..
+        } = solver;
+        let fork = Fork {
+            program, overflow_depth, max_size, stack, provisional, overflow, cache, dependencies,
+            snapshot, deadline, timed_out, sort_candidates,
+        };
+        (fork, result)
+    }
+}
+
+enum ProofFrame {
+    Goal {
+        kind: ProofKind,
+        candidates: Vec<ProofCandidate>,
+        rejected: Vec<ProofCandidate>,
+        subproofs: Vec<ProofTree>,
+    },
+    Candidate(ProofCandidate),
 }
 
 impl Solver {
-    pub fn new(program: &Arc<ProgramEnvironment>, overflow_depth: usize) -> Self {
-        Solver { program: program.clone(), stack: vec![], overflow_depth, }
+    /// Creates a solver that treats goals nested more than
+    /// `overflow_depth` deep as ambiguous, rather than recursing into
+    /// them.
+    pub fn new(program: &Arc<ChalkDatabase>, overflow_depth: usize) -> Self {
+        Solver {
+            program: program.clone(),
+            stack: vec![],
+            provisional: HashMap::new(),
+            overflow_depth,
+            max_size: DEFAULT_MAX_SIZE,
+            overflow: None,
+            cache: HashMap::new(),
+            dependencies: HashMap::new(),
+            snapshot: None,
+            proof_frames: None,
+            proof_trees: vec![],
+            delegate: None,
+            deadline: None,
+            timed_out: false,
+            sort_candidates: false,
+        }
+    }
+
+    /// How deep goals may be nested before the solver gives up on
+    /// them as ambiguous.
+    pub fn overflow_depth(&self) -> usize {
+        self.overflow_depth
+    }
+
+    /// Truncates trait goals with types bigger than `max_size` (see
+    /// `solve::truncate`), solving the truncated goal in their place;
+    /// they can then only be found to hold ambiguously. This keeps
+    /// programs that build ever larger types from running into the
+    /// overflow depth on goals that have nothing to do with them.
+    pub fn set_max_size(&mut self, max_size: usize) {
+        self.max_size = max_size;
+    }
+
+    /// If solving has ever overflowed, the stack of goals that led to
+    /// the first overflow, outermost first; the last goal is the one
+    /// that was given up on.
+    pub fn overflow(&self) -> Option<&[Canonical<InEnvironment<WhereClauseGoal>>]> {
+        self.overflow.as_ref().map(|stack| &stack[..])
+    }
+
+    /// The cached results of the goals solved so far.
+    pub fn cache(&self) -> &HashMap<Canonical<InEnvironment<WhereClauseGoal>>, CachedResult> {
+        &self.cache
+    }
+
+    /// Forgets the cached results, so that every goal is solved afresh.
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+        self.dependencies.clear();
+    }
+
+    /// Solves goals against `program` from now on, such as after an
+    /// impl was added to or removed from the program the solver was
+    /// made with (see `Program::add_impl`). Only the cached results
+    /// that consulted the clauses of the `changed_items` are forgotten
+    /// (see `ProgramEnvironment::changed_items`), or all of them if
+    /// that is `None`; this relies on the two programs giving the same
+    /// ids to the items they share.
+    pub fn set_program(&mut self,
+                       program: &Arc<ChalkDatabase>,
+                       changed_items: Option<HashSet<ItemId>>) {
+        assert!(self.stack.is_empty());
+        match changed_items {
+            None => self.clear_cache(),
+            Some(changed) => {
+                let dependencies = &mut self.dependencies;
+                self.cache.retain(|goal, _| {
+                    let unaffected = dependencies[goal].is_disjoint(&changed);
+                    if !unaffected {
+                        dependencies.remove(goal);
+                    }
+                    unaffected
+                });
+            }
+        }
+        self.program = program.clone();
+    }
+
+    /// Starts recording a `ProofTree` for each goal solved from now on.
+    pub fn record_proof_trees(&mut self) {
+        if self.proof_frames.is_none() {
+            self.proof_frames = Some(vec![]);
+        }
+    }
+
+    /// Takes the proof trees recorded for the goals solved at the top
+    /// level (rather than as subgoals of another goal) since the last
+    /// call.
+    pub fn take_proof_trees(&mut self) -> Vec<ProofTree> {
+        mem::replace(&mut self.proof_trees, vec![])
+    }
+
+    /// Has `delegate` notified of each step the solver takes from now
+    /// on, in place of any earlier delegate.
+    pub fn set_delegate(&mut self, delegate: Box<SolverDelegate>) {
+        self.delegate = Some(delegate);
+    }
+
+    /// Stops notifying the delegate, returning it.
+    pub fn take_delegate(&mut self) -> Option<Box<SolverDelegate>> {
+        self.delegate.take()
+    }
+
+    /// Gives up on solving once `deadline` has passed, or never if it
+    /// is `None`. Every goal the solver is then asked to solve, and
+    /// any it was in the middle of, fails with `ErrorKind::TimedOut`,
+    /// and nothing is cached from them. Setting a new deadline lets
+    /// the solver carry on.
+    pub fn set_deadline(&mut self, deadline: Option<Instant>) {
+        assert!(self.stack.is_empty());
+        self.deadline = deadline;
+        self.timed_out = false;
+    }
+
+    /// Explores the candidates for each goal -- the where-clauses and
+    /// program clauses that might prove it -- sorted by how they print,
+    /// rather than in the order the program happens to give them. The
+    /// result of a goal is the same either way, but proof trees, the
+    /// order of answers and the like then come out the same from one
+    /// run (or platform) to the next.
+    pub fn set_sort_candidates(&mut self, sort_candidates: bool) {
+        self.sort_candidates = sort_candidates;
+    }
+
+    /// Whether the candidates for each goal are explored in a stable
+    /// order; see `set_sort_candidates`.
+    pub fn sort_candidates(&self) -> bool {
+        self.sort_candidates
+    }
+
+    /// Whether the deadline has passed. Results the solver returned
+    /// since are not to be trusted: a goal that failed because it
+    /// timed out may have been taken to say that some other goal
+    /// does not hold. See `or_timed_out`.
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    /// `result`, unless the deadline has passed, in which case
+    /// `ErrorKind::TimedOut`. Top-level solvers pass what they found
+    /// through this before returning it.
+    pub fn or_timed_out<T>(&self, result: Result<T>) -> Result<T> {
+        if self.timed_out {
+            Err(ErrorKind::TimedOut.into())
+        } else {
+            result
+        }
+    }
+
+    /// Fails with `ErrorKind::TimedOut` if the deadline has passed.
+    pub fn check_deadline(&mut self) -> Result<()> {
+        if !self.timed_out {
+            match self.deadline {
+                Some(deadline) if Instant::now() >= deadline => {
+                    debug!("check_deadline: timed out");
+                    self.timed_out = true;
+                }
+                _ => return Ok(()),
+            }
+        }
+        Err(ErrorKind::TimedOut.into())
+    }
+
+    /// Whether the solver can be split with `fork`. A solver that is
+    /// recording proof trees or notifying a delegate cannot, since
+    /// those must see each step in order.
+    pub fn can_fork(&self) -> bool {
+        self.proof_frames.is_none() && self.delegate.is_none()
+    }
+
+    /// Splits off `count` copies of the solver's state, with each of
+    /// which goals can be solved (on another thread, say) just as they
+    /// would be here; `join` then brings what each learned back in,
+    /// and `unfork` ends the split.
+    ///
+    /// The results cached so far are not copied into each fork, but
+    /// moved into a snapshot that all of them, and the solver itself,
+    /// share until `unfork`.
+    pub fn forks(&mut self, count: usize) -> Vec<Fork> {
+        assert!(self.can_fork());
+        let snapshot = Arc::new(Snapshot {
+            provisional: mem::replace(&mut self.provisional, HashMap::new()),
+            cache: mem::replace(&mut self.cache, HashMap::new()),
+            dependencies: mem::replace(&mut self.dependencies, HashMap::new()),
+            parent: self.snapshot.take(),
+        });
+        self.snapshot = Some(snapshot.clone());
+        (0..count).map(|_| Fork {
+            program: self.program.clone(),
+            overflow_depth: self.overflow_depth,
+            max_size: self.max_size,
+            stack: self.stack.clone(),
+            provisional: HashMap::new(),
+            overflow: self.overflow.clone(),
+            cache: HashMap::new(),
+            dependencies: HashMap::new(),
+            snapshot: Some(snapshot.clone()),
+            deadline: self.deadline,
+            timed_out: self.timed_out,
+            sort_candidates: self.sort_candidates,
+        }).collect()
+    }
+
+    /// Takes back the snapshot made by `forks`, once each of the forks
+    /// has been joined or dropped, merging what was learned since
+    /// into it.
+    pub fn unfork(&mut self) {
+        let snapshot = match self.snapshot.take() {
+            Some(snapshot) => snapshot,
+            None => return,
+        };
+        let Snapshot { provisional, cache, dependencies, parent } = match Arc::try_unwrap(snapshot) {
+            Ok(snapshot) => snapshot,
+            Err(_) => panic!("unfork: a fork is still alive"),
+        };
+        let added_provisional = mem::replace(&mut self.provisional, provisional);
+        self.provisional.extend(added_provisional);
+        let added_cache = mem::replace(&mut self.cache, cache);
+        self.cache.extend(added_cache);
+        let added_dependencies = mem::replace(&mut self.dependencies, dependencies);
+        self.dependencies.extend(added_dependencies);
+        self.snapshot = parent;
+    }
+
+    /// Merges a fork made by `forks` back in, as if the goals it
+    /// solved had been solved here. Forks should be joined in the
+    /// order those goals would otherwise have been solved in.
+    ///
+    /// Errors are not taken into the cache: their messages were
+    /// formatted on whatever thread the fork ran on, where the names
+    /// of the program's items may not be known.
+    pub fn join(&mut self, fork: Fork) {
+        let Fork { stack, overflow, cache, mut dependencies, timed_out, .. } = fork;
+        assert_eq!(stack.len(), self.stack.len());
+        for (entry, forked) in self.stack.iter_mut().zip(stack) {
+            entry.cycle |= forked.cycle;
+            entry.items.extend(forked.items);
+            entry.depends_on = match (entry.depends_on, forked.depends_on) {
+                (Some(a), Some(b)) => Some(::std::cmp::min(a, b)),
+                (a, b) => a.or(b),
+            };
+        }
+        if self.overflow.is_none() {
+            self.overflow = overflow;
+        }
+        self.timed_out |= timed_out;
+        for (goal, result) in cache {
+            let known = self.cache.contains_key(&goal) ||
+                self.snapshot.as_ref().and_then(|snapshot| snapshot.cached(&goal)).is_some();
+            if result.is_ok() && !known {
+                let items = dependencies.remove(&goal).unwrap();
+                self.dependencies.insert(goal.clone(), items);
+                self.cache.insert(goal, result);
+            }
+        }
+    }
+
+    /// Finds the answers to `goal` lazily, one each time the iterator
+    /// is advanced; see `Prove::answers`.
+    pub fn answers(&mut self, goal: Box<Goal>) -> Answers<Vec<WhereClauseGoal>> {
+        Prove::new(self, goal).answers()
     }
 
     /// Tries to solve one **closed** where-clause `wc` (in the given
     /// environment).
+    ///
+    /// A goal may turn out to depend on itself, as `Box<T>: Foo` does
+    /// given `impl<T: Foo> Foo for Box<T>`. The recursive use is then
+    /// answered with whatever we have assumed the goal's result to be
+    /// -- at first, that it does not hold, since an inductive proof
+    /// cannot use a goal to prove itself -- and the goal is re-solved
+    /// under each new answer until the answer stops changing.
+    ///
+    /// The exception is a cycle made up entirely of goals for
+    /// coinductive traits (such as auto traits), which simply holds.
     pub fn solve(&mut self,
-                 wc_env: Query<InEnvironment<WhereClauseGoal>>)
+                 wc_env: Canonical<InEnvironment<WhereClauseGoal>>)
                  -> Result<Solution<InEnvironment<WhereClauseGoal>>> {
-        debug_heading!("Solver::solve({:?})", wc_env);
+        if self.delegate.is_none() {
+            return self.solve_recorded(wc_env);
+        }
+
+        self.notify(|delegate| delegate.enter_goal(&wc_env));
+        let result = self.solve_recorded(wc_env.clone());
+        self.notify(|delegate| delegate.exit_goal(&wc_env, &result));
+        result
+    }
+
+    /// Solves `wc_env`, recording its proof tree if need be.
+    fn solve_recorded(&mut self,
+                      wc_env: Canonical<InEnvironment<WhereClauseGoal>>)
+                      -> Result<Solution<InEnvironment<WhereClauseGoal>>> {
+        if self.proof_frames.is_none() {
+            return self.solve_goal(wc_env);
+        }
 
-        if self.stack.contains(&wc_env) || self.stack.len() > self.overflow_depth {
-            // Recursive invocation or overflow
-            debug!("solve: {:?} already on the stack or overflowed max depth", wc_env);
-            return Ok(Solution {
-                successful: Successful::Maybe,
-                refined_goal: wc_env.map(|w| Constrained { value: w, constraints: vec![] })
+        self.push_proof_frame(ProofFrame::Goal {
+            kind: ProofKind::Search,
+            candidates: vec![],
+            rejected: vec![],
+            subproofs: vec![],
+        });
+        let result = self.solve_goal(wc_env.clone());
+        if let Some(ProofFrame::Goal { kind, candidates, rejected, subproofs }) = self.pop_proof_frame() {
+            self.add_subproof(ProofTree {
+                goal: wc_env,
+                result: cached_result(&result),
+                kind,
+                candidates,
+                rejected,
+                subproofs,
             });
         }
+        result
+    }
+
+    fn solve_goal(&mut self,
+                  wc_env: Canonical<InEnvironment<WhereClauseGoal>>)
+                  -> Result<Solution<InEnvironment<WhereClauseGoal>>> {
+        // Goals that differ only in how their universes are numbered
+        // are solved, and cached, as one.
+        let (wc_env, universes) = UniverseMap::compress(&wc_env);
+        let solution = match truncate(&wc_env, self.max_size) {
+            // The truncated goal is solved as it is, rather than
+            // truncated again: the variables it was cut down to count
+            // towards its size too.
+            Some(truncated) => {
+                debug!("solve: truncated {:?} to {:?}", wc_env, truncated);
+                self.solve_compressed(truncated)?;
+                Solution::maybe(wc_env)
+            }
+            None => self.solve_compressed(wc_env)?,
+        };
+        Ok(Solution {
+            successful: solution.successful,
+            refined_goal: universes.decompress(&solution.refined_goal),
+        })
+    }
+
+    fn solve_compressed(&mut self,
+                        wc_env: Canonical<InEnvironment<WhereClauseGoal>>)
+                        -> Result<Solution<InEnvironment<WhereClauseGoal>>> {
+        debug_heading!("Solver::solve({:?})", wc_env);
+
+        self.check_deadline()?;
+
+        // What the solver was forked with, if anything, is consulted
+        // after what it learned itself.
+        {
+            let snapshot = &self.snapshot;
+            let cached = match self.cache.get(&wc_env) {
+                Some(result) => Some((result, &self.dependencies[&wc_env])),
+                None => snapshot.as_ref().and_then(|snapshot| snapshot.cached(&wc_env)),
+            };
+            if let Some((cached, items)) = cached {
+                debug!("solve: {:?} is cached as {:?}", wc_env, cached);
+                if let Some(entry) = self.stack.last_mut() {
+                    entry.items.extend(items.iter().cloned());
+                }
+                // (Not `set_proof_kind`, as `cached` borrows the cache.)
+                if let Some(&mut ProofFrame::Goal { ref mut kind, .. }) =
+                    self.proof_frames.as_mut().and_then(|f| f.last_mut())
+                {
+                    *kind = ProofKind::Cached;
+                }
+                return match *cached {
+                    Ok(ref solution) => Ok(solution.clone()),
+                    Err(ref message) => Err(message.clone().into()),
+                };
+            }
+        }
+
+        if let Some(index) = self.stack.iter().position(|entry| entry.goal == wc_env) {
+            debug!("solve: {:?} already on the stack", wc_env);
+            self.depend_on(index);
+            self.set_proof_kind(ProofKind::Cycle);
+            if self.stack[index..].iter().all(|entry| self.is_coinductive(&entry.goal)) {
+                debug!("solve: {:?} is a coinductive cycle", wc_env);
+                return Ok(Solution {
+                    successful: Successful::Yes,
+                    refined_goal: wc_env.map(|w| Constrained { value: w, constraints: vec![] }),
+                });
+            }
+            self.stack[index].cycle = true;
+            let snapshot = &self.snapshot;
+            let assumed = match self.provisional.get(&wc_env) {
+                Some(answer) => Some(answer),
+                None => snapshot.as_ref().and_then(|snapshot| snapshot.provisional(&wc_env)),
+            };
+            return match assumed {
+                Some(&Some(ref solution)) => Ok(solution.clone()),
+                _ => bail!("`{:?}` depends on itself", wc_env.value.goal),
+            };
+        }
+
+        let overflowed = self.stack.len() > self.overflow_depth;
+        if overflowed {
+            debug!("solve: {:?} overflowed max depth", wc_env);
+            self.depend_on(0);
+            self.set_proof_kind(ProofKind::Overflow);
+            if self.overflow.is_none() {
+                let mut stack: Vec<_> = self.stack.iter().map(|entry| entry.goal.clone()).collect();
+                stack.push(wc_env.clone());
+                self.overflow = Some(stack);
+            }
+            return Ok(Solution::maybe(wc_env));
+        }
+
+        self.stack.push(StackEntry {
+            goal: wc_env.clone(),
+            cycle: false,
+            depends_on: None,
+            items: wc_env.value.goal.item_id().into_iter().collect(),
+        });
+
+        let mut iterations = 0;
+        let result;
+        loop {
+            // Only the proof from the last time around counts.
+            if let Some(&mut ProofFrame::Goal {
+                ref mut candidates, ref mut rejected, ref mut subproofs, ..
+            }) = self.proof_frames.as_mut().and_then(|f| f.last_mut())
+            {
+                candidates.clear();
+                rejected.clear();
+                subproofs.clear();
+            }
+
+            let solved = self.solve_new(wc_env.clone());
+
+            let entry = self.stack.last_mut().unwrap();
+            if !entry.cycle {
+                result = solved;
+                break;
+            }
+            entry.cycle = false;
+
+            // The result was computed from the answer we assumed for
+            // the recursive uses of `wc_env`; it is final once the two
+            // agree.
+            let answer = solved.as_ref().ok().cloned();
+            let assumed = self.provisional.get(&wc_env).cloned().unwrap_or(None);
+            if answer == assumed {
+                result = solved;
+                break;
+            }
+
+            iterations += 1;
+            if iterations > self.overflow_depth {
+                debug!("solve: {:?} did not reach a fixed point", wc_env);
+                result = Ok(Solution::maybe(wc_env.clone()));
+                break;
+            }
+            self.provisional.insert(wc_env.clone(), answer);
+        }
+
+        self.provisional.remove(&wc_env);
+        let entry = self.stack.pop().unwrap();
+        if let Some(parent) = self.stack.last_mut() {
+            parent.items.extend(entry.items.iter().cloned());
+        }
+        match entry.depends_on {
+            Some(index) if index < self.stack.len() => self.depend_on(index),
+            _ if self.timed_out => {}
+            _ => {
+                let cached = cached_result(&result);
+                self.cache.insert(wc_env.clone(), cached);
+                self.dependencies.insert(wc_env, entry.items);
+            }
+        }
+
+        debug!("Solver::solve: result={:?}", result);
+
+        result
+    }
+
+    /// Passes a notification on to the delegate, if there is one.
+    pub(super) fn notify<F>(&mut self, op: F)
+        where F: FnOnce(&mut SolverDelegate)
+    {
+        if let Some(ref mut delegate) = self.delegate {
+            op(&mut **delegate);
+        }
+    }
+
+    /// Applies a clause to a goal by way of `op`. If we are recording
+    /// proof trees, the clause is recorded as a candidate of the goal
+    /// if it applies, or as rejected if not, together with the proofs
+    /// of the subgoals that `op` solved.
+    pub(super) fn with_candidate<G, C, F>(&mut self, clause: C, op: F) -> Result<Solution<G>>
+        where C: FnOnce() -> ProofClause,
+              F: FnOnce(&mut Self) -> Result<Solution<G>>
+    {
+        if self.proof_frames.is_none() && self.delegate.is_none() {
+            return op(self);
+        }
+
+        let clause = clause();
+        self.notify(|delegate| delegate.select_candidate(&clause));
+        self.push_proof_frame(ProofFrame::Candidate(ProofCandidate {
+            clause: clause.clone(),
+            subproofs: vec![],
+        }));
+        let result = op(self);
+        if result.is_err() {
+            self.notify(|delegate| delegate.backtrack(&clause));
+        }
+        if let Some(ProofFrame::Candidate(candidate)) = self.pop_proof_frame() {
+            if let Some(&mut ProofFrame::Goal { ref mut candidates, ref mut rejected, .. }) =
+                self.proof_frames.as_mut().and_then(|f| f.last_mut())
+            {
+                if result.is_ok() {
+                    candidates.push(candidate);
+                } else {
+                    rejected.push(candidate);
+                }
+            }
+        }
+        result
+    }
+
+    fn push_proof_frame(&mut self, frame: ProofFrame) {
+        if let Some(ref mut frames) = self.proof_frames {
+            frames.push(frame);
+        }
+    }
+
+    fn pop_proof_frame(&mut self) -> Option<ProofFrame> {
+        self.proof_frames.as_mut().and_then(|frames| frames.pop())
+    }
+
+    fn set_proof_kind(&mut self, proof_kind: ProofKind) {
+        if let Some(&mut ProofFrame::Goal { ref mut kind, .. }) =
+            self.proof_frames.as_mut().and_then(|f| f.last_mut())
+        {
+            *kind = proof_kind;
+        }
+    }
 
-        self.stack.push(wc_env.clone());
+    /// Adds the proof of a subgoal to the proof under way, or to the
+    /// finished proof trees if there is none.
+    fn add_subproof(&mut self, tree: ProofTree) {
+        match self.proof_frames.as_mut().and_then(|f| f.last_mut()) {
+            Some(&mut ProofFrame::Goal { ref mut subproofs, .. }) => subproofs.push(tree),
+            Some(&mut ProofFrame::Candidate(ref mut candidate)) => candidate.subproofs.push(tree),
+            None => self.proof_trees.push(tree),
+        }
+    }
 
-        let Query { value: InEnvironment { environment, goal: wc }, binders } = wc_env;
+    /// Records that the result of the goal atop the stack relies on the
+    /// answer assumed for the goal at `index`.
+    fn depend_on(&mut self, index: usize) {
+        if let Some(entry) = self.stack.last_mut() {
+            entry.depends_on = Some(entry.depends_on.map_or(index, |d| ::std::cmp::min(d, index)));
+        }
+    }
 
-        let result = match wc {
+    pub(super) fn is_coinductive(&self, wc_env: &Canonical<InEnvironment<WhereClauseGoal>>) -> bool {
+        match wc_env.value.goal {
+            WhereClauseGoal::Implemented(ref trait_ref) => {
+                self.program
+                    .trait_datum(trait_ref.trait_id)
+                    .map_or(false, |datum| datum.binders.value.flags.coinductive())
+            }
+            _ => false,
+        }
+    }
+
+    /// Solves `wc_env` from scratch, without consulting the stack.
+    fn solve_new(&mut self,
+                 wc_env: Canonical<InEnvironment<WhereClauseGoal>>)
+                 -> Result<Solution<InEnvironment<WhereClauseGoal>>> {
+        let Canonical { value: InEnvironment { environment, goal: wc }, binders } = wc_env;
+
+        match wc {
             WhereClauseGoal::Implemented(trait_ref) => {
-                let q = Query {
+                let q = Canonical {
                     value: InEnvironment::new(&environment, trait_ref),
                     binders: binders,
                 };
                 Implemented::new(self, q).solve().cast()
             }
             WhereClauseGoal::Normalize(normalize_to) => {
-                let q = Query {
+                let q = Canonical {
                     value: InEnvironment::new(&environment, normalize_to),
                     binders: binders,
                 };
                 SolveNormalize::new(self, q).solve().cast()
             }
+            WhereClauseGoal::NormalizeConst(normalize_to) => {
+                // Unlike types, there is no fallback for a const that
+                // cannot be resolved.
+                let q = Canonical {
+                    value: InEnvironment::new(&environment, normalize_to),
+                    binders: binders,
+                };
+                MatchAny::new(self, &q).solve().cast()
+            }
+            WhereClauseGoal::TypeOutlives(outlives) => {
+                let q = Canonical {
+                    value: InEnvironment::new(&environment, outlives),
+                    binders: binders,
+                };
+                SolveOutlives::new(self, q).solve().cast()
+            }
+            WhereClauseGoal::LifetimeOutlives(outlives) => {
+                let q = Canonical {
+                    value: InEnvironment::new(&environment, outlives),
+                    binders: binders,
+                };
+                SolveOutlives::new(self, q).solve().cast()
+            }
             WhereClauseGoal::UnifyTys(unify) => {
-                let q = Query {
+                let q = Canonical {
                     value: InEnvironment::new(&environment, unify),
                     binders: binders,
                 };
                 SolveUnify::new(self, q).solve().cast()
             }
+            WhereClauseGoal::Subtype(subtype) => {
+                let q = Canonical {
+                    value: InEnvironment::new(&environment, subtype),
+                    binders: binders,
+                };
+                SolveSubtype::new(self, q).solve().cast()
+            }
             WhereClauseGoal::NotUnifyTys(not_unify) => {
-                let q = Query {
+                let q = Canonical {
                     value: InEnvironment::new(&environment, not_unify),
                     binders: binders,
                 };
                 SolveNotUnify::new(self, q).solve().cast()
             }
             WhereClauseGoal::UnifyKrates(unify) => {
-                let q = Query {
+                let q = Canonical {
                     value: InEnvironment::new(&environment, unify),
                     binders: binders,
                 };
                 SolveUnify::new(self, q).solve().cast()
             }
             WhereClauseGoal::UnifyLifetimes(unify) => {
-                let q = Query {
+                let q = Canonical {
                     value: InEnvironment::new(&environment, unify),
                     binders: binders,
                 };
                 SolveUnify::new(self, q).solve().cast()
             }
+            WhereClauseGoal::WellFormed(wf) => {
+                let q = Canonical {
+                    value: InEnvironment::new(&environment, wf),
+                    binders: binders,
+                };
+                MatchAny::new(self, &q).solve().cast()
+            }
+            WhereClauseGoal::Numeric(numeric) => {
+                let q = Canonical {
+                    value: InEnvironment::new(&environment, numeric),
+                    binders: binders,
+                };
+                SolveNumeric::new(self, q).solve().cast()
+            }
             WhereClauseGoal::NotImplemented(_) |
             WhereClauseGoal::NotNormalize(_) |
             WhereClauseGoal::TyLocalTo(_) |
-            WhereClauseGoal::WellFormed(_) => {
-                // Currently, we don't allow `LocalTo` or `WF` types
-                // into the environment, there we just have to search
-                // for program clauses.
+            WhereClauseGoal::Unsize(_) => {
+                // Currently, we don't allow `LocalTo` or `Unsize` into
+                // the environment, there we just have to search for
+                // program clauses.
                 let program = self.program.clone();
-                let q = Query {
+                let q = Canonical {
                     value: InEnvironment::new(&environment, wc),
                     binders: binders
                 }; // reconstruct `wc_env`
-                self.solve_any(program.program_clauses.iter(), &q, |this, program_clause| {
-                    MatchProgramClause::new(this, &q, &program_clause).solve()
+                let mut program_clauses = program.program_clauses_for(&q.value.goal);
+                if self.sort_candidates {
+                    program_clauses.sort_by_key(|clause| format!("{:?}", clause));
+                }
+                self.solve_any(program_clauses, &q, false, |this, program_clause| {
+                    this.with_candidate(|| ProofClause::Program(program_clause.clone()), |this| {
+                        MatchProgramClause::new(this, &q, &program_clause).solve()
+                    })
                 })
             }
-        };
-
-        self.stack.pop().unwrap();
+        }
+    }
 
-        debug!("Solver::solve: result={:?}", result);
+    /// Like `solve`, but rather than collapsing the ways that `wc_env`
+    /// might hold into a single (possibly ambiguous) solution, returns
+    /// the distinct solution from each clause that applies. Only
+    /// trait and normalization goals are split up this way; for other
+    /// goals, this is just the result of `solve`.
+    pub fn solve_candidates(&mut self,
+                            wc_env: Canonical<InEnvironment<WhereClauseGoal>>)
+                            -> Result<Vec<Solution<InEnvironment<WhereClauseGoal>>>> {
+        debug_heading!("Solver::solve_candidates({:?})", wc_env);
 
-        result
+        let Canonical { value: InEnvironment { environment, goal: wc }, binders } = wc_env.clone();
+        match wc {
+            WhereClauseGoal::Implemented(trait_ref) => {
+                let q = Canonical {
+                    value: InEnvironment::new(&environment, trait_ref),
+                    binders: binders,
+                };
+                Ok(MatchAny::new(self, &q).candidates().into_iter().map(|s| s.cast()).collect())
+            }
+            WhereClauseGoal::Normalize(normalize_to) => {
+                let q = Canonical {
+                    value: InEnvironment::new(&environment, normalize_to),
+                    binders: binders,
+                };
+                let candidates = MatchAny::new(self, &q).candidates();
+                if candidates.is_empty() {
+                    Ok(vec![self.solve(wc_env)?])
+                } else {
+                    Ok(candidates.into_iter().map(|s| s.cast()).collect())
+                }
+            }
+            _ => Ok(vec![self.solve(wc_env)?]),
+        }
     }
 
     /// Given a closed goal `start_goal`, and a number of possible
@@ -122,12 +952,13 @@ impl Solver {
     /// because multiple possibilities resulted in the same solution.
     pub fn solve_any<P,Ps,E,R>(&mut self,
                                possibilities: Ps,
-                               start_goal: &Query<R>,
+                               start_goal: &Canonical<R>,
+                               allow_overlap: bool,
                                mut evaluate_possibility: E)
                                -> Result<Solution<R>>
         where Ps: IntoIterator<Item = P>,
               E: FnMut(&mut Solver, P) -> Result<Solution<R>>,
-              R: Clone + Hash + Eq + Debug,
+              R: Clone + Hash + Eq + Debug + Zip + Fold<Result = R>,
               P: Debug,
     {
         debug_heading!("solve_any()");
@@ -135,7 +966,7 @@ impl Solver {
         // For each impl, recursively apply it. Note that all we need
         // to verify is that `T: Foo` **is implemented**. We don't
         // actually need to know *which impl* implified with.
-        let mut candidates = HashSet::new();
+        let mut candidates = vec![];
         for possibility in possibilities {
             debug_heading!("possibility={:?}", possibility);
             if let Ok(solution) = evaluate_possibility(self, possibility) {
@@ -156,17 +987,14 @@ impl Solver {
                 // that the trait is implemented). But if there are
                 // multiple impls, perhaps with distinct unifications,
                 // then we have to return an ambiguous result.
-                if let Successful::Yes = solution.successful {
-                    if {
-                        solution.refined_goal.binders == start_goal.binders &&
-                        solution.refined_goal.value.value == start_goal.value &&
-                            solution.refined_goal.value.constraints.is_empty()
-                    } {
-                        return Ok(solution);
-                    }
+                if solution.is_unconditional_for(start_goal) {
+                    return Ok(solution);
                 }
 
-                candidates.insert(solution);
+                // Candidates that a more general one makes redundant
+                // are dropped, so as not to make the goal look
+                // ambiguous when it is not.
+                add_answer(&mut candidates, solution, Solution::subsumes);
             } else {
                 debug!("error");
             }
@@ -180,17 +1008,31 @@ impl Solver {
             return Ok(candidates.into_iter().next().unwrap());
         }
 
+        // If overlap is allowed (i.e., for marker traits), it doesn't
+        // matter which candidate we use, so long as they all agree
+        // about what we learn about the goal; prefer one that
+        // succeeded outright.
+        if allow_overlap {
+            let agree = candidates.iter().all(|c| {
+                c.refined_goal.binders == candidates[0].refined_goal.binders &&
+                    c.refined_goal.value.value == candidates[0].refined_goal.value.value
+            });
+            if agree {
+                candidates.sort_by_key(|c| (c.successful != Successful::Yes,
+                                            c.refined_goal.value.constraints.len()));
+                return Ok(candidates.swap_remove(0));
+            }
+        }
+
         // There are multiple candidates and they don't agree about
-        // what we can infer thus far. Return an ambiguous
-        // result. This actually isn't as precise as it could be, in
-        // two ways:
+        // what we can infer thus far, so the result is ambiguous. We
+        // can still offer guidance, though: whichever candidate turns
+        // out to apply, the goal must be refined at least as far as
+        // what they all agree on. Two ways in which this isn't as
+        // precise as it could be:
         //
-        // a. It might be that while there are multiple distinct
-        //    candidates, they all agree about *some things*. To be
-        //    maximally precise, we would compute the intersection of
-        //    what they agree on. It's not clear though that this is
-        //    actually what we want Rust's inference to do, and it's
-        //    certainly not what it does today.
+        // a. We don't consider what the candidates' region
+        //    constraints have in common; the guidance has none.
         // b. There might also be an ambiguous candidate and a successful
         //    candidate, both with the same refined-goal. In that case,
         //    we could probably claim success, since if the conditions of the
@@ -199,9 +1041,9 @@ impl Solver {
         //    and successful candidate `Option<?0>: Clone`.
         //
         // But you get the idea.
-        return Ok(Solution {
+        Ok(Solution {
             successful: Successful::Maybe,
-            refined_goal: start_goal.clone().map(|g| Constrained { value: g, constraints: vec![] }),
-        });
+            refined_goal: guidance(start_goal, &candidates),
+        })
     }
 }
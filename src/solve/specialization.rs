@@ -0,0 +1,60 @@
+use errors::*;
+use ir::*;
+use zip::{Zip, Zipper};
+
+impl DefaultProgramClause {
+    /// True if an impl that overrides this clause might also apply to
+    /// `goal`. Since any variable (or placeholder) in the goal might
+    /// later turn out to be a type that the overriding impl matches,
+    /// we only rule an impl out when there is a definite mismatch.
+    pub fn may_be_specialized(&self, goal: &WhereClauseGoal) -> bool {
+        self.specializations.iter().any(|normalize| {
+            let pattern = WhereClauseGoal::Normalize(normalize.value.clone());
+            Zip::zip_with(&mut MayUnify, goal, &pattern).is_ok()
+        })
+    }
+}
+
+/// A zipper that fails only where two values definitely cannot be
+/// unified, treating every variable, placeholder and projection as a
+/// wildcard.
+struct MayUnify;
+
+impl Zipper for MayUnify {
+    fn zip_tys(&mut self, a: &Ty, b: &Ty) -> Result<()> {
+        match (a, b) {
+            (&Ty::Apply(ref apply1), &Ty::Apply(ref apply2)) => {
+                match (apply1.name, apply2.name) {
+                    (TypeName::ForAll(_), _) |
+                    (_, TypeName::ForAll(_)) |
+                    (TypeName::AssociatedType(_), _) |
+                    (_, TypeName::AssociatedType(_)) => Ok(()),
+                    (name1, name2) => {
+                        if name1 != name2 {
+                            bail!("`{:?}` and `{:?}` are distinct", a, b)
+                        }
+                        Zip::zip_with(self, &apply1.parameters, &apply2.parameters)
+                    }
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn zip_lifetimes(&mut self, _: &Lifetime, _: &Lifetime) -> Result<()> {
+        Ok(())
+    }
+
+    fn zip_krates(&mut self, _: &Krate, _: &Krate) -> Result<()> {
+        Ok(())
+    }
+
+    fn zip_consts(&mut self, a: &Const, b: &Const) -> Result<()> {
+        match (*a, *b) {
+            (Const::Value(v1), Const::Value(v2)) if v1 != v2 => {
+                bail!("`{:?}` and `{:?}` are distinct", a, b)
+            }
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! Telling when one answer to a goal makes another redundant.
+//!
+//! An answer that refines the goal `exists<T> { T: Foo }` to
+//! `Vec<?0>: Foo` already says everything that `Vec<i32>: Foo` does,
+//! and more; keeping both would only make the goal look ambiguous
+//! when it is not, and crowd out answers that do say something new.
+
+use fold::Fold;
+use ir::*;
+use solve::infer::InferenceTable;
+use std::fmt::Debug;
+use zip::Zip;
+
+/// True if `general` subsumes `specific`: that is, `specific` is
+/// `general` with some of its variables filled in, and holds under no
+/// fewer constraints.
+pub fn subsumes<G>(general: &Canonical<Constrained<G>>, specific: &Canonical<Constrained<G>>) -> bool
+    where G: Zip + Fold<Result = G> + Clone + Debug + PartialEq
+{
+    if general == specific {
+        return true;
+    }
+
+    // We have no way to relate the constraints of the two answers, so
+    // only an answer without any can subsume another.
+    if !general.value.constraints.is_empty() {
+        return false;
+    }
+
+    // Instantiate both answers in one table. `general` subsumes
+    // `specific` if they unify without binding any of the variables
+    // of `specific`, which would show up in its canonical form.
+    let mut infer = InferenceTable::new();
+    let specific_value = infer.instantiate_canonical(&specific.clone().map(|c| c.value));
+    let before = infer.canonicalize(&specific_value).quantified;
+    let general_value = infer.instantiate_canonical(&general.clone().map(|c| c.value));
+    match infer.unify(&Environment::new(), &general_value, &specific_value) {
+        Ok(ref result) if result.goals.is_empty() && result.constraints.is_empty() => {}
+        _ => return false,
+    }
+    infer.canonicalize(&specific_value).quantified == before
+}
+
+/// Adds `answer` to `answers`, unless it is subsumed by one of them;
+/// any that it subsumes in turn are dropped. Returns true if `answers`
+/// changed.
+pub fn add_answer<A, F>(answers: &mut Vec<A>, answer: A, subsumes: F) -> bool
+    where F: Fn(&A, &A) -> bool
+{
+    if answers.iter().any(|a| subsumes(a, &answer)) {
+        return false;
+    }
+    answers.retain(|a| !subsumes(&answer, a));
+    answers.push(answer);
+    true
+}
@@ -0,0 +1,114 @@
+use cast::Cast;
+use errors::*;
+use ir::*;
+use solve::infer::InferenceTable;
+use solve::fulfill::Fulfill;
+use solve::solver::Solver;
+use solve::Solution;
+use std::sync::Arc;
+
+/// Solves `Subtype(A, B)` goals one layer at a time: the parameters
+/// of `A` and `B` are related according to their variance, yielding
+/// further subtyping goals, equalities and outlives constraints.
+pub struct SolveSubtype<'s> {
+    fulfill: Fulfill<'s>,
+    environment: Arc<Environment>,
+    goal: Subtype,
+}
+
+impl<'s> SolveSubtype<'s> {
+    pub fn new(solver: &'s mut Solver, env_goal: Canonical<InEnvironment<Subtype>>) -> Self {
+        let Canonical { binders, value: InEnvironment { environment, goal } } = env_goal;
+        let infer = InferenceTable::new_with_vars(&binders);
+        let fulfill = Fulfill::new(solver, infer);
+        SolveSubtype { fulfill, environment, goal }
+    }
+
+    pub fn solve(mut self) -> Result<Solution<InEnvironment<Subtype>>> {
+        let environment = self.environment.clone();
+        let Subtype { a, b } = self.goal.clone();
+
+        match (a, b) {
+            // We do not generalize inference variables as rustc does;
+            // if either side is still unknown, the types must be equal.
+            (a @ Ty::Var(_), b) | (a, b @ Ty::Var(_)) |
+            (a @ Ty::Projection(_), b) | (a, b @ Ty::Projection(_)) => {
+                self.fulfill.unify(&environment, &a, &b)?;
+            }
+
+            // `A <: for<'a> B` if `A <: B` for all choices of `'a`.
+            (a, Ty::ForAll(quantified_ty)) => {
+                let mut environment = environment.clone();
+                let lifetimes: Vec<_> = (0..quantified_ty.num_binders)
+                    .map(|_| {
+                        environment = environment.new_universe();
+                        Lifetime::ForAll(environment.universe).cast()
+                    })
+                    .collect();
+                let b = quantified_ty.subst(&lifetimes);
+                self.push_subtype(&environment, a, b);
+            }
+
+            // `for<'a> A <: B` if `A <: B` for some choice of `'a`.
+            (Ty::ForAll(quantified_ty), b) => {
+                let binders = (0..quantified_ty.num_binders).map(|_| ParameterKind::Lifetime(()));
+                let a = self.fulfill.instantiate_in(environment.universe, binders, &quantified_ty.ty);
+                self.push_subtype(&environment, a, b);
+            }
+
+            (Ty::Apply(ref apply_a), Ty::Apply(ref apply_b)) => {
+                if apply_a.name != apply_b.name || apply_a.parameters.len() != apply_b.parameters.len() {
+                    bail!("`{:?}` is not a subtype of `{:?}`", apply_a, apply_b);
+                }
+
+                let program = self.fulfill.program();
+                let variances = apply_a.name.parameter_variances(apply_a.parameters.len(),
+                                                                 |id| program.variances(id));
+                for ((a, b), variance) in apply_a.parameters.iter().zip(&apply_b.parameters).zip(variances) {
+                    self.relate_parameters(&environment, variance, a, b)?;
+                }
+            }
+        }
+
+        let successful = self.fulfill.solve_all()?;
+        let refined_goal = self.fulfill.refine_goal(InEnvironment::new(&environment, &self.goal));
+        Ok(Solution {
+            successful: successful,
+            refined_goal: refined_goal,
+        })
+    }
+
+    fn relate_parameters(&mut self,
+                         environment: &Arc<Environment>,
+                         variance: Variance,
+                         a: &Parameter,
+                         b: &Parameter)
+                         -> Result<()> {
+        match (variance, a, b) {
+            (Variance::Bivariant, _, _) => { }
+            (Variance::Covariant, &ParameterKind::Ty(ref a), &ParameterKind::Ty(ref b)) => {
+                self.push_subtype(environment, a.clone(), b.clone());
+            }
+            (Variance::Contravariant, &ParameterKind::Ty(ref a), &ParameterKind::Ty(ref b)) => {
+                self.push_subtype(environment, b.clone(), a.clone());
+            }
+
+            // `&'a T <: &'b T` if `'a: 'b`.
+            (Variance::Covariant, &ParameterKind::Lifetime(a), &ParameterKind::Lifetime(b)) => {
+                self.fulfill.add_constraint(environment, Constraint::LifetimeOutlives(a, b));
+            }
+            (Variance::Contravariant, &ParameterKind::Lifetime(a), &ParameterKind::Lifetime(b)) => {
+                self.fulfill.add_constraint(environment, Constraint::LifetimeOutlives(b, a));
+            }
+
+            // Everything else, including crates and consts, which
+            // have no notion of subtyping, must simply be equal.
+            _ => self.fulfill.unify(environment, a, b)?,
+        }
+        Ok(())
+    }
+
+    fn push_subtype(&mut self, environment: &Arc<Environment>, a: Ty, b: Ty) {
+        self.fulfill.push_goal(Goal::Leaf(Subtype { a, b }.cast()), environment);
+    }
+}
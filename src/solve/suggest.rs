@@ -0,0 +1,105 @@
+//! Suggestions of the where-clauses that would make a failing goal
+//! hold, such as `T: Clone` for `forall<T> { Vec<T>: Clone }`.
+
+use ir::*;
+use solve::Successful;
+use solve::goal::Prove;
+use solve::solver::Solver;
+use solve::why_not::WhyNot;
+use std::sync::Arc;
+
+/// Tries to prove `goal` with the recursive solver, and if that fails,
+/// looks for where-clauses about its type parameters that, added to
+/// the environment of the first obligation that failed, make that
+/// obligation hold. None of the where-clauses returned can be left
+/// out. Returns `None` if the goal holds, or if there are no such
+/// where-clauses to be found.
+pub fn suggest_where_clauses(program: &Arc<ChalkDatabase>,
+                             overflow_depth: usize,
+                             goal: Box<Goal>)
+                             -> Option<Vec<WhereClause>> {
+    let mut solver = Solver::new(program, overflow_depth);
+    solver.record_proof_trees();
+    if Prove::new(&mut solver, goal).solve().is_ok() {
+        return None;
+    }
+    let mut why_not = solver.take_proof_trees().iter().filter_map(WhyNot::from_proof_tree).next()?;
+    let failed = why_not.goal.clone();
+    if !failed.binders.is_empty() {
+        return None;
+    }
+
+    // Each failure tells us of subgoals that a where-clause could take
+    // care of, but only of the first that failed for each candidate,
+    // so we go on until the goal holds or we learn nothing more.
+    let mut hypotheses = vec![];
+    loop {
+        let len = hypotheses.len();
+        collect_hypotheses(&why_not, failed.value.environment.universe, &mut hypotheses);
+        if hypotheses.len() == len {
+            return None;
+        }
+        match solve_with(program, overflow_depth, &failed, &hypotheses) {
+            Ok(()) => break,
+            Err(Some(next)) => why_not = next,
+            Err(None) => return None,
+        }
+    }
+
+    // Drop whichever of the where-clauses the goal holds without.
+    let mut index = 0;
+    while index < hypotheses.len() {
+        let hypothesis = hypotheses.remove(index);
+        if solve_with(program, overflow_depth, &failed, &hypotheses).is_err() {
+            hypotheses.insert(index, hypothesis);
+            index += 1;
+        }
+    }
+    Some(hypotheses)
+}
+
+/// Pushes onto `hypotheses` the failed trait goals of `why_not` whose
+/// self type is a type parameter of the environment (a placeholder no
+/// newer than `universe`), looking into the failed subgoals of the
+/// rest.
+fn collect_hypotheses(why_not: &WhyNot, universe: UniverseIndex, hypotheses: &mut Vec<WhereClause>) {
+    if why_not.goal.binders.is_empty() {
+        if let WhereClauseGoal::Implemented(ref trait_ref) = why_not.goal.value.goal {
+            if let Some(&Ty::Apply(ApplicationTy { name: TypeName::ForAll(ui), .. })) =
+                trait_ref.parameters[0].as_ref().ty()
+            {
+                if universe.can_see(ui) {
+                    let hypothesis = WhereClause::Implemented(trait_ref.clone());
+                    if !hypotheses.contains(&hypothesis) {
+                        hypotheses.push(hypothesis);
+                    }
+                    return;
+                }
+            }
+        }
+    }
+
+    for candidate in &why_not.candidates {
+        if let Some(ref failed_subgoal) = candidate.failed_subgoal {
+            collect_hypotheses(failed_subgoal, universe, hypotheses);
+        }
+    }
+}
+
+/// Solves `goal` with `hypotheses` added to its environment: `Ok` if
+/// it holds, or else why not, if it failed outright.
+fn solve_with(program: &Arc<ChalkDatabase>,
+              overflow_depth: usize,
+              goal: &Canonical<InEnvironment<WhereClauseGoal>>,
+              hypotheses: &[WhereClause])
+              -> ::std::result::Result<(), Option<WhyNot>> {
+    let environment = goal.value.environment.add_clauses(hypotheses.iter().cloned());
+    let goal = goal.clone().map(|g| InEnvironment::new(&environment, g.goal));
+    let mut solver = Solver::new(program, overflow_depth);
+    solver.record_proof_trees();
+    match solver.solve(goal) {
+        Ok(ref solution) if solution.successful() == Successful::Yes => Ok(()),
+        Ok(_) => Err(None),
+        Err(_) => Err(solver.take_proof_trees().iter().filter_map(WhyNot::from_proof_tree).next()),
+    }
+}
@@ -2,9 +2,18 @@ use chalk_parse;
 use errors::*;
 use ir;
 use lower::*;
+use solve::delegate::SolverDelegate;
 use solve::goal::Prove;
+use solve::proof_tree::{ProofClause, ProofKind};
 use solve::solver::Solver;
+use solve::suggest;
+use solve::why_not;
+use solve::{Solution, SolveOptions, SolverChoice, Successful};
+use std::cell::RefCell;
+use std::rc::Rc;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
 fn parse_and_lower_program(text: &str) -> Result<ir::Program> {
     chalk_parse::parse_program(text)?.lower()
@@ -16,18 +25,26 @@ fn parse_and_lower_goal(program: &ir::Program, text: &str) -> Result<Box<ir::Goa
 
 macro_rules! test {
     (program $program:tt $(goal $goal:tt yields { $expected:expr })*) => {
-        solve_goal(stringify!($program), vec![$((stringify!($goal), $expected)),*])
+        // Pick a low overflow depth just because the existing
+        // tests don't require a higher one.
+        test!(solver SolverChoice::Recursive { overflow_depth: 3 };
+              program $program $(goal $goal yields { $expected })*)
+    };
+
+    (solver $choice:expr; program $program:tt $(goal $goal:tt yields { $expected:expr })*) => {
+        solve_goal($choice, stringify!($program), vec![$((stringify!($goal), $expected)),*])
     }
 }
 
-fn solve_goal(program_text: &str,
+fn solve_goal(choice: SolverChoice,
+              program_text: &str,
               goals: Vec<(&str, &str)>)
 {
     println!("program {}", program_text);
     assert!(program_text.starts_with("{"));
     assert!(program_text.ends_with("}"));
     let program = Arc::new(parse_and_lower_program(&program_text[1..program_text.len()-1]).unwrap());
-    let env = Arc::new(program.environment());
+    let env: Arc<ir::ChalkDatabase> = Arc::new(program.environment());
     ir::set_current_program(&program, || {
         for (goal_text, expected) in goals {
             println!("----------------------------------------------------------------------");
@@ -35,13 +52,7 @@ fn solve_goal(program_text: &str,
             assert!(goal_text.starts_with("{"));
             assert!(goal_text.ends_with("}"));
             let goal = parse_and_lower_goal(&program, &goal_text[1..goal_text.len()-1]).unwrap();
-
-            // Pick a low overflow depth just because the existing
-            // tests don't require a higher one.
-            let overflow_depth = 3;
-
-            let mut solver = Solver::new(&env, overflow_depth);
-            let result = match Prove::new(&mut solver, goal).solve() {
+            let result = match choice.solve_goal(&env, goal) {
                 Ok(v) => format!("{:#?}", v),
                 Err(e) => format!("{}", e),
             };
@@ -56,6 +67,28 @@ fn solve_goal(program_text: &str,
     });
 }
 
+/// Enumerates up to `limit` answers to `goal_text`, checking that the
+/// refined goals are exactly `expected` (in any order).
+fn solve_goal_answers(program_text: &str, goal_text: &str, limit: usize, expected: &[&str]) {
+    let program = Arc::new(parse_and_lower_program(program_text).unwrap());
+    let env: Arc<ir::ChalkDatabase> = Arc::new(program.environment());
+    ir::set_current_program(&program, || {
+        let goal = parse_and_lower_goal(&program, goal_text).unwrap();
+        let mut solver = Solver::new(&env, 3);
+        let answers = Prove::new(&mut solver, goal).solve_answers(limit).unwrap();
+        println!("answers:\n{:#?}", answers);
+
+        let strip = |s: String| -> String { s.chars().filter(|c| !c.is_whitespace()).collect() };
+        let mut actual: Vec<_> = answers.iter()
+                                        .map(|a| strip(format!("{:?}", a.refined_goal.value.value)))
+                                        .collect();
+        let mut expected: Vec<_> = expected.iter().map(|e| strip(e.to_string())).collect();
+        actual.sort();
+        expected.sort();
+        assert_eq!(actual, expected);
+    });
+}
+
 #[test]
 fn prove_clone() {
     test! {
@@ -73,7 +106,7 @@ fn prove_clone() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             Vec<Foo>: Clone
@@ -90,7 +123,7 @@ fn prove_clone() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             Foo: Clone
@@ -132,7 +165,7 @@ fn prove_infer() {
         } yields {
             "Solution {
                 successful: Maybe,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             ?0: Map<?1>
@@ -152,7 +185,7 @@ fn prove_infer() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             Foo: Map<Bar>
@@ -169,7 +202,7 @@ fn prove_infer() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             Foo: Map<Bar>
@@ -209,7 +242,7 @@ fn prove_forall() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             !1: Marker
@@ -228,7 +261,7 @@ fn prove_forall() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             Vec<!1>: Marker
@@ -258,7 +291,7 @@ fn prove_forall() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             Vec<!1>: Clone
@@ -291,7 +324,7 @@ fn higher_ranked() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             SomeType<!1>: Foo<u8>
@@ -325,20 +358,45 @@ fn ordering() {
     }
 }
 
-/// This test forces the solver into an overflow scenario: `Foo` is
-/// only implemented for `S<S<S<...>>>` ad infinitum. So when asked to
-/// compute the type for which `Foo` is implemented, we wind up
-/// recursing for a while before we overflow. You can see that our
-/// final result is "Maybe" (i.e., either multiple proof trees or an
-/// infinite proof tree) and that we do conclude that, if a definite
-/// proof tree exists, it must begin with `S<S<S<S<...>>>>`.
+/// `Foo` is only implemented for `S<S<S<...>>>` ad infinitum, so
+/// proving `?0: Foo` needs `?0: Foo` all over again. A goal cannot be
+/// used to prove itself, so there is no finite proof tree, and the
+/// goal fails rather than recursing until we overflow.
+#[test]
+fn inductive_cycle() {
+    test! {
+        program {
+            trait Foo { }
+            struct S<T> { }
+            impl<T> Foo for S<T> where T: Foo { }
+        }
+
+        goal {
+            exists<T> {
+                T: Foo
+            }
+        } yields {
+            "`Foo` is not implemented for `?0`"
+        }
+    }
+}
+
+/// Once `Foo` has a base case, the cycle in `?0: Foo` is solved to a
+/// fixed point: `Bar`, `S<Bar>`, `S<S<Bar>>` and so on all work, so
+/// the answer is ambiguous. A cycle among closed goals still fails.
 #[test]
-fn max_depth() {
+fn cycle_fixed_point() {
     test! {
         program {
             trait Foo { }
             struct S<T> { }
+            struct Bar { }
+            struct A { }
+            struct B { }
+            impl Foo for Bar { }
             impl<T> Foo for S<T> where T: Foo { }
+            impl Foo for A where B: Foo { }
+            impl Foo for B where A: Foo { }
         }
 
         goal {
@@ -348,10 +406,10 @@ fn max_depth() {
         } yields {
             "Solution {
                 successful: Maybe,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
-                            S<S<S<S<?0>>>>: Foo
+                            ?0: Foo
                         ],
                         constraints: []
                     },
@@ -361,6 +419,18 @@ fn max_depth() {
                 }
             }"
         }
+
+        goal {
+            S<S<Bar>>: Foo
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            A: Foo
+        } yields {
+            "`Foo` is not implemented for `A`"
+        }
     }
 }
 
@@ -385,7 +455,7 @@ fn normalize() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             <Vec<!1> as Iterator>::Item ==> !1
@@ -404,7 +474,7 @@ fn normalize() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             <Vec<!1> as Iterator>::Item ==> !1
@@ -427,7 +497,7 @@ fn normalize() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             <!1 as Iterator>::Item ==> u32
@@ -450,7 +520,7 @@ fn normalize() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             <!1 as Iterator>::Item ==> (Iterator::Item)<!1>
@@ -484,7 +554,7 @@ fn normalize_rev_infer() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             <u32 as Identity>::Item ==> u32
@@ -518,7 +588,7 @@ fn region_equality() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             Ref<'!1, Unit>: Eq<Ref<'!2, Unit>>
@@ -541,7 +611,7 @@ fn region_equality() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             Ref<'!1, Unit>: Eq<Ref<'!1, Unit>>
@@ -580,7 +650,7 @@ fn forall_equality() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             for<2> Ref<'?0, Ref<'?1, Unit>>: Eq<for<2> Ref<'?0, Ref<'?1, Unit>>>
@@ -603,7 +673,7 @@ fn forall_equality() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             for<2> Ref<'?0, Ref<'?1, Ref<'?0, Unit>>>: Eq<for<2> Ref<'?0, Ref<'?1, Ref<'?1, Unit>>>>
@@ -642,7 +712,7 @@ fn forall_projection() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                          value: [
                              for<1> <Unit as DropLt<'?0>>::Item: Eq<Unit>
@@ -673,7 +743,7 @@ fn elaborate_eq() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             !1: PartialEq
@@ -705,7 +775,7 @@ fn elaborate_transitive() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             !1: PartialEq
@@ -719,6 +789,37 @@ fn elaborate_transitive() {
     }
 }
 
+#[test]
+fn elaborate_trait_parameters() {
+    test! {
+        program {
+            trait Bar { }
+            trait Baz { }
+            trait Foo<T> where Self: Bar, T: Baz { }
+        }
+
+        goal {
+            forall<T, U> {
+                if (T: Foo<U>) {
+                    T: Bar, U: Baz
+                }
+            }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<T, U> {
+                if (T: Foo<U>) {
+                    T: Baz
+                }
+            }
+        } yields {
+            "`Baz` is not implemented for `!1`"
+        }
+    }
+}
+
 #[test]
 fn elaborate_normalize() {
     test! {
@@ -739,7 +840,7 @@ fn elaborate_normalize() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             !2: Eq
@@ -790,7 +891,7 @@ fn atc1() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             <Vec<!1> as Iterable>::Iter<'!2> ==> Iter<'!2, !1>
@@ -862,7 +963,7 @@ fn struct_wf() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             WellFormed(Foo<Baz>)
@@ -879,7 +980,7 @@ fn struct_wf() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             WellFormed(Foo<Foo<Baz>>)
@@ -1014,7 +1115,7 @@ fn crate_variable() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             LocalTo(Int, Id(foo))
@@ -1140,7 +1241,7 @@ fn normalize_under_binder() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             <Ref<'!1, I32> as Deref<'!1>>::Item ==> I32
@@ -1156,7 +1257,7 @@ fn normalize_under_binder() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             <Ref<'!1, I32> as Id<'!1>>::Item ==> Ref<'!1, I32>
@@ -1177,7 +1278,7 @@ fn normalize_under_binder() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             <Ref<'!1, I32> as Id<'!1>>::Item ==> Ref<'?0, I32>
@@ -1195,6 +1296,81 @@ fn normalize_under_binder() {
     }
 }
 
+/// A projection out of a type we know nothing about yet is left until
+/// we do, rather than normalized to its fallback right away.
+#[test]
+fn normalize_lazily() {
+    test! {
+        program {
+            trait Iterator { type Item; }
+        }
+
+        goal {
+            exists<T, U> {
+                T: Iterator<Item = U>
+            }
+        } yields {
+            "Solution {
+                successful: Maybe,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            <?0 as Iterator>::Item ==> ?1
+                        ],
+                        constraints: []
+                    },
+                    binders: [
+                        U0,
+                        U0
+                    ]
+                }
+            }"
+        }
+
+        goal {
+            forall<X> {
+                if (X: Iterator) {
+                    exists<T, U> {
+                        T: Iterator<Item = U>, T = X
+                    }
+                }
+            }
+        } yields {
+            "Solution { successful: Yes"
+        }
+    }
+}
+
+/// A projection that no impl normalizes can still be compared with
+/// itself, if the trait bound holds: it normalizes to the
+/// application standing for it.
+#[test]
+fn normalize_to_placeholder() {
+    test! {
+        program {
+            trait Iterator { type Item; }
+        }
+
+        goal {
+            forall<T> {
+                if (T: Iterator) {
+                    <T as Iterator>::Item = <T as Iterator>::Item
+                }
+            }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<T> {
+                <T as Iterator>::Item = <T as Iterator>::Item
+            }
+        } yields {
+            "`Iterator` is not implemented for `!1`"
+        }
+    }
+}
+
 #[test]
 fn unify_quantified_lifetimes() {
     test! {
@@ -1213,7 +1389,7 @@ fn unify_quantified_lifetimes() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             ('?0 = '!1)
@@ -1242,7 +1418,7 @@ fn unify_quantified_lifetimes() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             ('?0 = '!1),
@@ -1261,6 +1437,37 @@ fn unify_quantified_lifetimes() {
     }
 }
 
+/// A type parameter of a `forall` cannot be the value of a variable
+/// from outside it.
+#[test]
+fn leak_check() {
+    test! {
+        program {
+            struct Vec<T> { }
+        }
+
+        goal {
+            exists<T> {
+                forall<U> {
+                    T = Vec<U>
+                }
+            }
+        } yields {
+            "`!1` would escape the `forall` that introduced it, into a variable of universe U0"
+        }
+
+        goal {
+            forall<U> {
+                exists<T> {
+                    T = Vec<U>
+                }
+            }
+        } yields {
+            "Solution { successful: Yes"
+        }
+    }
+}
+
 #[test]
 fn equality_binder() {
     test! {
@@ -1280,7 +1487,7 @@ fn equality_binder() {
         } yields {
             "Solution {
                 successful: Yes,
-                refined_goal: Query {
+                refined_goal: Canonical {
                     value: Constrained {
                         value: [
                             (for<1> Ref<'?0, !1> = Ref<'?0, !1>)
@@ -1297,3 +1504,3893 @@ fn equality_binder() {
         }
     }
 }
+
+#[test]
+fn auto_trait() {
+    test! {
+        program {
+            auto trait Send { }
+            trait Marker { }
+
+            struct i32 { }
+            struct Vec<T> { data: T }
+            struct Pair<T, U> { first: T, second: Vec<U> }
+            struct Rc<T> { data: T }
+
+            impl<T> Send for Rc<T> where T: Marker { }
+        }
+
+        goal {
+            Pair<i32, Vec<i32>>: Send
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            Pair<i32, Vec<i32>>: Send
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            forall<T> { Vec<T>: Send }
+        } yields {
+            "`Send` is not implemented for `Vec<!1>`"
+        }
+
+        goal {
+            forall<T> { if (T: Send) { Vec<T>: Send } }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        // The explicit impl replaces the structural rule for `Rc`.
+        goal {
+            Rc<i32>: Send
+        } yields {
+            "`Send` is not implemented for `Rc<i32>`"
+        }
+    }
+}
+
+/// An impl for every type replaces the structural rule for every
+/// struct, so only the types that meet its where clause are `Send`.
+#[test]
+fn auto_trait_blanket_impl() {
+    test! {
+        program {
+            auto trait Send { }
+            trait Marker { }
+
+            struct i32 { }
+            struct Foo { }
+            struct Vec<T> { data: T }
+
+            impl<T> Send for T where T: Marker { }
+            impl Marker for Foo { }
+        }
+
+        goal {
+            Foo: Send
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            i32: Send
+        } yields {
+            "`Send` is not implemented for `i32`"
+        }
+
+        goal {
+            Vec<Foo>: Send
+        } yields {
+            "`Send` is not implemented for `Vec<Foo>`"
+        }
+    }
+}
+
+#[test]
+fn negative_impls() {
+    test! {
+        program {
+            auto trait Send { }
+            trait Clone { }
+
+            struct i32 { }
+            struct Rc<T> { }
+            struct Vec<T> { data: T }
+
+            impl<T> !Send for Rc<T> { }
+
+            impl<T> Clone for T { }
+            impl !Clone for i32 { }
+        }
+
+        goal {
+            Rc<i32>: Send
+        } yields {
+            "`Send` is not implemented for `Rc<i32>`"
+        }
+
+        goal {
+            Vec<Rc<i32>>: Send
+        } yields {
+            "`Send` is not implemented for `Vec<Rc<i32>>`"
+        }
+
+        goal {
+            Vec<i32>: Send
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            i32: Clone
+        } yields {
+            "`Clone` is not implemented for `i32`"
+        }
+
+        goal {
+            Vec<i32>: Clone
+        } yields {
+            "Solution { successful: Yes"
+        }
+    }
+}
+
+/// A struct that contains itself (through a `Box`) depends on itself
+/// to be `Send`; since auto traits are coinductive, that is fine. The
+/// same cycle for an ordinary trait has no finite proof, and fails.
+#[test]
+fn coinductive_auto_traits() {
+    test! {
+        program {
+            auto trait Send { }
+            trait Foo { }
+
+            struct i32 { }
+            struct Box<T> { data: T }
+            struct List<T> { data: T, next: Box<List<T>> }
+
+            impl Foo for i32 { }
+            impl<T> Foo for Box<T> where T: Foo { }
+            impl<T> Foo for List<T> where T: Foo, Box<List<T>>: Foo { }
+        }
+
+        goal {
+            List<i32>: Send
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            List<i32>: Foo
+        } yields {
+            "`Foo` is not implemented for `List<i32>`"
+        }
+    }
+}
+
+#[test]
+fn scalars() {
+    test! {
+        program {
+            trait Foo { }
+            struct Vec<T> { }
+
+            impl Foo for i32 { }
+            impl<T> Foo for Vec<T> where T: Foo { }
+        }
+
+        goal {
+            Vec<i32>: Foo
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Vec<u32>: Foo
+        } yields {
+            "`Foo` is not implemented for `Vec<u32>`"
+        }
+
+        goal {
+            exists<T> { T: Foo, T = i32 }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            i32: Foo,
+                            (i32 = i32)
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            bool = char
+        } yields {
+            "cannot zip `bool` and `char`"
+        }
+
+        goal {
+            f32 != f64
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            WellFormed(Vec<bool>)
+        } yields {
+            "Solution { successful: Yes"
+        }
+    }
+}
+
+#[test]
+fn tuples() {
+    test! {
+        program {
+            trait Foo { }
+            struct Bar { }
+            struct Baz { }
+
+            impl Foo for () { }
+            impl<A> Foo for (A,) where A: Foo { }
+            impl<A, B> Foo for (A, B) where A: Foo, B: Foo { }
+            impl Foo for Bar { }
+        }
+
+        goal {
+            (): Foo
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            (Bar, (Bar,)): Foo
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            (Bar, Baz): Foo
+        } yields {
+            "`Foo` is not implemented for `(Bar, Baz)`"
+        }
+
+        goal {
+            exists<T> { (Bar, T) = (Bar, Baz) }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            ((Bar, Baz) = (Bar, Baz))
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            exists<T> { (Bar, T) = (Bar,) }
+        } yields {
+            "cannot zip `2-tuple` and `1-tuple`"
+        }
+
+        goal {
+            (Bar, Baz) != (Bar,)
+        } yields {
+            "Solution { successful: Yes"
+        }
+    }
+}
+
+#[test]
+fn tuples_wf_and_auto_traits() {
+    test! {
+        program {
+            auto trait Send { }
+            trait Eq { }
+            struct Foo<T> where T: Eq { }
+            struct Bar { }
+            struct Baz { }
+            struct Rc<T> { }
+            struct Big { field: (u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, Rc<u32>) }
+
+            impl Eq for Baz { }
+            impl<T> !Send for Rc<T> { }
+        }
+
+        goal {
+            WellFormed(())
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            WellFormed((u32,))
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            WellFormed((u32, Foo<Baz>))
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            WellFormed((u32, Foo<Bar>))
+        } yields {
+            "no applicable candidates"
+        }
+
+        goal {
+            (u32,): Send
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            (u32, (Bar, Baz)): Send
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            (u32, Rc<u32>): Send
+        } yields {
+            "`Send` is not implemented for `(u32, Rc<u32>)`"
+        }
+
+        // Longer tuples get the same clauses if the program uses them.
+        goal {
+            WellFormed((u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32))
+        } yields {
+            "Solution { successful: Yes"
+        }
+    }
+}
+
+#[test]
+fn references() {
+    test! {
+        program {
+            trait Foo { }
+            trait Bar { }
+            struct Baz { }
+
+            impl<'a, T> Foo for &'a T where T: Foo { }
+            impl<'a, T> Bar for &'a mut T { }
+            impl Foo for Baz { }
+        }
+
+        goal {
+            forall<'a> { &'a Baz: Foo }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<'a> { &'a mut Baz: Foo }
+        } yields {
+            "`Foo` is not implemented for `&'!1 mut Baz`"
+        }
+
+        goal {
+            forall<'a> { &'a mut Baz: Bar }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<'a> { &'a Baz: Bar }
+        } yields {
+            "`Bar` is not implemented for `&'!1 Baz`"
+        }
+
+        goal {
+            forall<'a> { exists<T> { &'a Baz = &'a T } }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            (&'!1 Baz = &'!1 Baz)
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+    }
+}
+
+#[test]
+fn raw_pointers() {
+    test! {
+        program {
+            auto trait Send { }
+            auto trait Sync { }
+            struct Foo { }
+            struct Bar<T> { t: T }
+
+            impl<T> !Send for *const T { }
+            impl<T> !Send for *mut T { }
+        }
+
+        goal {
+            *const Foo: Send
+        } yields {
+            "`Send` is not implemented for `*const Foo`"
+        }
+
+        goal {
+            Bar<*mut Foo>: Send
+        } yields {
+            "`Send` is not implemented for `Bar<*mut Foo>`"
+        }
+
+        goal {
+            Bar<*mut Foo>: Sync
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<'a> { &'a *const Foo: Sync }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            exists<T> { *const T = *mut Foo }
+        } yields {
+            "cannot zip `*const` and `*mut`"
+        }
+    }
+}
+
+#[test]
+fn slices() {
+    test! {
+        program {
+            auto trait Send { }
+            trait Foo { }
+            struct Bar { }
+            struct Baz { }
+
+            impl !Send for Baz { }
+            impl<T> Foo for [T] where T: Foo { }
+            impl Foo for Bar { }
+        }
+
+        goal {
+            [Bar]: Send
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            [Baz]: Send
+        } yields {
+            "`Send` is not implemented for `[Baz]`"
+        }
+
+        goal {
+            [[Bar]]: Foo
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            exists<T> { [T]: Foo, T = Baz }
+        } yields {
+            "`Foo` is not implemented for `[Baz]`"
+        }
+    }
+}
+
+#[test]
+fn str_type() {
+    test! {
+        program {
+            #[lang(sized)] trait Sized { }
+            auto trait Send { }
+            trait Foo { }
+            struct Name { text: str }
+
+            impl<'a> Foo for &'a str { }
+        }
+
+        goal {
+            str: Send
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            str: Sized
+        } yields {
+            "`Sized` is not implemented for `str`"
+        }
+
+        goal {
+            Name: Sized
+        } yields {
+            "`Sized` is not implemented for `Name`"
+        }
+
+        goal {
+            forall<'a> { &'a str: Sized }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<'a> { &'a str: Foo }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<'a> { &'a [u8]: Foo }
+        } yields {
+            "`Foo` is not implemented for `&'!1 [u8]`"
+        }
+    }
+}
+
+#[test]
+fn phantom_data() {
+    test! {
+        program {
+            #[lang(sized)] trait Sized { }
+            auto trait Send { }
+            struct Foo { }
+            struct Bar { }
+            struct Owner<T> { marker: PhantomData<T> }
+
+            impl !Send for Bar { }
+        }
+
+        goal {
+            PhantomData<[Foo]>: Sized
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Owner<Foo>: Send
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Owner<Bar>: Send
+        } yields {
+            "`Send` is not implemented for `Owner<Bar>`"
+        }
+
+        goal {
+            forall<'a, 'b> { Subtype(Owner<&'a Foo>, Owner<&'b Foo>) }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            Subtype(Owner<&'!1 Foo>, Owner<&'!2 Foo>)
+                        ],
+                        constraints: [
+                            (Env(U2, []) |- LifetimeOutlives('!1, '!2))
+                        ]
+                    },
+                    binders: []
+                }
+            }"
+        }
+    }
+}
+
+#[test]
+fn arrays() {
+    test! {
+        program {
+            auto trait Send { }
+            trait Foo { }
+            struct Bar { }
+            struct Baz { }
+
+            impl !Send for Baz { }
+            impl<T, const N> Foo for [T; N] where T: Foo { }
+            impl Foo for Bar { }
+        }
+
+        goal {
+            [Bar; 3]: Foo
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<const N> { [Bar; N]: Foo }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<const N> { [Baz; N]: Send }
+        } yields {
+            "`Send` is not implemented for `[Baz; !1]`"
+        }
+
+        goal {
+            exists<const N> { [Bar; N] = [Bar; 3] }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            ([Bar; 3] = [Bar; 3])
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            [Bar; 3] = [Bar; 4]
+        } yields {
+            "cannot unify `3` with `4`"
+        }
+
+        goal {
+            [Bar; 3] != [Bar; 4]
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<const N> { exists<const M> { [Bar; N] = [Bar; M] } }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            exists<const M> { forall<const N> { [Bar; N] = [Bar; M] } }
+        } yields {
+            "`!1` would escape the `forall` that introduced it, into a variable of universe U0"
+        }
+    }
+}
+
+#[test]
+fn fn_pointers() {
+    test! {
+        program {
+            trait Foo { }
+            struct Bar { }
+            struct Baz { }
+
+            impl<A, R> Foo for fn(A) -> R { }
+        }
+
+        goal {
+            fn(Bar) -> Baz: Foo
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            fn(Bar, Baz): Foo
+        } yields {
+            "`Foo` is not implemented for `fn(Bar, Baz) -> ()`"
+        }
+
+        goal {
+            exists<A, R> { fn(A) -> R = fn(Bar) }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            (fn(Bar) -> () = fn(Bar) -> ())
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            fn(Bar) -> Bar = fn(Bar) -> Baz
+        } yields {
+            "cannot zip `Bar` and `Baz`"
+        }
+
+        goal {
+            fn(Bar) != fn(Bar, Bar)
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            for<'a> fn(&'a Bar) = for<'b> fn(&'b Bar)
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            (for<1> fn(&'?0 Bar) -> () = for<1> fn(&'?0 Bar) -> ())
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+    }
+}
+
+#[test]
+fn fn_pointers_wf_and_auto_traits() {
+    test! {
+        program {
+            auto trait Send { }
+            trait Eq { }
+            struct Foo<T> where T: Eq { }
+            struct Bar { }
+            struct Baz { }
+            struct Rc<T> { }
+
+            impl Eq for Baz { }
+            impl<T> !Send for Rc<T> { }
+        }
+
+        goal {
+            WellFormed(fn(u32) -> u32)
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            WellFormed(fn(Foo<Baz>))
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            WellFormed(fn() -> Foo<Bar>)
+        } yields {
+            "no applicable candidates"
+        }
+
+        goal {
+            fn(u32): Send
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        // A fn pointer holds no values of its argument or return
+        // types, so it is `Send` whatever they are.
+        goal {
+            fn(Rc<u32>) -> Rc<u32>: Send
+        } yields {
+            "Solution { successful: Yes"
+        }
+    }
+}
+
+#[test]
+fn never_type() {
+    test! {
+        program {
+            #[lang(sized)] trait Sized { }
+            auto trait Send { }
+            trait Foo { }
+            trait Bar { }
+            struct Baz { }
+
+            impl Foo for ! { }
+            impl<R> Bar for fn() -> R where R: Foo { }
+        }
+
+        goal {
+            !: Send
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            !: Sized
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            !: Foo
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            !: Bar
+        } yields {
+            "`Bar` is not implemented for `!`"
+        }
+
+        goal {
+            fn() -> !: Bar
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            fn() -> Baz: Bar
+        } yields {
+            "`Bar` is not implemented for `fn() -> Baz`"
+        }
+
+        goal {
+            WellFormed(!)
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            ! = Baz
+        } yields {
+            "cannot zip `!` and `Baz`"
+        }
+    }
+}
+
+#[test]
+fn closures() {
+    test! {
+        program {
+            #[lang(fn_once)] trait FnOnce<Args> { type Output; }
+            #[lang(fn_mut)] trait FnMut<Args> where Self: FnOnce<Args> { }
+            #[lang(fn)] trait Fn<Args> where Self: FnMut<Args> { }
+
+            struct Foo { }
+            struct Bar { }
+
+            closure Map<T>: FnMut(T) -> Bar;
+            closure Consume: FnOnce(Foo, Foo);
+        }
+
+        goal {
+            Map<Foo>: FnMut<(Foo,)>
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Map<Foo>: Fn<(Foo,)>
+        } yields {
+            "`Fn<(Foo,)>` is not implemented for `Map<Foo>`"
+        }
+
+        goal {
+            Map<Foo>: FnOnce<(Bar,)>
+        } yields {
+            "`FnOnce<(Bar,)>` is not implemented for `Map<Foo>`"
+        }
+
+        goal {
+            exists<U> { Map<Foo>: FnOnce<(Foo,), Output = U> }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            <Map<Foo> as FnOnce<(Foo,)>>::Output ==> Bar
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            exists<A> { Consume: FnOnce<A> }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            Consume: FnOnce<(Foo, Foo)>
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            exists<A> { Consume: FnMut<A> }
+        } yields {
+            "`FnMut<?0>` is not implemented for `Consume`"
+        }
+    }
+}
+
+#[test]
+fn fn_defs() {
+    test! {
+        program {
+            #[lang(sized)] trait Sized { }
+            auto trait Send { }
+            #[lang(fn_once)] trait FnOnce<Args> { type Output; }
+            #[lang(fn_mut)] trait FnMut<Args> where Self: FnOnce<Args> { }
+            #[lang(fn)] trait Fn<Args> where Self: FnMut<Args> { }
+            trait Eq { }
+
+            struct Foo { }
+            struct Bar { }
+            impl Eq for Foo { }
+
+            fn apply<T>(T) -> Bar where T: Eq;
+            fn noop();
+        }
+
+        goal {
+            apply<Foo>: Fn<(Foo,)>
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            apply<Bar>: FnOnce<(Bar,)>
+        } yields {
+            "`FnOnce<(Bar,)>` is not implemented for `apply<Bar>`"
+        }
+
+        goal {
+            exists<U> { apply<Foo>: FnOnce<(Foo,), Output = U> }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            <apply<Foo> as FnOnce<(Foo,)>>::Output ==> Bar
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            exists<U> { noop: FnOnce<(), Output = U> }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            <noop as FnOnce<()>>::Output ==> ()
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            noop: Sized
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            apply<Bar>: Send
+        } yields {
+            "Solution { successful: Yes"
+        }
+    }
+}
+
+#[test]
+fn generators() {
+    test! {
+        program {
+            #[lang(generator)] trait Generator<R> { type Yield; type Return; }
+
+            struct Foo { }
+            struct Bar { }
+
+            generator Counter<T>(Foo) yield T -> Bar;
+            generator Unit() yield Foo;
+        }
+
+        goal {
+            Counter<u32>: Generator<Foo>
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Counter<u32>: Generator<Bar>
+        } yields {
+            "`Generator<Bar>` is not implemented for `Counter<u32>`"
+        }
+
+        goal {
+            exists<Y> { Counter<u32>: Generator<Foo, Yield = Y> }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            <Counter<u32> as Generator<Foo>>::Yield ==> u32
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            exists<R> { Unit: Generator<(), Return = R> }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            <Unit as Generator<()>>::Return ==> ()
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+    }
+}
+
+#[test]
+fn extern_types() {
+    test! {
+        program {
+            #[lang(sized)] trait Sized { }
+            auto trait Send { }
+            trait Handle { }
+
+            extern type Opaque;
+            struct Wrapper { field: Opaque }
+
+            impl Handle for Opaque { }
+        }
+
+        goal {
+            Opaque: Handle
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Opaque: Sized
+        } yields {
+            "`Sized` is not implemented for `Opaque`"
+        }
+
+        goal {
+            Wrapper: Sized
+        } yields {
+            "`Sized` is not implemented for `Wrapper`"
+        }
+
+        goal {
+            Opaque: Send
+        } yields {
+            "`Send` is not implemented for `Opaque`"
+        }
+
+        goal {
+            Wrapper: Send
+        } yields {
+            "`Send` is not implemented for `Wrapper`"
+        }
+    }
+}
+
+#[test]
+fn enumerate_answers() {
+    let program = "
+        struct Foo { }
+        struct Bar { }
+        struct Vec<T> { }
+        trait Clone { }
+        trait Marker { }
+        impl Clone for Foo { }
+        impl Clone for Bar { }
+        impl<T> Clone for Vec<T> where T: Marker { }
+        impl Marker for Foo { }
+    ";
+
+    solve_goal_answers(program, "exists<T> { T: Clone }", 10, &[
+        "[Foo: Clone]",
+        "[Bar: Clone]",
+        "[Vec<Foo>: Clone]",
+    ]);
+
+    // A goal that isn't ambiguous has just the one answer.
+    solve_goal_answers(program, "Vec<Foo>: Clone", 10, &[
+        "[Vec<Foo>: Clone]",
+    ]);
+
+    solve_goal_answers(program, "exists<T> { T: Clone, T: Marker }", 10, &[
+        "[Foo: Clone, Foo: Marker]",
+    ]);
+}
+
+/// When the candidates for an ambiguous goal agree on part of the
+/// answer, the solution says what that part is.
+#[test]
+fn ambiguous_guidance() {
+    test! {
+        program {
+            struct Foo { }
+            struct Bar { }
+            struct Vec<T> { }
+            struct Pair<T, U> { }
+            trait Clone { }
+            trait Eq { }
+            trait Show { }
+            impl Clone for Vec<Foo> { }
+            impl Clone for Vec<Bar> { }
+            impl Eq for Pair<Foo, Foo> { }
+            impl Eq for Pair<Bar, Bar> { }
+            impl Show for Foo { }
+            impl Show for Bar { }
+        }
+
+        goal {
+            exists<T> { T: Clone }
+        } yields {
+            "Solution {
+                successful: Maybe,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            Vec<?0>: Clone
+                        ],
+                        constraints: []
+                    },
+                    binders: [
+                        U0
+                    ]
+                }
+            }"
+        }
+
+        goal {
+            exists<T, U> { Pair<T, U>: Eq }
+        } yields {
+            "Solution {
+                successful: Maybe,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            Pair<?0, ?0>: Eq
+                        ],
+                        constraints: []
+                    },
+                    binders: [
+                        U0
+                    ]
+                }
+            }"
+        }
+
+        goal {
+            exists<T> { T: Show }
+        } yields {
+            "Solution {
+                successful: Maybe,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            ?0: Show
+                        ],
+                        constraints: []
+                    },
+                    binders: [
+                        U0
+                    ]
+                }
+            }"
+        }
+    }
+}
+
+/// Overflowing the solver's depth limit leaves a record of how it
+/// got there.
+#[test]
+fn overflow_stack() {
+    let program = Arc::new(parse_and_lower_program("
+        trait Foo { }
+        struct S<T> { }
+        struct Bar { }
+        impl<T> Foo for S<T> where S<S<T>>: Foo { }
+    ").unwrap());
+    let env: Arc<ir::ChalkDatabase> = Arc::new(program.environment());
+    ir::set_current_program(&program, || {
+        let overflow_depth = 3;
+
+        let goal = parse_and_lower_goal(&program, "S<Bar>: Foo").unwrap();
+        let mut solver = Solver::new(&env, overflow_depth);
+        Prove::new(&mut solver, goal).solve().unwrap();
+        let stack = solver.overflow().unwrap();
+        println!("overflow stack: {:#?}", stack);
+        assert_eq!(stack.len(), overflow_depth + 2);
+
+        let goal = parse_and_lower_goal(&program, "forall<T> { if (T: Foo) { T: Foo } }").unwrap();
+        let mut solver = Solver::new(&env, overflow_depth);
+        Prove::new(&mut solver, goal).solve().unwrap();
+        assert!(solver.overflow().is_none());
+    });
+}
+
+/// A proof tree records the clause used for each goal, and the proofs
+/// of the subgoals that clause led to.
+#[test]
+fn proof_trees() {
+    let program = Arc::new(parse_and_lower_program("
+        trait Clone { }
+        struct Vec<T> { }
+        struct Bar { }
+        impl Clone for Bar { }
+        impl<T> Clone for Vec<T> where T: Clone { }
+    ").unwrap());
+    let env: Arc<ir::ChalkDatabase> = Arc::new(program.environment());
+    ir::set_current_program(&program, || {
+        let mut solver = Solver::new(&env, 3);
+        solver.record_proof_trees();
+
+        let goal = parse_and_lower_goal(&program, "Vec<Bar>: Clone").unwrap();
+        Prove::new(&mut solver, goal).solve().unwrap();
+        let trees = solver.take_proof_trees();
+        println!("proof trees: {:#?}", trees);
+        assert_eq!(trees.len(), 1);
+
+        let tree = &trees[0];
+        assert_eq!(format!("{:?}", tree.goal.value.goal), "Vec<Bar>: Clone");
+        assert_eq!(tree.kind, ProofKind::Search);
+        assert!(tree.result.is_ok());
+        assert_eq!(tree.candidates.len(), 1);
+        match tree.candidates[0].clause {
+            ProofClause::Program(ref clause) => {
+                assert_eq!(format!("{:?}", clause.implication.value.consequence),
+                           "Vec<?0>: Clone");
+            }
+            ref clause => panic!("unexpected clause {:?}", clause),
+        }
+
+        let subproofs = &tree.candidates[0].subproofs;
+        assert_eq!(subproofs.len(), 1);
+        assert_eq!(format!("{:?}", subproofs[0].goal.value.goal), "Bar: Clone");
+        assert_eq!(subproofs[0].candidates.len(), 1);
+        assert!(subproofs[0].candidates[0].subproofs.is_empty());
+
+        // No more trees are recorded until the next goal is solved.
+        assert!(solver.take_proof_trees().is_empty());
+    });
+}
+
+/// A delegate hears about each goal the solver enters and leaves, and
+/// about each clause it tries.
+#[test]
+fn solver_delegate() {
+    struct Log(Rc<RefCell<Vec<String>>>);
+
+    impl SolverDelegate for Log {
+        fn enter_goal(&mut self, goal: &ir::Canonical<ir::InEnvironment<ir::WhereClauseGoal>>) {
+            self.0.borrow_mut().push(format!("enter {:?}", goal.value.goal));
+        }
+
+        fn exit_goal(&mut self,
+                     goal: &ir::Canonical<ir::InEnvironment<ir::WhereClauseGoal>>,
+                     result: &Result<Solution<ir::InEnvironment<ir::WhereClauseGoal>>>) {
+            self.0.borrow_mut().push(format!("exit {:?} {}", goal.value.goal, result.is_ok()));
+        }
+
+        fn select_candidate(&mut self, _clause: &ProofClause) {
+            self.0.borrow_mut().push("candidate".to_string());
+        }
+
+        fn backtrack(&mut self, _clause: &ProofClause) {
+            self.0.borrow_mut().push("backtrack".to_string());
+        }
+    }
+
+    let program = Arc::new(parse_and_lower_program("
+        trait Clone { }
+        struct Vec<T> { }
+        struct Bar { }
+        impl Clone for Bar { }
+        impl<T> Clone for Vec<T> where T: Clone { }
+    ").unwrap());
+    let env: Arc<ir::ChalkDatabase> = Arc::new(program.environment());
+    ir::set_current_program(&program, || {
+        let log = Rc::new(RefCell::new(vec![]));
+        let mut solver = Solver::new(&env, 3);
+        solver.set_delegate(Box::new(Log(log.clone())));
+
+        let goal = parse_and_lower_goal(&program, "Vec<Bar>: Clone").unwrap();
+        Prove::new(&mut solver, goal).solve().unwrap();
+        let events = log.borrow().clone();
+        println!("events: {:#?}", events);
+
+        assert_eq!(events.first().unwrap(), "enter Vec<Bar>: Clone");
+        assert_eq!(events.last().unwrap(), "exit Vec<Bar>: Clone true");
+        let enter_bar = events.iter().position(|e| e == "enter Bar: Clone").unwrap();
+        let exit_bar = events.iter().position(|e| e == "exit Bar: Clone true").unwrap();
+        assert!(enter_bar < exit_bar);
+
+        // Each clause is tried for each goal, and all but the one that
+        // applies are backtracked out of.
+        let candidates = events.iter().filter(|e| *e == "candidate").count();
+        let backtracks = events.iter().filter(|e| *e == "backtrack").count();
+        assert_eq!(candidates, backtracks + 2);
+
+        assert!(solver.take_delegate().is_some());
+    });
+}
+
+/// The solver remembers the subgoals it has solved, except for those
+/// whose results only held given what was assumed about a cycle.
+#[test]
+fn solution_cache() {
+    let program = Arc::new(parse_and_lower_program("
+        trait Clone { }
+        trait Foo { }
+        struct Vec<T> { }
+        struct Bar { }
+        struct A { }
+        struct B { }
+        impl Clone for Bar { }
+        impl<T> Clone for Vec<T> where T: Clone { }
+        impl Foo for A where B: Foo { }
+        impl Foo for B where A: Foo { }
+    ").unwrap());
+    let env: Arc<ir::ChalkDatabase> = Arc::new(program.environment());
+    ir::set_current_program(&program, || {
+        let mut solver = Solver::new(&env, 3);
+        let cached_goals = |solver: &Solver| -> Vec<String> {
+            solver.cache().keys().map(|goal| format!("{:?}", goal.value.goal)).collect()
+        };
+
+        let goal = parse_and_lower_goal(&program, "Vec<Vec<Bar>>: Clone").unwrap();
+        Prove::new(&mut solver, goal).solve().unwrap();
+        let cached = cached_goals(&solver);
+        println!("cached: {:#?}", cached);
+        for goal in &["Vec<Vec<Bar>>: Clone", "Vec<Bar>: Clone", "Bar: Clone"] {
+            assert!(cached.contains(&goal.to_string()));
+        }
+
+        // `B: Foo` fails only because `A: Foo` was assumed to.
+        let goal = parse_and_lower_goal(&program, "A: Foo").unwrap();
+        assert!(Prove::new(&mut solver, goal).solve().is_err());
+        let cached = cached_goals(&solver);
+        assert!(cached.contains(&"A: Foo".to_string()));
+        assert!(!cached.contains(&"B: Foo".to_string()));
+
+        let goal = parse_and_lower_goal(&program, "Vec<Bar>: Clone").unwrap();
+        let solution = Prove::new(&mut solver, goal).solve().unwrap();
+        assert_eq!(solution.successful(), Successful::Yes);
+
+        solver.clear_cache();
+        assert!(solver.cache().is_empty());
+    });
+}
+
+/// Editing the impls of a program forgets only the cached results that
+/// consulted them.
+#[test]
+fn program_edits() {
+    let mut program = parse_and_lower_program("
+        trait Foo { }
+        trait Bar { }
+        struct Vec<T> { }
+        struct A { }
+        struct B { }
+        impl Foo for A { }
+        impl<T> Foo for Vec<T> where T: Foo { }
+        impl Bar for A { }
+    ").unwrap();
+    let mut env = Arc::new(program.environment());
+    let mut solver = Solver::new(&(env.clone() as Arc<ir::ChalkDatabase>), 3);
+    let edit = |solver: &mut Solver, env: &mut Arc<ir::ProgramEnvironment>, program: &ir::Program| {
+        let new_env = Arc::new(program.environment());
+        solver.set_program(&(new_env.clone() as Arc<ir::ChalkDatabase>), env.changed_items(&new_env));
+        *env = new_env;
+    };
+    let prove = |solver: &mut Solver, program: &ir::Program, text: &str| -> bool {
+        let goal = parse_and_lower_goal(program, text).unwrap();
+        Prove::new(solver, goal).solve().is_ok()
+    };
+    let cached_goals = |solver: &Solver, program: &ir::Program| -> Vec<String> {
+        ir::set_current_program(&Arc::new(program.clone()), || {
+            solver.cache().keys().map(|goal| format!("{:?}", goal.value.goal)).collect()
+        })
+    };
+
+    assert!(prove(&mut solver, &program, "Vec<A>: Foo"));
+    assert!(prove(&mut solver, &program, "A: Bar"));
+    assert!(!prove(&mut solver, &program, "Vec<B>: Foo"));
+
+    let impl_ = match chalk_parse::parse_program("impl Foo for B { }").unwrap().items.pop() {
+        Some(chalk_parse::ast::Item::Impl(impl_)) => impl_,
+        _ => panic!("expected an impl"),
+    };
+    let impl_id = program.add_impl(&impl_).unwrap();
+    edit(&mut solver, &mut env, &program);
+    let cached = cached_goals(&solver, &program);
+    println!("cached: {:#?}", cached);
+    assert!(cached.contains(&"A: Bar".to_string()));
+    assert!(!cached.iter().any(|goal| goal.ends_with(": Foo")));
+    assert!(prove(&mut solver, &program, "Vec<B>: Foo"));
+
+    program.remove_impl(impl_id).unwrap();
+    edit(&mut solver, &mut env, &program);
+    assert!(cached_goals(&solver, &program).contains(&"A: Bar".to_string()));
+    assert!(!prove(&mut solver, &program, "Vec<B>: Foo"));
+
+    // An impl that overlaps with another is rejected, leaving the
+    // program as it was.
+    let impl_ = match chalk_parse::parse_program("impl Bar for A { }").unwrap().items.pop() {
+        Some(chalk_parse::ast::Item::Impl(impl_)) => impl_,
+        _ => panic!("expected an impl"),
+    };
+    assert!(program.add_impl(&impl_).is_err());
+    assert_eq!(program.impl_data.len(), 3);
+}
+
+/// The solver can look up the program through a database of the
+/// embedder's own.
+#[test]
+fn custom_database() {
+    use ir::ChalkDatabase;
+
+    struct Database {
+        env: ir::ProgramEnvironment,
+        clause_lookups: AtomicUsize,
+    }
+
+    impl ChalkDatabase for Database {
+        fn struct_datum(&self, id: ir::ItemId) -> Option<&ir::StructDatum> {
+            self.env.struct_datum(id)
+        }
+
+        fn enum_datum(&self, id: ir::ItemId) -> Option<&ir::EnumDatum> {
+            self.env.enum_datum(id)
+        }
+
+        fn trait_datum(&self, id: ir::ItemId) -> Option<&ir::TraitDatum> {
+            self.env.trait_datum(id)
+        }
+
+        fn associated_ty_datum(&self, id: ir::ItemId) -> Option<&ir::AssociatedTyDatum> {
+            self.env.associated_ty_datum(id)
+        }
+
+        fn associated_const_datum(&self, id: ir::ItemId) -> Option<&ir::AssociatedConstDatum> {
+            self.env.associated_const_datum(id)
+        }
+
+        fn variances(&self, id: ir::ItemId) -> Option<&[ir::Variance]> {
+            self.env.variances(id)
+        }
+
+        fn program_clauses_for(&self, goal: &ir::WhereClauseGoal) -> Vec<&ir::ProgramClause> {
+            self.clause_lookups.fetch_add(1, Ordering::SeqCst);
+            self.env.program_clauses_for(goal)
+        }
+
+        fn negative_impl_clauses(&self, trait_id: ir::ItemId) -> Vec<&ir::ProgramClause> {
+            self.env.negative_impl_clauses(trait_id)
+        }
+
+        fn default_program_clauses_for(&self, goal: &ir::WhereClauseGoal)
+                                       -> Vec<&ir::DefaultProgramClause> {
+            self.env.default_program_clauses_for(goal)
+        }
+    }
+
+    let program = Arc::new(parse_and_lower_program("
+        trait Clone { }
+        struct Vec<T> { }
+        struct Bar { }
+        impl Clone for Bar { }
+        impl<T> Clone for Vec<T> where T: Clone { }
+    ").unwrap());
+    let database = Arc::new(Database {
+        env: program.environment(),
+        clause_lookups: AtomicUsize::new(0),
+    });
+    ir::set_current_program(&program, || {
+        let mut solver = Solver::new(&(database.clone() as Arc<ChalkDatabase>), 3);
+        let goal = parse_and_lower_goal(&program, "Vec<Vec<Bar>>: Clone").unwrap();
+        let solution = Prove::new(&mut solver, goal).solve().unwrap();
+        assert_eq!(solution.successful(), Successful::Yes);
+        assert!(database.clause_lookups.load(Ordering::SeqCst) >= 3);
+    });
+}
+
+/// Looking up an item that the database doesn't have gives `None`,
+/// rather than a panic.
+#[test]
+fn custom_database_unknown_items() {
+    use ir::ChalkDatabase;
+
+    let program = parse_and_lower_program("
+        trait Iterator { type Item; }
+    ").unwrap();
+    let env = program.environment();
+    let unknown = ir::ItemId { index: program.type_kinds.len() + 100 };
+    assert!(env.trait_datum(unknown).is_none());
+    assert!(env.associated_ty_datum(unknown).is_none());
+    assert!(env.associated_const_datum(unknown).is_none());
+    let projection = ir::ProjectionTy { associated_ty_id: unknown, parameters: vec![] };
+    assert!(env.split_projection(&projection).is_none());
+}
+
+#[test]
+fn dyn_trait() {
+    test! {
+        program {
+            trait Display { }
+            trait Debug { }
+            struct Foo { }
+            struct Box<T> { }
+
+            impl Display for Foo { }
+            impl Debug for dyn Display { }
+            impl<T> Debug for Box<T> where T: Debug { }
+        }
+
+        goal {
+            Box<dyn Display>: Debug
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Box<Foo>: Debug
+        } yields {
+            "`Debug` is not implemented for `Box<Foo>`"
+        }
+    }
+}
+
+#[test]
+fn dyn_trait_associated_types() {
+    test! {
+        program {
+            #[lang(sized)] trait Sized { }
+            trait Iterator { type Item; }
+            struct Foo { }
+            struct Bar { }
+
+            impl Iterator for Foo { type Item = Bar; }
+        }
+
+        goal {
+            exists<U> { dyn Iterator<Item = Bar>: Iterator<Item = U> }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            <Iterator<Bar> as Iterator>::Item ==> Bar
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            Unsize(Foo, dyn Iterator<Item = Bar>)
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            exists<U> { Unsize(Foo, dyn Iterator<Item = U>) }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            Unsize(Foo, Iterator<Bar>)
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+    }
+}
+
+#[test]
+fn opaque_types() {
+    test! {
+        program {
+            trait Iterator { }
+            trait Clone { }
+            auto trait Send { }
+            struct Vec<T> { }
+
+            impl<T> Iterator for Vec<T> { }
+            impl<T> Clone for Vec<T> { }
+
+            opaque type Iter<T>: Iterator;
+        }
+
+        goal {
+            forall<T> { Iter<T>: Iterator }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Iter<Vec<u32>>: Clone
+        } yields {
+            "`Clone` is not implemented for `Iter<Vec<u32>>`"
+        }
+
+        goal {
+            Iter<u32>: Send
+        } yields {
+            "`Send` is not implemented for `Iter<u32>`"
+        }
+
+        goal {
+            exists<T> { Iter<u32> = Vec<T> }
+        } yields {
+            "cannot zip `Iter` and `Vec`"
+        }
+    }
+}
+
+#[test]
+fn associated_ty_defaults() {
+    test! {
+        program {
+            trait Convert<T> {
+                type Output = Pair<Self, T>;
+                type Wrapped<U> = Pair<U, T>;
+            }
+            struct Pair<A, B> { }
+            struct Foo { }
+            struct Bar { }
+
+            impl Convert<Bar> for Foo { }
+            impl Convert<Foo> for Bar {
+                type Output = Bar;
+            }
+        }
+
+        goal {
+            exists<U> { Foo: Convert<Bar, Output = U> }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            <Foo as Convert<Bar>>::Output ==> Pair<Foo, Bar>
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            exists<U> { Bar: Convert<Foo, Output = U> }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            <Bar as Convert<Foo>>::Output ==> Bar
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            exists<U> { Bar: Convert<Foo, Wrapped<Bar> = U> }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            <Bar as Convert<Foo>>::Wrapped<Bar> ==> Pair<Bar, Foo>
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+    }
+}
+
+#[test]
+fn generic_assoc_tys() {
+    test! {
+        program {
+            trait Family { type Member<T>; }
+            struct Vec<T> { }
+            struct Option<T> { }
+            struct VecFamily { }
+            struct Foo { }
+
+            impl Family for VecFamily {
+                type Member<T> = Vec<T>;
+            }
+        }
+
+        goal {
+            exists<U> { VecFamily: Family<Member<Foo> = U> }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            <VecFamily as Family>::Member<Foo> ==> Vec<Foo>
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            forall<T> { VecFamily: Family<Member<T> = Vec<T>> }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            VecFamily: Family<Member<Foo> = Option<Foo>>
+        } yields {
+            "cannot zip `Option` and `(Family::Member)`"
+        }
+
+        goal {
+            forall<F> {
+                if (F: Family) {
+                    exists<U> { F: Family<Member<Foo> = U> }
+                }
+            }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            <!1 as Family>::Member<Foo> ==> (Family::Member)<Foo, !1>
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+    }
+}
+
+#[test]
+fn assoc_consts() {
+    test! {
+        program {
+            trait Len { const LEN; }
+            trait Empty { }
+            struct Foo { }
+            struct Array<T, const N> { }
+
+            impl Len for Foo { const LEN = 0; }
+            impl<T, const N> Len for Array<T, N> { const LEN = N; }
+            impl<T> Empty for T where T: Len<const LEN = 0> { }
+        }
+
+        goal {
+            exists<const N> { Array<Foo, 3>: Len<const LEN = N> }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            <Array<Foo, 3> as Len>::LEN ==> 3
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            Foo: Len<const LEN = 1>
+        } yields {
+            "no applicable candidates"
+        }
+
+        goal {
+            Foo: Empty
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Array<Foo, 2>: Empty
+        } yields {
+            "`Empty` is not implemented for `Array<Foo, 2>`"
+        }
+
+        goal {
+            forall<T> {
+                if (T: Len<const LEN = 0>) {
+                    T: Len
+                }
+            }
+        } yields {
+            "Solution { successful: Yes"
+        }
+    }
+}
+
+#[test]
+fn supertraits() {
+    test! {
+        program {
+            trait PartialEq<T> { }
+            trait Eq: PartialEq<Self> { }
+            trait PartialOrd<T>: PartialEq<T> { }
+            trait Ord: Eq + PartialOrd<Self> { }
+            struct Foo { }
+            struct Bar { }
+        }
+
+        goal {
+            forall<T> {
+                if (T: Ord) {
+                    T: PartialEq<T>
+                }
+            }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            !1: PartialEq<!1>
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            forall<T, U> {
+                if (T: PartialOrd<U>) {
+                    T: PartialEq<U>
+                }
+            }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<T, U> {
+                if (T: PartialOrd<U>) {
+                    U: PartialEq<T>
+                }
+            }
+        } yields {
+            "`PartialEq<!1>` is not implemented for `!2`"
+        }
+
+        goal {
+            forall<T> {
+                if (T: PartialEq<T>) {
+                    T: Eq
+                }
+            }
+        } yields {
+            "`Eq` is not implemented for `!1`"
+        }
+    }
+}
+
+#[test]
+fn assoc_ty_where_clauses() {
+    test! {
+        program {
+            trait Sized { }
+            trait Iterator { type Item where Self: Sized; }
+            struct Foo { }
+            struct Bar { }
+
+            impl Sized for Foo { }
+            impl Iterator for Foo { type Item = Bar; }
+            impl Iterator for Bar { type Item = Foo; }
+        }
+
+        goal {
+            Foo: Iterator<Item = Bar>
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Bar: Iterator<Item = Foo>
+        } yields {
+            "cannot zip `Foo` and `(Iterator::Item)`"
+        }
+
+        goal {
+            WellFormed(<Foo as Iterator>::Item)
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            WellFormed(<Bar as Iterator>::Item)
+        } yields {
+            "no applicable candidates"
+        }
+
+        goal {
+            forall<T> {
+                if (T: Iterator<Item = Foo>) {
+                    T: Sized
+                }
+            }
+        } yields {
+            "Solution { successful: Yes"
+        }
+    }
+}
+
+#[test]
+fn trait_aliases() {
+    test! {
+        program {
+            trait Clone { }
+            trait Into<T> { }
+            trait CloneInto<T> = Clone + Into<T>;
+
+            struct Foo { }
+            struct Bar { }
+
+            impl Clone for Foo { }
+            impl Into<Bar> for Foo { }
+            impl Into<Bar> for Bar { }
+        }
+
+        goal {
+            Foo: CloneInto<Bar>
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Bar: CloneInto<Bar>
+        } yields {
+            "`CloneInto<Bar>` is not implemented for `Bar`"
+        }
+
+        goal {
+            exists<T> { Foo: CloneInto<T> }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            Foo: CloneInto<Bar>
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            forall<T, U> {
+                if (T: CloneInto<U>) {
+                    T: Into<U>
+                }
+            }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<T, U> {
+                if (T: Clone, T: Into<U>) {
+                    T: CloneInto<U>
+                }
+            }
+        } yields {
+            "Solution { successful: Yes"
+        }
+    }
+}
+
+#[test]
+fn type_outlives() {
+    test! {
+        program {
+            trait Foo<'a> { }
+            struct Ref<'a, T> { }
+            impl<'a, T> Foo<'a> for Ref<'a, T> where T: 'a { }
+        }
+
+        goal {
+            forall<'a, T> { Ref<'a, T>: Foo<'a> }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<'a, T> { T: 'a }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            !2: '!1
+                        ],
+                        constraints: [
+                            (Env(U2, []) |- TypeOutlives(!2, '!1))
+                        ]
+                    },
+                    binders: []
+                }
+            }"
+        }
+    }
+}
+
+#[test]
+fn lifetime_outlives() {
+    test! {
+        program {
+            trait Foo<'a> { }
+            struct Ref<'a> { }
+            impl<'a, 'b> Foo<'a> for Ref<'b> where 'b: 'a { }
+        }
+
+        goal {
+            forall<'a, 'b> { 'a: 'b }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            '!1: '!2
+                        ],
+                        constraints: [
+                            (Env(U2, []) |- LifetimeOutlives('!1, '!2))
+                        ]
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            forall<'a> { exists<'b> { Ref<'b>: Foo<'a> } }
+        } yields {
+            "Solution { successful: Yes"
+        }
+    }
+}
+
+#[test]
+fn static_lifetime() {
+    test! {
+        program {
+            struct Ref<'a> { }
+        }
+
+        goal {
+            forall<'a> { 'static: 'a }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            'static: '!1
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            forall<'a> { 'a: 'static }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            '!1: 'static
+                        ],
+                        constraints: [
+                            (Env(U1, []) |- LifetimeOutlives('!1, 'static))
+                        ]
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            forall<'a> { Subtype(&'static u32, &'a u32) }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            Subtype(&'static u32, &'!1 u32)
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            exists<'a> { Ref<'a> = Ref<'static> }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            (Ref<'static> = Ref<'static>)
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            forall<'a> { Ref<'a> = Ref<'static> }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            (Ref<'!1> = Ref<'static>)
+                        ],
+                        constraints: [
+                            (Env(U1, []) |- LifetimeEq('!1, 'static))
+                        ]
+                    },
+                    binders: []
+                }
+            }"
+        }
+    }
+}
+
+#[test]
+fn marker_traits() {
+    test! {
+        program {
+            #[marker] trait Marker { }
+            trait A { }
+            trait B { }
+            struct Ref<'a> { }
+
+            impl<'a> A for Ref<'a> { }
+            impl<'a> B for Ref<'a> where 'a: 'a { }
+
+            impl<T> Marker for T where T: A { }
+            impl<T> Marker for T where T: B { }
+        }
+
+        goal {
+            exists<T> { T: Marker }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            Ref<'?0>: Marker
+                        ],
+                        constraints: []
+                    },
+                    binders: [
+                        U0
+                    ]
+                }
+            }"
+        }
+    }
+}
+
+#[test]
+fn fundamental_structs() {
+    test! {
+        program {
+            crate std {
+                #[fundamental] struct Box<T> { }
+                struct Vec<T> { }
+            }
+            crate foo {
+                struct Local { }
+            }
+        }
+
+        goal {
+            LocalTo(Box<Local>, crate foo)
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            LocalTo(Box<Box<Local>>, crate foo)
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            LocalTo(Vec<Local>, crate foo)
+        } yields {
+            "no applicable candidates"
+        }
+
+        goal {
+            LocalTo(Box<Vec<Local>>, crate foo)
+        } yields {
+            "no applicable candidates"
+        }
+
+        goal {
+            LocalTo(Box<Local>, crate std)
+        } yields {
+            "Solution { successful: Yes"
+        }
+    }
+}
+
+#[test]
+fn specialization() {
+    test! {
+        program {
+            trait Tr { type Item; }
+            struct Foo { }
+            struct Bar { }
+            struct Vec<T> { }
+
+            impl<T> Tr for T { default type Item = T; }
+            impl Tr for Foo { type Item = Bar; }
+        }
+
+        goal {
+            exists<U> { Foo: Tr<Item = U> }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            <Foo as Tr>::Item ==> Bar
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            exists<U> { Vec<Foo>: Tr<Item = U> }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            <Vec<Foo> as Tr>::Item ==> Vec<Foo>
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            forall<T> { exists<U> { T: Tr<Item = U> } }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            <!1 as Tr>::Item ==> (Tr::Item)<!1>
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+    }
+}
+
+#[test]
+fn default_impl_specialization() {
+    test! {
+        program {
+            trait Tr { type Item; }
+            struct Foo { }
+            struct Bar { }
+            struct Vec<T> { }
+
+            default impl<T> Tr for Vec<T> { type Item = T; }
+            impl Tr for Vec<Foo> { type Item = Bar; }
+        }
+
+        goal {
+            Vec<Foo>: Tr<Item = Bar>
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Vec<Bar>: Tr<Item = Bar>
+        } yields {
+            "Solution { successful: Yes"
+        }
+    }
+}
+
+#[test]
+fn negative_goals() {
+    test! {
+        program {
+            trait Copy { }
+            struct i32 { }
+            struct Vec<T> { }
+            impl Copy for i32 { }
+        }
+
+        goal {
+            not { Vec<i32>: Copy }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            not { i32: Copy }
+        } yields {
+            "`i32: Copy` is provable"
+        }
+
+        goal {
+            exists<T> { not { T: Copy } }
+        } yields {
+            "Solution { successful: Maybe"
+        }
+
+        goal {
+            exists<T> { T = Vec<i32>, not { T: Copy } }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            (Vec<i32> = Vec<i32>)
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            forall<T> { not { T: Copy } }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<T> { if (T: Copy) { not { T: Copy } } }
+        } yields {
+            "`!1: Copy` is provable"
+        }
+    }
+}
+
+/// A negative goal that mentions an inference variable flounders: it
+/// is ambiguous, even if no choice of the variable makes it false,
+/// until the rest of the goal settles what the variable is.
+#[test]
+fn negative_goals_flounder() {
+    let program = "{
+        trait Copy { }
+        trait Foo { }
+        struct i32 { }
+        struct u32 { }
+        struct Vec<T> { }
+        struct Ref<'a> { }
+        impl Copy for i32 { }
+        impl Foo for i32 { }
+        impl Foo for u32 { }
+        impl Copy for Ref<'static> { }
+    }";
+    let goals = vec![
+        ("{ exists<T> { not { Vec<T>: Copy } } }", "Solution { successful: Maybe"),
+        ("{ exists<'a> { not { Ref<'a>: Copy } } }", "Solution { successful: Maybe"),
+        ("{ exists<T> { not { T: Copy }, T: Foo, T = u32 } }", "Solution { successful: Yes"),
+    ];
+    solve_goal(SolverChoice::Recursive { overflow_depth: 3 }, program, goals.clone());
+    solve_goal(SolverChoice::Slg { max_size: 10 }, program, goals);
+
+    solve_goal(SolverChoice::Recursive { overflow_depth: 3 }, program, vec![
+        ("{ exists<T> { not { T: Copy }, T: Foo } }", "Solution { successful: Maybe"),
+        ("{ exists<T> { not { T: Copy }, T: Foo, T = i32 } }", "`i32: Copy` is provable"),
+    ]);
+
+    // The SLG solver tries each answer to `T: Foo` in turn, so by the
+    // time it checks the negative goal, there is nothing left to
+    // flounder on.
+    solve_goal(SolverChoice::Slg { max_size: 10 }, program, vec![
+        ("{ exists<T> { not { T: Copy }, T: Foo } }", "Solution {
+            successful: Yes,
+            refined_goal: Canonical {
+                value: Constrained {
+                    value: [
+                        u32: Foo
+                    ],"),
+    ]);
+}
+
+#[test]
+fn implied_bounds() {
+    test! {
+        program {
+            trait Hash { }
+            trait Eq { }
+            trait Ord where Self: Eq { }
+            struct HashSet<K> where K: Hash { }
+            struct i32 { }
+            impl Hash for i32 { }
+        }
+
+        goal {
+            forall<K> { if (WellFormed(HashSet<K>)) { K: Hash } }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<K> { K: Hash }
+        } yields {
+            "`Hash` is not implemented for `!1` in environment `Env(U1, [])`"
+        }
+
+        goal {
+            forall<K> { if (WellFormed(HashSet<K>)) { WellFormed(HashSet<K>) } }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<T> { if (WellFormed(T: Ord)) { T: Eq } }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            WellFormed(HashSet<i32>)
+        } yields {
+            "Solution { successful: Yes"
+        }
+    }
+}
+
+#[test]
+fn sized() {
+    test! {
+        program {
+            #[lang(sized)] trait Sized { }
+            trait Foo { }
+            struct Vec<T> { }
+            struct Wrapper<T> where T: ?Sized { value: T }
+            impl<T> Foo for Vec<T> { }
+            closure Add: Fn(u32) -> u32;
+            generator Count(u32) yield u32 -> bool;
+        }
+
+        goal {
+            u32: Sized
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<'a> { &'a [u32]: Sized }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            [u32]: Sized
+        } yields {
+            "`Sized` is not implemented for `[u32]` in environment `Env(U0, [])`"
+        }
+
+        goal {
+            Wrapper<u32>: Sized
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Wrapper<[u32]>: Sized
+        } yields {
+            "`Sized` is not implemented for `Wrapper<[u32]>` in environment `Env(U0, [])`"
+        }
+
+        goal {
+            WellFormed(Wrapper<[u32]>)
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        // `Vec<T>` has an implicit `T: Sized` bound.
+        goal {
+            WellFormed(Vec<[u32]>)
+        } yields {
+            "no applicable candidates"
+        }
+
+        goal {
+            Vec<[u32]>: Foo
+        } yields {
+            "`Foo` is not implemented for `Vec<[u32]>` in environment `Env(U0, [])`"
+        }
+
+        goal {
+            forall<T> { if (WellFormed(Vec<T>)) { T: Sized } }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        // Tuples are `Sized` if their elements are...
+        goal {
+            (u32, (), fn(u32) -> u32): Sized
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            (u32, [u32]): Sized
+        } yields {
+            "`Sized` is not implemented for `(u32, [u32])` in environment `Env(U0, [])`"
+        }
+
+        // ...and fn pointers, closures and generators always are.
+        goal {
+            fn([u32]) -> [u32]: Sized
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Add: Sized
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Count: Sized
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Vec<()>: Foo
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Vec<(u32,)>: Foo
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Vec<fn()>: Foo
+        } yields {
+            "Solution { successful: Yes"
+        }
+    }
+}
+
+#[test]
+fn unsize() {
+    test! {
+        program {
+            #[lang(sized)] trait Sized { }
+            trait Display { }
+            struct Foo { }
+            struct Rc<T> where T: ?Sized { value: T }
+            struct Pair<T> { a: T, b: T }
+            impl Display for Foo { }
+        }
+
+        goal {
+            Unsize([u32; 3], [u32])
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Unsize(Foo, dyn Display)
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Unsize(u32, dyn Display)
+        } yields {
+            "no applicable candidates"
+        }
+
+        goal {
+            Unsize(Rc<[u32; 3]>, Rc<[u32]>)
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Unsize(Rc<Foo>, Rc<dyn Display>)
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        // `T` appears in a field other than the last.
+        goal {
+            Unsize(Pair<[u32; 3]>, Pair<[u32]>)
+        } yields {
+            "no applicable candidates"
+        }
+
+        goal {
+            exists<T> { Unsize([u32; 3], T) }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            Unsize([u32; 3], [u32])
+                        ],"
+        }
+    }
+}
+
+#[test]
+fn enums() {
+    test! {
+        program {
+            auto trait Send { }
+            trait Eq { }
+            struct Rc<T> { }
+            struct u32 { }
+            impl !Send for Rc<u32> { }
+            impl Eq for u32 { }
+            enum Option<T> { None, Some { value: T } }
+            enum Set<T> where T: Eq { Empty, Single { value: T } }
+        }
+
+        goal {
+            Option<u32>: Send
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Option<Rc<u32>>: Send
+        } yields {
+            "`Send` is not implemented for `Option<Rc<u32>>` in environment `Env(U0, [])`"
+        }
+
+        goal {
+            WellFormed(Set<u32>)
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<T> { if (WellFormed(Set<T>)) { T: Eq } }
+        } yields {
+            "Solution { successful: Yes"
+        }
+    }
+}
+
+#[test]
+fn higher_ranked_where_clauses() {
+    test! {
+        program {
+            trait Foo<'a> { }
+            trait Baz { }
+            struct Unit { }
+            struct Fixed<'a> { }
+            impl<'a> Foo<'a> for Unit { }
+            impl<'a> Foo<'a> for Fixed<'a> { }
+            impl<T> Baz for T where forall<'a> T: Foo<'a> { }
+        }
+
+        goal {
+            forall<T, 'x> { if (forall<'a> T: Foo<'a>) { T: Foo<'x> } }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<'a> Unit: Foo<'a>
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Unit: Baz
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            // `Fixed<'x>` only implements `Foo<'x>`, so the bound
+            // lifetime must equal `'x`: an unsolvable region constraint.
+            forall<'x> { Fixed<'x>: Baz }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            Fixed<'!1>: Baz
+                        ],
+                        constraints: [
+                            (Env(U2, []) |- LifetimeEq('!2, '!1))
+                        ]
+                    },
+                    binders: []
+                }
+            }"
+        }
+    }
+}
+
+#[test]
+fn subtyping() {
+    test! {
+        program {
+            struct u32 { }
+            struct Unit { }
+            struct Box<T> { value: T }
+            struct Cell<T> { value: *mut T }
+        }
+
+        goal {
+            forall<'a, 'b> { Subtype(&'a u32, &'b u32) }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            Subtype(&'!1 u32, &'!2 u32)
+                        ],
+                        constraints: [
+                            (Env(U2, []) |- LifetimeOutlives('!1, '!2))
+                        ]
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            // Function arguments are contravariant.
+            forall<'a, 'b> { Subtype(fn(&'a u32) -> Unit, fn(&'b u32) -> Unit) }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            Subtype(fn(&'!1 u32) -> Unit, fn(&'!2 u32) -> Unit)
+                        ],
+                        constraints: [
+                            (Env(U2, []) |- LifetimeOutlives('!2, '!1))
+                        ]
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            // `Cell` is invariant, so the lifetimes must be equal.
+            forall<'a, 'b> { Subtype(Cell<&'a u32>, Cell<&'b u32>) }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            Subtype(Cell<&'!1 u32>, Cell<&'!2 u32>)
+                        ],
+                        constraints: [
+                            (Env(U2, []) |- LifetimeEq('!1, '!2))
+                        ]
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            // The bound lifetime may be chosen freely.
+            forall<'a> { Subtype(for<'b> fn(&'b u32) -> Unit, fn(&'a u32) -> Unit) }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            Subtype(for<1> fn(&'?0 u32) -> Unit, fn(&'!1 u32) -> Unit)
+                        ],
+                        constraints: [
+                            (Env(U1, []) |- LifetimeOutlives('!1, '?0))
+                        ]
+                    },
+                    binders: [
+                        U1
+                    ]
+                }
+            }"
+        }
+
+        goal {
+            // ... but not the other way around: every `'b` would have
+            // to outlive `'a`, an unsolvable region constraint.
+            forall<'a> { Subtype(fn(&'a u32) -> Unit, for<'b> fn(&'b u32) -> Unit) }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            Subtype(fn(&'!1 u32) -> Unit, for<1> fn(&'?0 u32) -> Unit)
+                        ],
+                        constraints: [
+                            (Env(U2, []) |- LifetimeOutlives('!2, '!1))
+                        ]
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            Subtype(Box<u32>, Box<Unit>)
+        } yields {
+            "`u32` is not a subtype of `Unit`"
+        }
+
+        goal {
+            // Unknown types are simply unified.
+            exists<T> { Subtype(Box<T>, Box<u32>) }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            Subtype(Box<u32>, Box<u32>)
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+    }
+}
+
+/// The SLG solver proves the same things as the recursive solver, but
+/// is not limited in how deep a proof may go.
+#[test]
+fn slg_solver() {
+    test! {
+        solver SolverChoice::Slg { max_size: 10 };
+
+        program {
+            trait Foo { }
+            struct Bar { }
+            struct S<T> { }
+            struct A { }
+            struct B { }
+            impl Foo for Bar { }
+            impl<T> Foo for S<T> where T: Foo { }
+            impl Foo for A where B: Foo { }
+            impl Foo for B where A: Foo { }
+        }
+
+        goal {
+            S<S<S<S<S<Bar>>>>>: Foo
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            A: Foo
+        } yields {
+            "no way to prove `[A: Foo]`"
+        }
+
+        // There are more answers than the tables can hold.
+        goal {
+            exists<T> { T: Foo }
+        } yields {
+            "Solution {
+                successful: Maybe,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            ?0: Foo
+                        ],
+                        constraints: []
+                    },
+                    binders: [
+                        U0
+                    ]
+                }
+            }"
+        }
+    }
+
+    // The recursive solver gives up on the deep goal.
+    test! {
+        program {
+            trait Foo { }
+            struct Bar { }
+            struct S<T> { }
+            impl Foo for Bar { }
+            impl<T> Foo for S<T> where T: Foo { }
+        }
+
+        goal {
+            S<S<S<S<S<Bar>>>>>: Foo
+        } yields {
+            "Solution { successful: Maybe"
+        }
+    }
+}
+
+/// In the SLG solver, a goal with types larger than the tables allow
+/// is truncated to a more general one, whose answers are ambiguous for
+/// the original: here, the truncated goal `Vec<Vec<Vec<?0>>>: Foo` has
+/// answers besides the impl for `Vec<Vec<Vec<Vec<u32>>>>`.
+#[test]
+fn slg_truncation() {
+    test! {
+        solver SolverChoice::Slg { max_size: 3 };
+
+        program {
+            trait Foo { }
+            struct Vec<T> { }
+            struct u32 { }
+            impl<T> Foo for T where Vec<T>: Foo { }
+            impl Foo for Vec<Vec<Vec<Vec<u32>>>> { }
+        }
+
+        goal {
+            Vec<Vec<Vec<Vec<u32>>>>: Foo
+        } yields {
+            "Solution { successful: Maybe"
+        }
+
+        goal {
+            u32: Foo
+        } yields {
+            "Solution { successful: Maybe"
+        }
+    }
+}
+
+/// The recursive solver truncates goals the same way, rather than
+/// following `Vec<T>: Foo` until it overflows.
+#[test]
+fn recursive_truncation() {
+    let program = Arc::new(parse_and_lower_program("
+        trait Foo { }
+        struct Vec<T> { }
+        struct u32 { }
+        impl<T> Foo for T where Vec<T>: Foo { }
+        impl Foo for Vec<Vec<Vec<Vec<u32>>>> { }
+    ").unwrap());
+    let env: Arc<ir::ChalkDatabase> = Arc::new(program.environment());
+    ir::set_current_program(&program, || {
+        let mut solver = Solver::new(&env, 10);
+        solver.set_max_size(3);
+
+        for goal in &["Vec<Vec<Vec<Vec<u32>>>>: Foo", "u32: Foo"] {
+            let goal = parse_and_lower_goal(&program, goal).unwrap();
+            let solution = Prove::new(&mut solver, goal).solve().unwrap();
+            assert_eq!(solution.successful(), Successful::Maybe);
+        }
+        assert!(solver.overflow().is_none());
+    });
+}
+
+/// Answers in one table can feed another, even through a clause that
+/// depends on the goal being solved.
+#[test]
+fn slg_transitive_closure() {
+    test! {
+        solver SolverChoice::Slg { max_size: 10 };
+
+        program {
+            trait Edge<T> { }
+            trait Reach<T> { }
+            struct A { }
+            struct B { }
+            struct C { }
+            impl Edge<B> for A { }
+            impl Edge<C> for B { }
+            impl<X, Y> Reach<Y> for X where X: Edge<Y> { }
+            impl<X, Y, Z> Reach<Z> for X where X: Reach<Y>, Y: Edge<Z> { }
+        }
+
+        goal {
+            A: Reach<C>
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            C: Reach<A>
+        } yields {
+            "no way to prove"
+        }
+
+        goal {
+            exists<T> { T: Reach<C> }
+        } yields {
+            "Solution { successful: Maybe"
+        }
+    }
+}
+
+/// As in the recursive solver, a struct that contains itself is `Send`
+/// for the SLG solver too, unless something else it contains is not.
+#[test]
+fn slg_coinductive_auto_traits() {
+    test! {
+        solver SolverChoice::Slg { max_size: 10 };
+
+        program {
+            auto trait Send { }
+            trait Foo { }
+
+            struct Rc { }
+            struct Box<T> { data: T }
+            struct List { next: Box<List> }
+            struct Node { rc: Rc, next: Box<Node> }
+
+            impl !Send for Rc { }
+            impl<T> Foo for Box<T> where T: Foo { }
+            impl Foo for List where Box<List>: Foo { }
+        }
+
+        goal {
+            List: Send
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            Node: Send
+        } yields {
+            "no way to prove"
+        }
+
+        goal {
+            List: Foo
+        } yields {
+            "no way to prove"
+        }
+    }
+}
+
+/// A goal with many candidates solves the same whether or not they are
+/// explored in parallel (with the `parallel` feature).
+#[test]
+fn many_impl_candidates() {
+    test! {
+        program {
+            trait Foo { }
+            trait Bar { }
+            struct S0 { } struct S1 { } struct S2 { } struct S3 { }
+            struct S4 { } struct S5 { } struct S6 { } struct S7 { }
+            struct S8 { } struct S9 { } struct S10 { } struct S11 { }
+            struct S12 { } struct S13 { } struct S14 { } struct S15 { }
+            struct Vec<T> { }
+            impl Foo for S0 { } impl Foo for S1 { } impl Foo for S2 { } impl Foo for S3 { }
+            impl Foo for S4 { } impl Foo for S5 { } impl Foo for S6 { } impl Foo for S7 { }
+            impl Foo for S8 { } impl Foo for S9 { } impl Foo for S10 { } impl Foo for S11 { }
+            impl Foo for S12 { } impl Foo for S13 { } impl Foo for S14 { } impl Foo for S15 { }
+            impl<T> Foo for Vec<T> where T: Foo { }
+            impl Bar for Vec<S7> { }
+        }
+
+        goal {
+            Vec<Vec<S13>>: Foo
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            exists<T> { T: Foo, Vec<T>: Bar }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            S7: Foo,
+                            Vec<S7>: Bar
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            Vec<u32>: Foo
+        } yields {
+            "`Foo` is not implemented for `Vec<u32>`"
+        }
+    }
+}
+
+/// Different search strategies give up on different goals: the
+/// depth-first recursive solver on any proof deeper than its overflow
+/// depth, whereas iterative deepening and breadth-first search go as
+/// deep as they need to for a proof that is there to be found.
+#[test]
+fn search_strategies() {
+    let program = "{
+        trait Foo { }
+        struct Bar { }
+        struct S<T> { }
+        impl Foo for Bar { }
+        impl<T> Foo for S<T> where T: Foo { }
+    }";
+
+    solve_goal(SolverChoice::Recursive { overflow_depth: 3 }, program, vec![
+        ("{S<S<S<S<S<Bar>>>>>: Foo}", "Solution { successful: Maybe"),
+    ]);
+
+    for &choice in &[SolverChoice::IterativeDeepening { max_depth: 10 },
+                     SolverChoice::BreadthFirst { max_steps: 10 }] {
+        solve_goal(choice, program, vec![
+            ("{S<S<S<S<S<Bar>>>>>: Foo}", "Solution { successful: Yes"),
+            ("{S<S<S<S<S<S<S<S<S<S<S<Bar>>>>>>>>>>>: Foo}", "Solution { successful: Maybe"),
+        ]);
+    }
+
+    // Breadth-first search finds the answers an infinite branch
+    // would otherwise hide behind.
+    test! {
+        solver SolverChoice::BreadthFirst { max_steps: 10 };
+
+        program {
+            trait Foo { }
+            struct Bar { }
+            struct S<T> { }
+            impl<T> Foo for S<T> where S<S<T>>: Foo { }
+            impl Foo for S<Bar> { }
+        }
+
+        goal {
+            S<Bar>: Foo
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            exists<T> { S<T>: Foo }
+        } yields {
+            "Solution {
+                successful: Maybe,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            S<Bar>: Foo
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+    }
+}
+
+/// A solve that runs past its deadline fails with `TimedOut`, and
+/// leaves the solver fit to go on once given more time.
+#[test]
+fn timeouts() {
+    let program = Arc::new(parse_and_lower_program("
+        trait Foo { }
+        struct Bar { }
+        struct S<T> { }
+        impl Foo for Bar { }
+        impl<T> Foo for S<T> where T: Foo { }
+    ").unwrap());
+    let env: Arc<ir::ChalkDatabase> = Arc::new(program.environment());
+    ir::set_current_program(&program, || {
+        for &choice in &[SolverChoice::Recursive { overflow_depth: 3 },
+                         SolverChoice::IterativeDeepening { max_depth: 10 },
+                         SolverChoice::BreadthFirst { max_steps: 10 },
+                         SolverChoice::Slg { max_size: 10 }] {
+            let goal = parse_and_lower_goal(&program, "S<S<Bar>>: Foo").unwrap();
+            match choice.solve_goal_with_timeout(&env, goal, Duration::from_secs(0)) {
+                Err(Error(ErrorKind::TimedOut, _)) => {}
+                result => panic!("{:?} did not time out: {:?}", choice, result),
+            }
+
+            let goal = parse_and_lower_goal(&program, "S<S<Bar>>: Foo").unwrap();
+            let solution = choice.solve_goal_with_timeout(&env, goal, Duration::from_secs(60))
+                                 .unwrap();
+            assert_eq!(solution.successful(), Successful::Yes);
+        }
+
+        let goal = parse_and_lower_goal(&program, "S<S<Bar>>: Foo").unwrap();
+        let mut solver = Solver::new(&env, 3);
+        solver.set_deadline(Some(Instant::now()));
+        let result = Prove::new(&mut solver, goal.clone()).solve();
+        assert!(solver.timed_out());
+        assert!(solver.or_timed_out(result).is_err());
+        assert!(solver.cache().is_empty());
+
+        solver.set_deadline(None);
+        let solution = Prove::new(&mut solver, goal).solve().unwrap();
+        assert_eq!(solution.successful(), Successful::Yes);
+    });
+}
+
+/// A where-clause that proves a goal is preferred to an impl that
+/// proves it some other way, as in rustc.
+#[test]
+fn prefer_where_clauses() {
+    let program = "{
+        trait Into<T> { }
+        struct Bar { }
+        struct Baz { }
+        impl<T> Into<T> for T { }
+    }";
+
+    for &choice in &[SolverChoice::Recursive { overflow_depth: 3 },
+                     SolverChoice::Slg { max_size: 10 }] {
+        solve_goal(choice, program, vec![
+            ("{forall<T> { if (T: Into<Bar>) { exists<U> { T: Into<U> } } }}", "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            !1: Into<Bar>
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"),
+
+            // The where-clauses disagree, so there is no telling which
+            // one is meant.
+            ("{forall<T> { if (T: Into<Bar>, T: Into<Baz>) { exists<U> { T: Into<U> } } }}",
+             "Solution { successful: Maybe"),
+
+            // Without a where-clause, the impl applies.
+            ("{exists<U> { Bar: Into<U> }}", "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            Bar: Into<Bar>
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"),
+        ]);
+    }
+}
+
+/// An answer that a more general one already covers is dropped,
+/// rather than making the goal look ambiguous.
+#[test]
+fn answer_subsumption() {
+    let program = "{
+        struct Foo { }
+        struct Bar { }
+        struct Pair<T, U> { }
+        trait Eq { }
+        impl<T> Eq for Pair<T, Foo> { }
+        impl Eq for Pair<Bar, Foo> { }
+    }";
+
+    for &choice in &[SolverChoice::Recursive { overflow_depth: 3 },
+                     SolverChoice::BreadthFirst { max_steps: 10 },
+                     SolverChoice::Slg { max_size: 10 }] {
+        solve_goal(choice, program, vec![
+            ("{exists<T, U> { Pair<T, U>: Eq }}", "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            Pair<?0, Foo>: Eq
+                        ],
+                        constraints: []
+                    },
+                    binders: [
+                        U0
+                    ]
+                }
+            }"),
+        ]);
+    }
+
+    solve_goal_answers(&program[1..program.len() - 1], "exists<T, U> { Pair<T, U>: Eq }", 10, &[
+        "[Pair<?0, Foo>: Eq]",
+    ]);
+}
+
+/// Rather than being ambiguous, a goal can be provable subject to the
+/// subgoals that could not be decided.
+#[test]
+fn conditional_solutions() {
+    let program = Arc::new(parse_and_lower_program("
+        trait Clone { }
+        trait Sized { }
+        struct Foo { }
+        struct Bar { }
+        struct Vec<T> { }
+        impl Clone for Foo { }
+        impl Clone for Bar { }
+        impl<T> Sized for Vec<T> { }
+    ").unwrap());
+    let env: Arc<ir::ChalkDatabase> = Arc::new(program.environment());
+    ir::set_current_program(&program, || {
+        let goal = parse_and_lower_goal(&program, "exists<T> { Vec<T>: Sized, T: Clone }").unwrap();
+        let mut solver = Solver::new(&env, 3);
+        let conditional = Prove::new(&mut solver, goal).solve_conditional().unwrap();
+        println!("conditional: {:#?}", conditional);
+        assert_eq!(conditional.binders.len(), 1);
+        assert_eq!(format!("{:?}", conditional.value.value), "[Vec<?0>: Sized, ?0: Clone]");
+        let goals: Vec<_> = conditional.value.goals.iter().map(|g| format!("{:?}", g.goal)).collect();
+        assert_eq!(goals, vec!["?0: Clone"]);
+
+        // A goal that holds outright has no goals left.
+        let goal = parse_and_lower_goal(&program, "exists<T> { Vec<T>: Sized }").unwrap();
+        let mut solver = Solver::new(&env, 3);
+        let conditional = Prove::new(&mut solver, goal).solve_conditional().unwrap();
+        assert!(conditional.value.goals.is_empty());
+
+        let goal = parse_and_lower_goal(&program, "Vec<Foo>: Clone").unwrap();
+        let mut solver = Solver::new(&env, 3);
+        assert!(Prove::new(&mut solver, goal).solve_conditional().is_err());
+    });
+}
+
+/// A failed goal can be explained by the clauses that were tried for
+/// it, and the subgoals that each of them failed on.
+#[test]
+fn explain_failures() {
+    let program = Arc::new(parse_and_lower_program("
+        trait Clone { }
+        struct Foo { }
+        struct Bar { }
+        struct Vec<T> { }
+        impl Clone for Foo { }
+        impl<T> Clone for Vec<T> where T: Clone { }
+    ").unwrap());
+    let env: Arc<ir::ChalkDatabase> = Arc::new(program.environment());
+    ir::set_current_program(&program, || {
+        let goal = parse_and_lower_goal(&program, "Vec<Foo>: Clone").unwrap();
+        assert!(why_not::why_not(&env, 3, goal).is_none());
+
+        let goal = parse_and_lower_goal(&program, "Vec<Bar>: Clone").unwrap();
+        let explanation = why_not::why_not(&env, 3, goal).unwrap();
+        println!("explanation: {:#?}", explanation);
+        assert_eq!(format!("{:?}", explanation.goal.value.goal), "Vec<Bar>: Clone");
+        assert_eq!(explanation.kind, ProofKind::Search);
+        assert_eq!(explanation.candidates.len(), 2);
+
+        // `impl Clone for Foo` does not match at all, while the impl
+        // for `Vec<T>` needs `Bar: Clone`, which no impl provides.
+        let failed: Vec<_> = explanation.candidates
+                                        .iter()
+                                        .filter_map(|c| c.failed_subgoal.as_ref())
+                                        .collect();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(format!("{:?}", failed[0].goal.value.goal), "Bar: Clone");
+        assert_eq!(failed[0].candidates.len(), 2);
+        assert!(failed[0].candidates.iter().all(|c| c.failed_subgoal.is_none()));
+    });
+}
+
+/// A goal that fails for want of where-clauses about its type
+/// parameters comes with the where-clauses it needs.
+#[test]
+fn suggest_where_clauses() {
+    let program = Arc::new(parse_and_lower_program("
+        trait Clone { }
+        trait Debug { }
+        struct Foo { }
+        struct Vec<T> { }
+        impl<T> Clone for Vec<T> where T: Clone, T: Debug { }
+    ").unwrap());
+    let env: Arc<ir::ChalkDatabase> = Arc::new(program.environment());
+    ir::set_current_program(&program, || {
+        let suggest_for = |goal_text: &str| -> Option<Vec<String>> {
+            let goal = parse_and_lower_goal(&program, goal_text).unwrap();
+            suggest::suggest_where_clauses(&env, 3, goal)
+                .map(|wcs| wcs.iter().map(|wc| format!("{:?}", wc)).collect())
+        };
+
+        let mut suggestions = suggest_for("forall<T> { Vec<T>: Clone }").unwrap();
+        suggestions.sort();
+        assert_eq!(suggestions, vec!["!1: Clone", "!1: Debug"]);
+
+        assert_eq!(suggest_for("forall<T> { if (T: Debug) { Vec<T>: Clone } }").unwrap(),
+                   vec!["!1: Clone"]);
+
+        // There is nothing to suggest for a goal that holds, or one
+        // that no where-clause would help with.
+        assert!(suggest_for("forall<T> { if (T: Clone, T: Debug) { Vec<T>: Clone } }").is_none());
+        assert!(suggest_for("Vec<Foo>: Clone").is_none());
+    });
+}
+
+#[test]
+fn simplify_goals() {
+    let program = Arc::new(parse_and_lower_program("
+        struct Foo { }
+        trait Bar { }
+    ").unwrap());
+
+    ir::set_current_program(&program, || {
+        let simplify = |goal_text: &str| -> String {
+            let goal = parse_and_lower_goal(&program, goal_text).unwrap();
+            format!("{:?}", goal.simplify())
+        };
+
+        assert_eq!(simplify("Foo: Bar, Foo = Foo, if (Foo: Bar) { if (Foo: Bar) { Foo: Bar } }"),
+                   "(Foo: Bar, if ([Foo: Bar, Foo: Bar]) { Foo: Bar })");
+
+        // A goal that holds trivially through and through is left with
+        // one of its conjuncts.
+        assert_eq!(simplify("Foo = Foo, Foo = Foo"), "(Foo = Foo)");
+    });
+}
+
+/// Only the impls whose self type could match that of a trait goal
+/// are tried for it.
+#[test]
+fn clauses_by_self_type() {
+    let program = Arc::new(parse_and_lower_program("
+        trait Clone { }
+        struct Foo { }
+        struct Bar { }
+        struct Vec<T> { }
+        struct Box<T> { }
+        impl Clone for Foo { }
+        impl Clone for Bar { }
+        impl<T> Clone for Vec<T> where T: Clone { }
+        impl<T> Clone for Box<T> where T: Clone { }
+    ").unwrap());
+    let env: Arc<ir::ChalkDatabase> = Arc::new(program.environment());
+
+    let clauses_for = |goal_text: &str| -> usize {
+        match *parse_and_lower_goal(&program, goal_text).unwrap() {
+            ir::Goal::Leaf(ref goal) => env.program_clauses_for(goal).len(),
+            ref goal => panic!("not a leaf goal: {:?}", goal),
+        }
+    };
+    assert_eq!(clauses_for("Foo: Clone"), 1);
+    assert_eq!(clauses_for("Vec<Foo>: Clone"), 1);
+    assert_eq!(clauses_for("Vec<Vec<Bar>>: Clone"), 1);
+
+    test! {
+        program {
+            trait Clone { }
+            struct Foo { }
+            struct Vec<T> { }
+            impl Clone for Foo { }
+            impl<T> Clone for Vec<T> where T: Clone { }
+        }
+
+        goal {
+            Vec<Vec<Foo>>: Clone
+        } yields {
+            "Solution { successful: Yes"
+        }
+    }
+}
+
+#[test]
+fn numeric_defaulting() {
+    test! {
+        program {
+            trait Foo { }
+            trait Bar { }
+            impl Foo for i32 { }
+            impl Foo for u8 { }
+            impl Foo for f64 { }
+            impl Bar for u8 { }
+        }
+
+        // With nothing else to go on, an integer is an `i32`...
+        goal {
+            exists<T> { T: {integer}, T: Foo }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            i32: {integer},
+                            i32: Foo
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        // ...and a float an `f64`...
+        goal {
+            exists<T> { T: {float}, T: Foo }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            f64: {float},
+                            f64: Foo
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        // ...but only if nothing else decides the type.
+        goal {
+            exists<T> { T: {integer}, T: Bar }
+        } yields {
+            "Solution {
+                successful: Yes,
+                refined_goal: Canonical {
+                    value: Constrained {
+                        value: [
+                            u8: {integer},
+                            u8: Bar
+                        ],
+                        constraints: []
+                    },
+                    binders: []
+                }
+            }"
+        }
+
+        goal {
+            bool: {integer}
+        } yields {
+            "`bool` is not an integer type"
+        }
+
+        goal {
+            exists<T> { T: {float}, T: Bar }
+        } yields {
+            "`u8` is not a float type"
+        }
+    }
+}
+
+/// A projection might normalize to a scalar, so until it does, whether
+/// it is one is ambiguous rather than an error.
+#[test]
+fn numeric_projection() {
+    test! {
+        program {
+            trait Iterator { type Item; }
+        }
+
+        goal {
+            forall<T> { if (T: Iterator) { <T as Iterator>::Item: {integer} } }
+        } yields {
+            "Solution { successful: Maybe"
+        }
+
+        // Defaulting the variable leaves the projection ambiguous.
+        goal {
+            forall<T> { if (T: Iterator) { exists<U> { U: {integer}, <T as Iterator>::Item: {integer} } } }
+        } yields {
+            "Solution { successful: Maybe"
+        }
+    }
+}
+
+/// The answers to a goal can be had one at a time, without waiting
+/// for the search to find every one of them.
+#[test]
+fn stream_answers() {
+    let program = Arc::new(parse_and_lower_program("
+        struct Foo { }
+        struct Vec<T> { }
+        trait Clone { }
+        impl Clone for Foo { }
+        impl<T> Clone for Vec<T> where T: Clone { }
+    ").unwrap());
+    let env: Arc<ir::ChalkDatabase> = Arc::new(program.environment());
+    ir::set_current_program(&program, || {
+        let goal = parse_and_lower_goal(&program, "exists<T> { T: Clone }").unwrap();
+        let mut solver = Solver::new(&env, 10);
+        let mut answers: Vec<_> = solver.answers(goal)
+                                        .take(2)
+                                        .map(|a| format!("{:?}", a.unwrap().refined_goal.value.value))
+                                        .collect();
+        answers.sort();
+        assert_eq!(answers, vec!["[Foo: Clone]", "[Vec<Foo>: Clone]"]);
+
+        // That the first few answers are the same as they would be
+        // all at once.
+        let goal = parse_and_lower_goal(&program, "exists<T> { T: Clone }").unwrap();
+        let mut solver = Solver::new(&env, 3);
+        let streamed: Vec<_> = solver.answers(goal.clone()).map(|a| a.unwrap()).collect();
+        let mut solver = Solver::new(&env, 3);
+        let all = Prove::new(&mut solver, goal).solve_answers(10).unwrap();
+        assert_eq!(streamed, all);
+    });
+}
+
+/// With `set_sort_candidates`, the order the program lists its impls
+/// in makes no difference to the order of the answers.
+#[test]
+fn sorted_candidates() {
+    let answers = |program_text: &str| -> Vec<String> {
+        let program = Arc::new(parse_and_lower_program(program_text).unwrap());
+        let env: Arc<ir::ChalkDatabase> = Arc::new(program.environment());
+        ir::set_current_program(&program, || {
+            let goal = parse_and_lower_goal(&program, "exists<T> { T: Clone }").unwrap();
+            let mut solver = Solver::new(&env, 3);
+            solver.set_sort_candidates(true);
+            let answers: Vec<_> = solver.answers(goal)
+                                        .map(|a| format!("{:?}", a.unwrap().refined_goal.value.value))
+                                        .collect();
+            answers
+        })
+    };
+
+    let forwards = answers("
+        trait Clone { }
+        struct Abc { }
+        struct Zed { }
+        impl Clone for Abc { }
+        impl Clone for Zed { }
+    ");
+    let backwards = answers("
+        trait Clone { }
+        struct Abc { }
+        struct Zed { }
+        impl Clone for Zed { }
+        impl Clone for Abc { }
+    ");
+    assert_eq!(forwards, vec!["[Abc: Clone]", "[Zed: Clone]"]);
+    assert_eq!(forwards, backwards);
+}
+
+/// The limits of a solver can be tightened for a single goal.
+#[test]
+fn solve_options() {
+    let program = Arc::new(parse_and_lower_program("
+        trait Foo { }
+        struct Bar { }
+        struct S<T> { }
+        impl Foo for Bar { }
+        impl<T> Foo for S<T> where T: Foo { }
+    ").unwrap());
+    let env: Arc<ir::ChalkDatabase> = Arc::new(program.environment());
+    ir::set_current_program(&program, || {
+        let choice = SolverChoice::Recursive { overflow_depth: 10 };
+        let shallow = SolveOptions { max_depth: Some(1), ..SolveOptions::default() };
+
+        let goal = parse_and_lower_goal(&program, "S<S<S<Bar>>>: Foo").unwrap();
+        let solution = choice.solve_goal(&env, goal.clone()).unwrap();
+        assert_eq!(solution.successful(), Successful::Yes);
+        let solution = choice.solve_goal_with_options(&env, goal, &shallow).unwrap();
+        assert_eq!(solution.successful(), Successful::Maybe);
+
+        // The options are for the one goal; the choice of solver is
+        // left as it was.
+        assert_eq!(choice.with_options(&shallow), SolverChoice::Recursive { overflow_depth: 1 });
+        assert_eq!(choice.with_options(&SolveOptions::default()), choice);
+
+        let goal = parse_and_lower_goal(&program, "exists<T> { T: Foo }").unwrap();
+        let options = SolveOptions { enumerate_limit: Some(2), ..SolveOptions::default() };
+        let answers = choice.solve_goal_answers(&env, goal, &options).unwrap();
+        assert_eq!(answers.len(), 2);
+    });
+}
+
+/// Hypotheses can be attached to a goal without touching the program.
+#[test]
+fn assuming_hypotheses() {
+    let program = Arc::new(parse_and_lower_program("
+        trait Foo { }
+        trait Bar { }
+        struct S { }
+        impl<T> Bar for T where T: Foo { }
+    ").unwrap());
+    let env: Arc<ir::ChalkDatabase> = Arc::new(program.environment());
+    ir::set_current_program(&program, || {
+        let choice = SolverChoice::Recursive { overflow_depth: 3 };
+
+        let goal = parse_and_lower_goal(&program, "S: Bar").unwrap();
+        assert!(choice.solve_goal(&env, goal.clone()).is_err());
+
+        let hypotheses = chalk_parse::parse_hypotheses("S: Foo").unwrap();
+        let hypotheses = program.lower_hypotheses(&hypotheses).unwrap();
+        let goal = Box::new((*goal).assuming(hypotheses));
+        assert_eq!(format!("{:?}", goal), "if ([S: Foo]) { S: Bar }");
+        let solution = choice.solve_goal(&env, goal).unwrap();
+        assert_eq!(solution.successful(), Successful::Yes);
+    });
+}
+
+#[test]
+fn multiple_hypotheses() {
+    test! {
+        program {
+            trait Clone { }
+            trait Debug { }
+            trait Both { }
+            impl<T> Both for T where T: Clone, T: Debug { }
+        }
+
+        goal {
+            forall<T> { if (T: Clone, T: Debug) { T: Both } }
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            forall<T> { if (T: Clone) { T: Both } }
+        } yields {
+            "`Both` is not implemented for `!1`"
+        }
+    }
+}
+
+/// A `#[coinductive]` trait, like an auto trait, holds for a cycle.
+#[test]
+fn coinductive_attribute() {
+    test! {
+        program {
+            #[coinductive] trait Foo { }
+            trait Bar { }
+            struct S { }
+            impl Foo for S where S: Foo { }
+            impl Bar for S where S: Bar { }
+        }
+
+        goal {
+            S: Foo
+        } yields {
+            "Solution { successful: Yes"
+        }
+
+        goal {
+            S: Bar
+        } yields {
+            "`Bar` is not implemented for `S`"
+        }
+    }
+}
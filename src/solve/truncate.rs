@@ -0,0 +1,112 @@
+//! Truncating goals whose types have grown too large.
+//!
+//! A program like
+//!
+//! ```notrust
+//! impl<T> Foo for T where Vec<T>: Foo { }
+//! ```
+//!
+//! leads from `u32: Foo` to `Vec<u32>: Foo`, `Vec<Vec<u32>>: Foo`, and
+//! so on forever, each a goal we have not seen before. Once a type gets
+//! too large, we cut it short, replacing what lies past the limit with
+//! fresh variables: `Vec<Vec<?0>>: Foo`, say. That goal is more general
+//! than the one we started with, so the answers to it cover those of
+//! the original, but need not all apply to it; whoever asked can only
+//! call the result ambiguous.
+
+use ir::*;
+use solve::infer::InferenceTable;
+
+/// The size of `ty`: how many types make it up, counting `ty` itself.
+pub fn ty_size(ty: &Ty) -> usize {
+    match *ty {
+        Ty::Apply(ref apply) => {
+            1 + apply.parameters
+                     .iter()
+                     .filter_map(|p| p.as_ref().ty())
+                     .map(ty_size)
+                     .sum::<usize>()
+        }
+        _ => 1,
+    }
+}
+
+/// If any of the types in the trait goal `goal` is bigger than
+/// `max_size`, returns `goal` with those types truncated to that size,
+/// the parts cut off replaced by fresh variables. Returns `None` if
+/// `goal` is small enough as it is.
+pub fn truncate(goal: &Canonical<InEnvironment<WhereClauseGoal>>, max_size: usize)
+                -> Option<Canonical<InEnvironment<WhereClauseGoal>>> {
+    let trait_ref = match goal.value.goal {
+        WhereClauseGoal::Implemented(ref trait_ref) => trait_ref,
+        _ => return None,
+    };
+    let too_large = trait_ref.parameters
+                             .iter()
+                             .filter_map(|p| p.as_ref().ty())
+                             .any(|ty| ty_size(ty) > max_size);
+    if !too_large {
+        return None;
+    }
+
+    let mut truncater = Truncater {
+        binders: goal.binders.clone(),
+        universe: goal.value.environment.universe,
+    };
+    let parameters = trait_ref.parameters
+                              .iter()
+                              .map(|p| match *p {
+                                  ParameterKind::Ty(ref ty) => {
+                                      let mut remaining = max_size;
+                                      ParameterKind::Ty(truncater.truncate_ty(ty, &mut remaining))
+                                  }
+                                  ref p => p.clone(),
+                              })
+                              .collect();
+    let trait_ref = TraitRef { trait_id: trait_ref.trait_id, parameters };
+    let value = InEnvironment::new(&goal.value.environment, WhereClauseGoal::Implemented(trait_ref));
+
+    // Canonicalizing afresh drops the variables that were only in the
+    // parts cut off, so that goals truncated to the same thing are the
+    // same goal.
+    let mut infer = InferenceTable::new_with_vars(&truncater.binders);
+    Some(infer.canonicalize(&value).quantified)
+}
+
+struct Truncater {
+    /// The binders of the goal being truncated, to which a fresh
+    /// variable is added for each part cut off.
+    binders: Vec<ParameterKind<UniverseIndex>>,
+
+    /// The universe of the fresh variables: that of the goal's
+    /// environment, so that they can name anything the goal can.
+    universe: UniverseIndex,
+}
+
+impl Truncater {
+    /// Truncates `ty` to at most `remaining` types, counting down as
+    /// they are used up.
+    fn truncate_ty(&mut self, ty: &Ty, remaining: &mut usize) -> Ty {
+        if *remaining == 0 {
+            self.binders.push(ParameterKind::Ty(self.universe));
+            return Ty::Var(self.binders.len() - 1);
+        }
+        *remaining -= 1;
+
+        match *ty {
+            Ty::Apply(ref apply) => {
+                let parameters = apply.parameters
+                                      .iter()
+                                      .map(|p| match *p {
+                                          ParameterKind::Ty(ref ty) => {
+                                              ParameterKind::Ty(self.truncate_ty(ty, remaining))
+                                          }
+                                          ref p => p.clone(),
+                                      })
+                                      .collect();
+                Ty::Apply(ApplicationTy { name: apply.name, parameters })
+            }
+            ref ty => ty.clone(),
+        }
+    }
+}
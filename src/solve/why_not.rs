@@ -0,0 +1,75 @@
+//! Explanations of why a goal does not hold, for debugging trait
+//! designs: the clauses that might have proven the goal, and for each,
+//! the first of its subgoals that failed, explained in turn.
+
+use ir::*;
+use solve::goal::Prove;
+use solve::proof_tree::{ProofClause, ProofKind, ProofTree};
+use solve::solver::Solver;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WhyNot {
+    pub goal: Canonical<InEnvironment<WhereClauseGoal>>,
+
+    /// The message of the error the goal failed with.
+    pub error: String,
+
+    /// Whether the goal was searched, or failed for some other reason,
+    /// such as having failed before (see `ProofKind`). Only for a goal
+    /// that was searched are the `candidates` known.
+    pub kind: ProofKind,
+
+    /// The clauses that were tried for the goal, in order.
+    pub candidates: Vec<RejectedCandidate>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RejectedCandidate {
+    pub clause: ProofClause,
+
+    /// Why the first of the clause's subgoals to fail did so; `None`
+    /// if the clause did not match the goal in the first place.
+    pub failed_subgoal: Option<Box<WhyNot>>,
+}
+
+impl WhyNot {
+    /// Explains the failure of the goal of `tree`, or returns `None` if
+    /// it did not fail.
+    pub fn from_proof_tree(tree: &ProofTree) -> Option<WhyNot> {
+        let error = match tree.result {
+            Ok(_) => return None,
+            Err(ref error) => error.clone(),
+        };
+        let candidates = tree.rejected
+                             .iter()
+                             .map(|candidate| RejectedCandidate {
+                                 clause: candidate.clause.clone(),
+                                 failed_subgoal: candidate.subproofs
+                                                          .iter()
+                                                          .filter_map(WhyNot::from_proof_tree)
+                                                          .next()
+                                                          .map(Box::new),
+                             })
+                             .collect();
+        Some(WhyNot {
+            goal: tree.goal.clone(),
+            error,
+            kind: tree.kind,
+            candidates,
+        })
+    }
+}
+
+/// Tries to prove `goal` with the recursive solver, explaining why it
+/// does not hold if that fails. Returns `None` if the goal holds (or
+/// may hold), or if what failed was not one of its where-clauses, as
+/// when a `not { ... }` goal turns out to be provable.
+pub fn why_not(program: &Arc<ChalkDatabase>, overflow_depth: usize, goal: Box<Goal>) -> Option<WhyNot> {
+    let mut solver = Solver::new(program, overflow_depth);
+    solver.record_proof_trees();
+    if Prove::new(&mut solver, goal).solve().is_ok() {
+        return None;
+    }
+    solver.take_proof_trees().iter().filter_map(WhyNot::from_proof_tree).next()
+}
@@ -1,11 +1,13 @@
 use errors::*;
 use ir::*;
+use std::fmt::Debug;
 use std::sync::Arc;
 
 pub trait Zipper {
     fn zip_tys(&mut self, a: &Ty, b: &Ty) -> Result<()>;
     fn zip_lifetimes(&mut self, a: &Lifetime, b: &Lifetime) -> Result<()>;
     fn zip_krates(&mut self, a: &Krate, b: &Krate) -> Result<()>;
+    fn zip_consts(&mut self, a: &Const, b: &Const) -> Result<()>;
 }
 
 impl<'f, Z: Zipper> Zipper for &'f mut Z {
@@ -20,6 +22,10 @@ impl<'f, Z: Zipper> Zipper for &'f mut Z {
     fn zip_krates(&mut self, a: &Krate, b: &Krate) -> Result<()> {
         (**self).zip_krates(a, b)
     }
+
+    fn zip_consts(&mut self, a: &Const, b: &Const) -> Result<()> {
+        (**self).zip_consts(a, b)
+    }
 }
 
 pub trait Zip {
@@ -67,15 +73,17 @@ impl<T: Zip, U: Zip> Zip for (T, U) {
     }
 }
 
-impl<T: Zip, L: Zip, C: Zip> Zip for ParameterKind<T, L, C> {
+impl<T: Zip, L: Zip, C: Zip, N: Zip> Zip for ParameterKind<T, L, C, N> {
     fn zip_with<Z: Zipper>(zipper: &mut Z, a: &Self, b: &Self) -> Result<()> {
         match (a, b) {
             (&ParameterKind::Ty(ref a), &ParameterKind::Ty(ref b)) => Zip::zip_with(zipper, a, b),
             (&ParameterKind::Lifetime(ref a), &ParameterKind::Lifetime(ref b)) => Zip::zip_with(zipper, a, b),
             (&ParameterKind::Krate(ref a), &ParameterKind::Krate(ref b)) => Zip::zip_with(zipper, a, b),
+            (&ParameterKind::Const(ref a), &ParameterKind::Const(ref b)) => Zip::zip_with(zipper, a, b),
             (&ParameterKind::Ty(_), _) |
             (&ParameterKind::Lifetime(_), _) |
-            (&ParameterKind::Krate(_), _) => {
+            (&ParameterKind::Krate(_), _) |
+            (&ParameterKind::Const(_), _) => {
                 panic!("zipping things of mixed kind")
             }
         }
@@ -100,6 +108,12 @@ impl Zip for Krate {
     }
 }
 
+impl Zip for Const {
+    fn zip_with<Z: Zipper>(zipper: &mut Z, a: &Self, b: &Self) -> Result<()> {
+        zipper.zip_consts(a, b)
+    }
+}
+
 macro_rules! eq_zip {
     ($t:ty) => {
         impl Zip for $t {
@@ -117,6 +131,7 @@ eq_zip!(ItemId);
 eq_zip!(TypeName);
 eq_zip!(KrateId);
 eq_zip!(Identifier);
+eq_zip!(NumericKind);
 
 macro_rules! struct_zip {
     ($t:ident$([$($param:tt)*])* { $($field:ident),* } $($w:tt)*) => {
@@ -138,10 +153,29 @@ struct_zip!(InEnvironment[T] { environment, goal } where T: Zip);
 struct_zip!(ApplicationTy { name, parameters });
 struct_zip!(ProjectionTy { associated_ty_id, parameters });
 struct_zip!(Normalize { projection, ty });
+struct_zip!(ConstProjection { associated_const_id, parameters });
+struct_zip!(NormalizeConst { projection, value });
+struct_zip!(TypeOutlives { ty, lifetime });
+struct_zip!(LifetimeOutlives { a, b });
 struct_zip!(LocalTo[T] { value, krate } where T: Zip);
+struct_zip!(Unsize { source, target });
+struct_zip!(Numeric { ty, kind });
+struct_zip!(Subtype { a, b });
 struct_zip!(Unify[T] { a, b } where T: Zip);
 struct_zip!(Not[T] { predicate, krate } where T: Zip);
 
+/// Bound variables cannot be unified with anything, so binders are
+/// only zippable when they are already identical; higher-ranked
+/// clauses must be instantiated first.
+impl<T: PartialEq + Debug> Zip for Binders<T> {
+    fn zip_with<Z: Zipper>(_zipper: &mut Z, a: &Self, b: &Self) -> Result<()> {
+        if a != b {
+            bail!("cannot zip `{:?}` and `{:?}`", a, b)
+        }
+        Ok(())
+    }
+}
+
 impl Zip for Environment {
     fn zip_with<Z: Zipper>(zipper: &mut Z, a: &Self, b: &Self) -> Result<()> {
         assert_eq!(a.universe, b.universe); // it's wrong to zip 2 env's with distinct universes!
@@ -171,8 +205,10 @@ macro_rules! enum_zip {
     }
 }
 
-enum_zip!(WhereClause { Implemented, Normalize });
-enum_zip!(WhereClauseGoal { Implemented, Normalize, UnifyTys, UnifyKrates, UnifyLifetimes,
-                            WellFormed, TyLocalTo, NotImplemented, NotNormalize, NotUnifyTys });
+enum_zip!(WhereClause { Implemented, Normalize, NormalizeConst, TypeOutlives, LifetimeOutlives,
+                        WellFormed, ForAll });
+enum_zip!(WhereClauseGoal { Implemented, Normalize, NormalizeConst, TypeOutlives, LifetimeOutlives,
+                            UnifyTys, Subtype, UnifyKrates, UnifyLifetimes, WellFormed, TyLocalTo, Unsize,
+                            Numeric, NotImplemented, NotNormalize, NotUnifyTys });
 enum_zip!(WellFormed { Ty, TraitRef });
 